@@ -185,7 +185,7 @@ mod resolver {
             DistributionDatabase::new(
                 client,
                 &build_context,
-                concurrency.downloads,
+                concurrency.requests,
                 PreviewMode::Disabled,
             ),
         )?;
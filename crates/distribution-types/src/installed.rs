@@ -281,6 +281,20 @@ impl InstalledDist {
         }
     }
 
+    /// Read the descriptive fields (e.g., `Summary` and `License`) from the `METADATA` or
+    /// `PKG-INFO` file.
+    pub fn descriptive_metadata(&self) -> Result<pypi_types::DescriptiveMetadata> {
+        let path = match self {
+            Self::Registry(_) | Self::Url(_) => Cow::Owned(self.path().join("METADATA")),
+            Self::EggInfoFile(dist) => Cow::Borrowed(&dist.path),
+            Self::EggInfoDirectory(dist) => Cow::Owned(dist.path.join("PKG-INFO")),
+            Self::LegacyEditable(dist) => Cow::Owned(dist.egg_info.join("PKG-INFO")),
+        };
+        let contents = fs::read(path.as_ref())?;
+        pypi_types::DescriptiveMetadata::parse_metadata(&contents)
+            .with_context(|| format!("Failed to parse metadata file at: {}", path.user_display()))
+    }
+
     /// Return the `INSTALLER` of the distribution.
     pub fn installer(&self) -> Result<Option<String>> {
         let path = self.path().join("INSTALLER");
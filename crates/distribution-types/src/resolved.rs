@@ -53,6 +53,12 @@ impl ResolvedDist {
         }
     }
 
+    /// Return true if the distribution is a source distribution, which must be built prior to
+    /// installation.
+    pub fn is_source(&self) -> bool {
+        matches!(self, Self::Installable(Dist::Source(_)))
+    }
+
     /// Returns the [`IndexUrl`], if the distribution is from a registry.
     pub fn index(&self) -> Option<&IndexUrl> {
         match self {
@@ -12,7 +12,9 @@ use pep440_rs::Version;
 use platform_tags::{Arch, Os};
 use pypi_types::Scheme;
 pub use script::{scripts_from_ini, Script};
-pub use uninstall::{uninstall_egg, uninstall_legacy_editable, uninstall_wheel, Uninstall};
+pub use uninstall::{
+    stash_wheel, uninstall_egg, uninstall_legacy_editable, uninstall_wheel, StashedFile, Uninstall,
+};
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
 pub use wheel::{parse_wheel_file, LibKind};
@@ -1,6 +1,7 @@
 //! Like `wheel.rs`, but for installing wheels that have already been unzipped, rather than
 //! reading from a zip file.
 
+use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::time::SystemTime;
@@ -10,14 +11,16 @@ use fs_err::{DirEntry, File};
 use reflink_copy as reflink;
 use serde::{Deserialize, Serialize};
 use tempfile::tempdir_in;
-use tracing::{debug, instrument};
+use tracing::{debug, instrument, warn};
 
 use distribution_filename::WheelFilename;
 use pep440_rs::Version;
 use pypi_types::DirectUrl;
 use uv_normalize::PackageName;
 
+use crate::record::RecordEntry;
 use crate::script::{scripts_from_ini, Script};
+use crate::uninstall::{remove_recorded, StashedFile};
 use crate::wheel::{
     extra_dist_info, install_data, parse_metadata, parse_wheel_file, read_record_file,
     write_script_entrypoints, LibKind,
@@ -31,6 +34,10 @@ use crate::{Error, Layout};
 /// <https://packaging.python.org/en/latest/specifications/binary-distribution-format/#installing-a-wheel-distribution-1-0-py32-none-any-whl>
 ///
 /// Wheel 1.0: <https://www.python.org/dev/peps/pep-0427/>
+///
+/// If `rollback` is set, any existing files that the wheel would overwrite are moved aside first,
+/// and a failed installation is reverted: the files written so far are removed, and the
+/// overwritten files are restored.
 #[instrument(skip_all, fields(wheel = %filename))]
 pub fn install_wheel(
     layout: &Layout,
@@ -39,6 +46,7 @@ pub fn install_wheel(
     direct_url: Option<&DirectUrl>,
     installer: Option<&str>,
     link_mode: LinkMode,
+    rollback: bool,
 ) -> Result<(), Error> {
     let dist_info_prefix = find_dist_info(&wheel)?;
     let metadata = dist_info_metadata(&dist_info_prefix, &wheel)?;
@@ -69,15 +77,13 @@ pub fn install_wheel(
 
     // > 1.c If Root-Is-Purelib == ‘true’, unpack archive into purelib (site-packages).
     // > 1.d Else unpack archive into platlib (site-packages).
-    debug!(name, "Extracting file");
     let site_packages = match lib_kind {
         LibKind::Pure => &layout.scheme.purelib,
         LibKind::Plat => &layout.scheme.platlib,
     };
-    let num_unpacked = link_mode.link_wheel_files(site_packages, &wheel)?;
-    debug!(name, "Extracted {num_unpacked} files");
 
-    // Read the RECORD file.
+    // Read the RECORD file. Every file written to the environment is tracked in the RECORD, such
+    // that a failed installation can be reverted.
     let mut record_file = File::open(
         wheel
             .as_ref()
@@ -85,8 +91,146 @@ pub fn install_wheel(
     )?;
     let mut record = read_record_file(&mut record_file)?;
 
-    let (console_scripts, gui_scripts) =
-        parse_scripts(&wheel, &dist_info_prefix, None, layout.python_version.1)?;
+    let (console_scripts, gui_scripts) = parse_scripts(
+        wheel.as_ref(),
+        &dist_info_prefix,
+        None,
+        layout.python_version.1,
+    )?;
+
+    if !rollback {
+        return install_recorded(
+            layout,
+            site_packages,
+            wheel.as_ref(),
+            &dist_info_prefix,
+            &name,
+            &console_scripts,
+            &gui_scripts,
+            direct_url,
+            installer,
+            link_mode,
+            &mut record,
+        );
+    }
+
+    // Move aside any existing files that the wheel would overwrite (e.g., files shared with another
+    // distribution), such that they can be restored if the installation fails.
+    let mut backup = None;
+    let mut overwritten = Vec::new();
+    for path in installed_paths(
+        layout,
+        site_packages,
+        &dist_info_prefix,
+        &name,
+        &record,
+        &console_scripts,
+        &gui_scripts,
+    ) {
+        if !fs::symlink_metadata(&path).is_ok_and(|metadata| !metadata.is_dir()) {
+            continue;
+        }
+        let backup = match backup {
+            Some(ref backup) => backup,
+            None => {
+                fs::create_dir_all(site_packages)?;
+                backup.insert(
+                    tempfile::Builder::new()
+                        .prefix(".uv-backup-")
+                        .tempdir_in(site_packages)?,
+                )
+            }
+        };
+        let stashed = backup.path().join(overwritten.len().to_string());
+        if let Some(file) = StashedFile::stash(path, stashed)? {
+            overwritten.push(file);
+        }
+    }
+
+    if let Err(err) = install_recorded(
+        layout,
+        site_packages,
+        wheel.as_ref(),
+        &dist_info_prefix,
+        &name,
+        &console_scripts,
+        &gui_scripts,
+        direct_url,
+        installer,
+        link_mode,
+        &mut record,
+    ) {
+        // A partially-installed wheel can't be uninstalled (its RECORD is incomplete), so remove
+        // any files that were written prior to the failure, and restore any files that were
+        // overwritten.
+        debug!(name, "Removing partially-installed files");
+        if let Err(err) = remove_recorded(site_packages, &record, BTreeSet::new()) {
+            warn!("Failed to remove partially-installed files for {name}: {err}");
+        }
+        for file in overwritten.iter().rev() {
+            if let Err(err) = file.restore() {
+                warn!("Failed to restore overwritten file for {name}: {err}");
+            }
+        }
+        return Err(err);
+    }
+
+    Ok(())
+}
+
+/// Return the paths in the environment to which the files listed in the wheel's RECORD, along with
+/// its entrypoints, will be installed.
+fn installed_paths(
+    layout: &Layout,
+    site_packages: &Path,
+    dist_info_prefix: &str,
+    name: &str,
+    record: &[RecordEntry],
+    console_scripts: &[Script],
+    gui_scripts: &[Script],
+) -> Vec<PathBuf> {
+    let data_dir = format!("{dist_info_prefix}.data/");
+    let files = record.iter().filter_map(|entry| {
+        let Some(data) = entry.path.strip_prefix(&data_dir) else {
+            return Some(site_packages.join(&entry.path));
+        };
+        // Mirror the destinations used by `install_data`.
+        let (kind, path) = data.split_once('/')?;
+        match kind {
+            "data" => Some(layout.scheme.data.join(path)),
+            "scripts" => Some(layout.scheme.scripts.join(path)),
+            "headers" => Some(layout.scheme.include.join(name).join(path)),
+            "purelib" => Some(layout.scheme.purelib.join(path)),
+            "platlib" => Some(layout.scheme.platlib.join(path)),
+            _ => None,
+        }
+    });
+    let entrypoints = console_scripts
+        .iter()
+        .chain(gui_scripts)
+        .map(|script| entrypoint_path(script, layout));
+    files.chain(entrypoints).collect()
+}
+
+/// Install the files of an unzipped wheel into site packages, extending the `record` with every
+/// file that's written.
+#[allow(clippy::too_many_arguments)]
+fn install_recorded(
+    layout: &Layout,
+    site_packages: &Path,
+    wheel: &Path,
+    dist_info_prefix: &str,
+    name: &str,
+    console_scripts: &[Script],
+    gui_scripts: &[Script],
+    direct_url: Option<&DirectUrl>,
+    installer: Option<&str>,
+    link_mode: LinkMode,
+    record: &mut Vec<RecordEntry>,
+) -> Result<(), Error> {
+    debug!(name, "Extracting file");
+    let num_unpacked = link_mode.link_wheel_files(site_packages, wheel)?;
+    debug!(name, "Extracted {num_unpacked} files");
 
     if console_scripts.is_empty() && gui_scripts.is_empty() {
         debug!(name, "No entrypoints");
@@ -94,8 +238,8 @@ pub fn install_wheel(
         debug!(name, "Writing entrypoints");
 
         fs_err::create_dir_all(&layout.scheme.scripts)?;
-        write_script_entrypoints(layout, site_packages, &console_scripts, &mut record, false)?;
-        write_script_entrypoints(layout, site_packages, &gui_scripts, &mut record, true)?;
+        write_script_entrypoints(layout, site_packages, console_scripts, record, false)?;
+        write_script_entrypoints(layout, site_packages, gui_scripts, record, true)?;
     }
 
     // 2.a Unpacked archive includes distribution-1.0.dist-info/ and (if there is data) distribution-1.0.data/.
@@ -107,10 +251,10 @@ pub fn install_wheel(
             layout,
             site_packages,
             &data_dir,
-            &name,
-            &console_scripts,
-            &gui_scripts,
-            &mut record,
+            name,
+            console_scripts,
+            gui_scripts,
+            record,
         )?;
         // 2.c If applicable, update scripts starting with #!python to point to the correct interpreter.
         // Script are unsupported through data
@@ -123,11 +267,11 @@ pub fn install_wheel(
     debug!(name, "Writing extra metadata");
     extra_dist_info(
         site_packages,
-        &dist_info_prefix,
+        dist_info_prefix,
         true,
         direct_url,
         installer,
-        &mut record,
+        record,
     )?;
 
     debug!(name, "Writing record");
@@ -136,7 +280,7 @@ pub fn install_wheel(
         .escape(b'"')
         .from_path(site_packages.join(format!("{dist_info_prefix}.dist-info/RECORD")))?;
     record.sort();
-    for entry in record {
+    for entry in record.iter() {
         record_writer.serialize(entry)?;
     }
 
@@ -238,6 +382,11 @@ pub enum LinkMode {
     Copy,
     /// Hard link packages from the wheel into the site packages.
     Hardlink,
+    /// Symbolically link packages from the wheel into the site packages.
+    ///
+    /// Since the links point into the cache, packages installed in this mode will be broken if the
+    /// cache is cleaned or pruned.
+    Symlink,
 }
 
 impl Default for LinkMode {
@@ -262,6 +411,7 @@ impl LinkMode {
             Self::Clone => clone_wheel_files(site_packages, wheel),
             Self::Copy => copy_wheel_files(site_packages, wheel),
             Self::Hardlink => hardlink_wheel_files(site_packages, wheel),
+            Self::Symlink => symlink_wheel_files(site_packages, wheel),
         }
     }
 }
@@ -550,3 +700,113 @@ fn hardlink_wheel_files(
 
     Ok(count)
 }
+
+/// Extract a wheel by symbolically linking all of its files into site packages.
+///
+/// Directories are created rather than linked, such that they can be shared with other
+/// distributions.
+fn symlink_wheel_files(
+    site_packages: impl AsRef<Path>,
+    wheel: impl AsRef<Path>,
+) -> Result<usize, Error> {
+    let mut attempt = Attempt::default();
+    let mut count = 0usize;
+
+    // Walk over the directory.
+    for entry in walkdir::WalkDir::new(&wheel) {
+        let entry = entry?;
+        let path = entry.path();
+
+        let relative = path.strip_prefix(&wheel).unwrap();
+        let out_path = site_packages.as_ref().join(relative);
+
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&out_path)?;
+            continue;
+        }
+
+        // The `RECORD` file is modified during installation, so we copy it instead of symlinking.
+        if path.ends_with("RECORD") {
+            fs::copy(path, &out_path)?;
+            count += 1;
+            continue;
+        }
+
+        // Fallback to copying if symlinks aren't supported for this installation.
+        match attempt {
+            Attempt::Initial => {
+                attempt = Attempt::Subsequent;
+                if let Err(err) = symlink_file(path, &out_path) {
+                    // If the file already exists, remove it and try again.
+                    if err.kind() == std::io::ErrorKind::AlreadyExists {
+                        debug!(
+                            "File already exists (initial attempt), overwriting: {}",
+                            out_path.display()
+                        );
+                        // Removing and recreating would lead to race conditions.
+                        let tempdir = tempdir_in(&site_packages)?;
+                        let tempfile = tempdir.path().join(entry.file_name());
+                        if symlink_file(path, &tempfile).is_ok() {
+                            fs_err::rename(&tempfile, &out_path)?;
+                        } else {
+                            warn!(
+                                "Failed to symlink `{}` to `{}`, falling back to copying files",
+                                path.display(),
+                                out_path.display()
+                            );
+                            fs::copy(path, &out_path)?;
+                            attempt = Attempt::UseCopyFallback;
+                        }
+                    } else {
+                        warn!(
+                            "Failed to symlink `{}` to `{}`, falling back to copying files",
+                            path.display(),
+                            out_path.display()
+                        );
+                        fs::copy(path, &out_path)?;
+                        attempt = Attempt::UseCopyFallback;
+                    }
+                }
+            }
+            Attempt::Subsequent => {
+                if let Err(err) = symlink_file(path, &out_path) {
+                    // If the file already exists, remove it and try again.
+                    if err.kind() == std::io::ErrorKind::AlreadyExists {
+                        debug!(
+                            "File already exists (subsequent attempt), overwriting: {}",
+                            out_path.display()
+                        );
+                        // Removing and recreating would lead to race conditions.
+                        let tempdir = tempdir_in(&site_packages)?;
+                        let tempfile = tempdir.path().join(entry.file_name());
+                        symlink_file(path, &tempfile)?;
+                        fs_err::rename(&tempfile, &out_path)?;
+                    } else {
+                        return Err(err.into());
+                    }
+                }
+            }
+            Attempt::UseCopyFallback => {
+                fs::copy(path, &out_path)?;
+            }
+        }
+
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// Create a symbolic link to the file at `src`.
+#[cfg(unix)]
+fn symlink_file(src: &Path, dst: &Path) -> std::io::Result<()> {
+    fs_err::os::unix::fs::symlink(src, dst)
+}
+
+/// Create a symbolic link to the file at `src`.
+///
+/// On Windows, creating symbolic links requires elevated privileges or Developer Mode.
+#[cfg(windows)]
+fn symlink_file(src: &Path, dst: &Path) -> std::io::Result<()> {
+    fs_err::os::windows::fs::symlink_file(src, dst)
+}
@@ -7,6 +7,7 @@ use std::sync::Mutex;
 use tracing::debug;
 use uv_fs::write_atomic_sync;
 
+use crate::record::RecordEntry;
 use crate::wheel::read_record_file;
 use crate::Error;
 
@@ -31,12 +32,127 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
         read_record_file(&mut record_file)?
     };
 
+    remove_recorded(site_packages, &record, BTreeSet::new())
+}
+
+/// Uninstall the wheel represented by the given `.dist-info` directory, moving its files into the
+/// `stash` directory rather than deleting them, such that they can later be restored.
+///
+/// Returns the uninstall summary, along with the files that were stashed in the order in which
+/// they were moved.
+pub fn stash_wheel(dist_info: &Path, stash: &Path) -> Result<(Uninstall, Vec<StashedFile>), Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+
+    // Read the RECORD file.
+    let record = {
+        let record_path = dist_info.join("RECORD");
+        let mut record_file = match fs::File::open(&record_path) {
+            Ok(record_file) => record_file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(Error::MissingRecord(record_path));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        read_record_file(&mut record_file)?
+    };
+
+    // Move each recorded file (or directory) into the stash, under a unique name.
+    fs::create_dir_all(stash)?;
+    let mut stashed = Vec::with_capacity(record.len());
+    for entry in &record {
+        if let Some(file) = StashedFile::stash(
+            site_packages.join(&entry.path),
+            stash.join(stashed.len().to_string()),
+        )? {
+            stashed.push(file);
+        }
+    }
+
+    // Remove anything left behind (e.g., `__pycache__` and empty directories). The recorded files
+    // have already been moved, so they're skipped, but the directories that contained them still
+    // need to be cleaned up.
+    let visited = stashed
+        .iter()
+        .filter_map(|file| file.original.parent())
+        .map(normalize_path)
+        .collect();
+    let mut summary = remove_recorded(site_packages, &record, visited)?;
+    summary.file_count += stashed.len();
+
+    Ok((summary, stashed))
+}
+
+/// A file (or directory) that was moved out of an environment by [`stash_wheel`].
+#[derive(Debug)]
+pub struct StashedFile {
+    /// The path of the file in the environment.
+    original: PathBuf,
+    /// The path to which the file was moved.
+    stashed: PathBuf,
+}
+
+impl StashedFile {
+    /// Move the file (or directory) at `original` to `stashed`.
+    ///
+    /// Returns `None` if there's nothing at `original`.
+    pub(crate) fn stash(original: PathBuf, stashed: PathBuf) -> Result<Option<Self>, Error> {
+        match move_path(&original, &stashed) {
+            Ok(()) => {
+                debug!("Stashed file: {}", original.display());
+                Ok(Some(Self { original, stashed }))
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Move the file back to its original location.
+    pub fn restore(&self) -> Result<(), Error> {
+        if let Some(parent) = self.original.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        move_path(&self.stashed, &self.original)?;
+        debug!("Restored file: {}", self.original.display());
+        Ok(())
+    }
+}
+
+/// Move a file or directory, falling back to a copy if it can't be renamed (e.g., because the
+/// destination is on a different filesystem).
+fn move_path(from: &Path, to: &Path) -> std::io::Result<()> {
+    match fs::rename(from, to) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Err(err),
+        Err(err) => {
+            if fs::symlink_metadata(from)?.is_dir() {
+                return Err(err);
+            }
+            fs::copy(from, to)?;
+            fs::remove_file(from)
+        }
+    }
+}
+
+/// Remove the files listed in a wheel's RECORD from the given `site-packages` directory, along
+/// with any directories that are left empty.
+///
+/// `visited` contains any directories from which files were already removed (e.g., by moving them
+/// elsewhere), which should be cleaned up along with those of the removed files.
+pub(crate) fn remove_recorded(
+    site_packages: &Path,
+    record: &[RecordEntry],
+    mut visited: BTreeSet<PathBuf>,
+) -> Result<Uninstall, Error> {
     let mut file_count = 0usize;
     let mut dir_count = 0usize;
+    let mut unrecorded_files = BTreeSet::new();
 
     // Uninstall the files, keeping track of any directories that are left empty.
-    let mut visited = BTreeSet::new();
-    for entry in &record {
+    for entry in record {
         let path = site_packages.join(&entry.path);
         match fs::remove_file(&path) {
             Ok(()) => {
@@ -97,8 +213,18 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
                 Err(err) => return Err(err.into()),
             };
 
-            // If the directory is not empty, we're done.
-            if read_dir.next().is_some() {
+            // If the directory is not empty, we're done. Any files left behind in a directory that
+            // contained recorded files weren't listed in the RECORD (e.g., files written by the
+            // package at runtime), and so are preserved.
+            let remaining = read_dir.collect::<Result<Vec<_>, _>>()?;
+            if !remaining.is_empty() {
+                if visited.contains(path) {
+                    for entry in remaining {
+                        if entry.file_type()?.is_file() {
+                            unrecorded_files.insert(entry.path());
+                        }
+                    }
+                }
                 break;
             }
 
@@ -115,12 +241,51 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
         }
     }
 
+    // Files left behind in a directory that's shared with another distribution (e.g., a namespace
+    // package) may belong to that distribution, so only report files in directories that the
+    // uninstalled distribution owned exclusively.
+    if !unrecorded_files.is_empty() {
+        let shared = recorded_dirs(site_packages)?;
+        unrecorded_files.retain(|path| {
+            path.parent()
+                .map_or(true, |parent| !shared.contains(parent))
+        });
+    }
+
     Ok(Uninstall {
         file_count,
         dir_count,
+        unrecorded_files: unrecorded_files.into_iter().collect(),
     })
 }
 
+/// Return the directories that contain files recorded by any of the distributions installed in
+/// the given `site-packages` directory.
+fn recorded_dirs(site_packages: &Path) -> Result<BTreeSet<PathBuf>, Error> {
+    let mut dirs = BTreeSet::new();
+    for entry in fs::read_dir(site_packages)? {
+        let path = entry?.path();
+        if !path.extension().is_some_and(|ext| ext == "dist-info") {
+            continue;
+        }
+        let mut record_file = match fs::File::open(path.join("RECORD")) {
+            Ok(record_file) => record_file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err.into()),
+        };
+        // An unreadable RECORD belongs to another distribution, and shouldn't fail the uninstall.
+        let Ok(record) = read_record_file(&mut record_file) else {
+            continue;
+        };
+        for entry in record {
+            if let Some(parent) = normalize_path(&site_packages.join(&entry.path)).parent() {
+                dirs.insert(parent.to_path_buf());
+            }
+        }
+    }
+    Ok(dirs)
+}
+
 /// Uninstall the egg represented by the `.egg-info` directory.
 ///
 /// See: <https://github.com/pypa/pip/blob/41587f5e0017bcd849f42b314dc8a34a7db75621/src/pip/_internal/req/req_uninstall.py#L483>
@@ -208,6 +373,7 @@ pub fn uninstall_egg(egg_info: &Path) -> Result<Uninstall, Error> {
     Ok(Uninstall {
         file_count,
         dir_count,
+        unrecorded_files: Vec::new(),
     })
 }
 
@@ -284,6 +450,7 @@ pub fn uninstall_legacy_editable(egg_link: &Path) -> Result<Uninstall, Error> {
     Ok(Uninstall {
         file_count,
         dir_count: 0usize,
+        unrecorded_files: Vec::new(),
     })
 }
 
@@ -293,6 +460,8 @@ pub struct Uninstall {
     pub file_count: usize,
     /// The number of directories that were removed during the uninstallation.
     pub dir_count: usize,
+    /// The files that were left in place, as they weren't listed in the RECORD.
+    pub unrecorded_files: Vec<PathBuf>,
 }
 
 /// Normalize a path, removing things like `.` and `..`.
@@ -537,6 +537,25 @@ impl Version {
         self
     }
 
+    /// Strip any trailing zeros from the release component, as in the canonical form of the
+    /// version (e.g., `1.0.0` becomes `1`, and `1.2.0rc1` becomes `1.2rc1`).
+    ///
+    /// At least one release number is always retained.
+    #[inline]
+    #[must_use]
+    pub fn without_trailing_zeros(self) -> Self {
+        let release = self.release();
+        let len = release
+            .iter()
+            .rposition(|n| *n != 0)
+            .map_or(1, |index| index + 1);
+        if len == release.len() {
+            return self;
+        }
+        let release = release[..len].to_vec();
+        self.with_release(release)
+    }
+
     /// Set the min-release component and return the updated version.
     ///
     /// The "min" component is internal-only, and does not exist in PEP 440.
@@ -3775,6 +3794,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn without_trailing_zeros() {
+        let strip = |s: &str| {
+            Version::from_str(s)
+                .unwrap()
+                .without_trailing_zeros()
+                .to_string()
+        };
+        assert_eq!(strip("1.0.0"), "1");
+        assert_eq!(strip("0.0"), "0");
+        assert_eq!(strip("1.2.0rc1"), "1.2rc1");
+        assert_eq!(strip("1.0.1"), "1.0.1");
+        assert_eq!(strip("2!1.0.post1+local.0"), "2!1.post1+local.0");
+    }
+
     // Tests our bespoke u64 decimal integer parser.
     #[test]
     fn parse_number_u64() {
@@ -338,6 +338,34 @@ impl Metadata10 {
     }
 }
 
+/// Python Package Metadata 1.0 and later as specified in
+/// <https://packaging.python.org/specifications/core-metadata/>.
+///
+/// This is a subset of the full metadata specification, and only includes the descriptive fields
+/// that are surfaced to users (e.g., in `uv pip tree`), rather than those used for resolution.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct DescriptiveMetadata {
+    pub summary: Option<String>,
+    pub license: Option<String>,
+}
+
+impl DescriptiveMetadata {
+    /// Parse the [`DescriptiveMetadata`] from a `METADATA` or `PKG-INFO` file.
+    pub fn parse_metadata(content: &[u8]) -> Result<Self, MetadataError> {
+        let headers = Headers::parse(content)?;
+        let summary = headers
+            .get_first_value("Summary")
+            .map(|summary| summary.trim().to_string())
+            .filter(|summary| !summary.is_empty());
+        let license = headers
+            .get_first_value("License")
+            .map(|license| license.trim().to_string())
+            .filter(|license| !license.is_empty());
+        Ok(Self { summary, license })
+    }
+}
+
 /// Parse a `Metadata-Version` field into a (major, minor) tuple.
 fn parse_version(metadata_version: &str) -> Result<(u8, u8), MetadataError> {
     let (major, minor) =
@@ -229,9 +229,23 @@ impl RequirementsTxt {
         working_dir: impl AsRef<Path>,
         client_builder: &BaseClientBuilder<'_>,
     ) -> Result<Self, RequirementsTxtFileError> {
-        let requirements_txt = requirements_txt.as_ref();
-        let working_dir = working_dir.as_ref();
+        Self::parse_with_ancestors(
+            requirements_txt.as_ref(),
+            working_dir.as_ref(),
+            client_builder,
+            &[],
+        )
+        .await
+    }
 
+    /// Parse a `requirements.txt` file that was included (via `-r` or `-c`) from each of the
+    /// `ancestors`, outermost first, such that include cycles can be detected.
+    async fn parse_with_ancestors(
+        requirements_txt: &Path,
+        working_dir: &Path,
+        client_builder: &BaseClientBuilder<'_>,
+        ancestors: &[PathBuf],
+    ) -> Result<Self, RequirementsTxtFileError> {
         let content =
             if requirements_txt.starts_with("http://") | requirements_txt.starts_with("https://") {
                 #[cfg(not(feature = "http"))]
@@ -273,12 +287,18 @@ impl RequirementsTxt {
             })?;
 
         let requirements_dir = requirements_txt.parent().unwrap_or(working_dir);
+        let ancestors = ancestors
+            .iter()
+            .cloned()
+            .chain(std::iter::once(include_key(requirements_txt, working_dir)))
+            .collect::<Vec<_>>();
         let data = Self::parse_inner(
             &content,
             working_dir,
             requirements_dir,
             client_builder,
             requirements_txt,
+            &ancestors,
         )
         .await
         .map_err(|err| RequirementsTxtFileError {
@@ -301,12 +321,16 @@ impl RequirementsTxt {
     /// the current working directory. However, relative paths to sub-files (e.g., `-r ../requirements.txt`)
     /// are resolved against the directory of the containing `requirements.txt` file, to match
     /// `pip`'s behavior.
+    ///
+    /// The `ancestors` are the files that (transitively) include the current file, including the
+    /// current file itself; including any of them again is rejected as a cycle.
     pub async fn parse_inner(
         content: &str,
         working_dir: &Path,
         requirements_dir: &Path,
         client_builder: &BaseClientBuilder<'_>,
         requirements_txt: &Path,
+        ancestors: &[PathBuf],
     ) -> Result<Self, RequirementsTxtParserError> {
         let mut s = Scanner::new(content);
 
@@ -341,14 +365,25 @@ impl RequirementsTxt {
                         } else {
                             requirements_dir.join(filename.as_ref())
                         };
-                    let sub_requirements =
-                        Box::pin(Self::parse(&sub_file, working_dir, client_builder))
-                            .await
-                            .map_err(|err| RequirementsTxtParserError::Subfile {
-                                source: Box::new(err),
-                                start,
-                                end,
-                            })?;
+                    if ancestors.contains(&include_key(&sub_file, working_dir)) {
+                        return Err(RequirementsTxtParserError::IncludeCycle {
+                            file: sub_file,
+                            start,
+                            end,
+                        });
+                    }
+                    let sub_requirements = Box::pin(Self::parse_with_ancestors(
+                        &sub_file,
+                        working_dir,
+                        client_builder,
+                        ancestors,
+                    ))
+                    .await
+                    .map_err(|err| RequirementsTxtParserError::Subfile {
+                        source: Box::new(err),
+                        start,
+                        end,
+                    })?;
 
                     // Disallow conflicting `--index-url` in nested `requirements` files.
                     if sub_requirements.index_url.is_some()
@@ -396,14 +431,25 @@ impl RequirementsTxt {
                         } else {
                             requirements_dir.join(filename.as_ref())
                         };
-                    let sub_constraints =
-                        Box::pin(Self::parse(&sub_file, working_dir, client_builder))
-                            .await
-                            .map_err(|err| RequirementsTxtParserError::Subfile {
-                                source: Box::new(err),
-                                start,
-                                end,
-                            })?;
+                    if ancestors.contains(&include_key(&sub_file, working_dir)) {
+                        return Err(RequirementsTxtParserError::IncludeCycle {
+                            file: sub_file,
+                            start,
+                            end,
+                        });
+                    }
+                    let sub_constraints = Box::pin(Self::parse_with_ancestors(
+                        &sub_file,
+                        working_dir,
+                        client_builder,
+                        ancestors,
+                    ))
+                    .await
+                    .map_err(|err| RequirementsTxtParserError::Subfile {
+                        source: Box::new(err),
+                        start,
+                        end,
+                    })?;
 
                     // Treat any nested requirements or constraints as constraints. This differs
                     // from `pip`, which seems to treat `-r` requirements in constraints files as
@@ -926,6 +972,11 @@ pub enum RequirementsTxtParserError {
         start: usize,
         end: usize,
     },
+    IncludeCycle {
+        file: PathBuf,
+        start: usize,
+        end: usize,
+    },
     NonUnicodeUrl {
         url: PathBuf,
     },
@@ -986,6 +1037,13 @@ impl Display for RequirementsTxtParserError {
             Self::Subfile { start, .. } => {
                 write!(f, "Error parsing included file at position {start}")
             }
+            Self::IncludeCycle { file, start, .. } => {
+                write!(
+                    f,
+                    "Cyclic include of `{}` at position {start}",
+                    file.user_display()
+                )
+            }
             Self::NonUnicodeUrl { url } => {
                 write!(
                     f,
@@ -1019,6 +1077,7 @@ impl std::error::Error for RequirementsTxtParserError {
             Self::Pep508 { source, .. } => Some(source),
             Self::ParsedUrl { source, .. } => Some(source),
             Self::Subfile { source, .. } => Some(source.as_ref()),
+            Self::IncludeCycle { .. } => None,
             Self::Parser { .. } => None,
             Self::NonUnicodeUrl { .. } => None,
             #[cfg(feature = "http")]
@@ -1140,6 +1199,14 @@ impl Display for RequirementsTxtFileError {
                     self.file.user_display(),
                 )
             }
+            RequirementsTxtParserError::IncludeCycle { file, start, .. } => {
+                write!(
+                    f,
+                    "Cyclic include of `{}` in `{}` at position {start}",
+                    file.user_display(),
+                    self.file.user_display(),
+                )
+            }
             RequirementsTxtParserError::NonUnicodeUrl { url } => {
                 write!(
                     f,
@@ -1185,6 +1252,16 @@ impl From<reqwest_middleware::Error> for RequirementsTxtParserError {
     }
 }
 
+/// Returns the key used to detect include cycles for the given `requirements.txt` path, such that
+/// different spellings of the same local file (e.g., `./a.txt` and `a.txt`) are treated as equal.
+fn include_key(requirements_txt: &Path, working_dir: &Path) -> PathBuf {
+    if requirements_txt.starts_with("http://") || requirements_txt.starts_with("https://") {
+        return requirements_txt.to_path_buf();
+    }
+    let path = working_dir.join(requirements_txt);
+    uv_fs::normalize_path(&path).unwrap_or(path)
+}
+
 /// Calculates the column and line offset of a given cursor based on the
 /// number of Unicode codepoints.
 fn calculate_row_column(content: &str, position: usize) -> (usize, usize) {
@@ -1954,6 +2031,87 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn nested_constraints() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            -c constraints/top.txt
+            flask
+        "})?;
+
+        // Relative includes are resolved against the directory of the including file.
+        let top = temp_dir.child("constraints").child("top.txt");
+        top.write_str(indoc! {"
+            -c base.txt
+            click<8
+        "})?;
+
+        let base = temp_dir.child("constraints").child("base.txt");
+        base.write_str(indoc! {"
+            jinja2<3
+        "})?;
+
+        let requirements = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await?;
+
+        let constraints = requirements
+            .constraints
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(constraints, ["click<8", "jinja2<3"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cyclic_constraints() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            -c constraints.txt
+            flask
+        "})?;
+
+        let constraints_txt = temp_dir.child("constraints.txt");
+        constraints_txt.write_str(indoc! {"
+            -c requirements.txt
+        "})?;
+
+        let error = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap_err();
+        let errors = anyhow::Error::new(error).chain().join("\n");
+
+        let requirement_txt = regex::escape(&requirements_txt.path().user_display().to_string());
+        let constraints_txt = regex::escape(&constraints_txt.path().user_display().to_string());
+        let filters = vec![
+            (requirement_txt.as_str(), "<REQUIREMENTS_TXT>"),
+            (constraints_txt.as_str(), "<CONSTRAINTS_TXT>"),
+        ];
+        insta::with_settings!({
+            filters => filters
+        }, {
+            insta::assert_snapshot!(errors, @r###"
+            Error parsing included file in `<REQUIREMENTS_TXT>` at position 0
+            Cyclic include of `<REQUIREMENTS_TXT>` in `<CONSTRAINTS_TXT>` at position 0
+            "###);
+        });
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn comments() -> Result<()> {
         let temp_dir = assert_fs::TempDir::new()?;
@@ -1,6 +1,6 @@
 use std::process::Stdio;
 use tokio::process::Command;
-use tracing::{instrument, trace, warn};
+use tracing::{debug, instrument, trace, warn};
 use url::Url;
 
 use crate::credentials::Credentials;
@@ -93,7 +93,9 @@ impl KeyringProvider {
             .stdout(Stdio::piped())
             .stderr(Stdio::inherit())
             .spawn()
-            .inspect_err(|err| warn!("Failure running `keyring` command: {err}"))
+            // The `keyring` command is often unavailable (e.g., not installed), in which case we
+            // fall back to unauthenticated requests.
+            .inspect_err(|err| debug!("Failure running `keyring` command: {err}"))
             .ok()?;
 
         let output = child
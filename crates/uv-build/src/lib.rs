@@ -63,6 +63,28 @@ static LD_NOT_FOUND_RE: Lazy<Regex> = Lazy::new(|| {
 static WHEEL_NOT_FOUND_RE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"error: invalid command 'bdist_wheel'").unwrap());
 
+/// e.g. `ModuleNotFoundError: No module named 'setuptools'`
+static MODULE_NOT_FOUND_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^ModuleNotFoundError: No module named '([a-zA-Z0-9_\-]+)(?:\.[^']*)?'$").unwrap()
+});
+
+/// Common build dependencies, keyed by the top-level module that they provide.
+///
+/// Module names don't necessarily match package names (e.g., `yaml` is provided by `PyYAML`), so
+/// we only suggest a package for modules that are known to be provided by it.
+static MODULE_PACKAGES: &[(&str, &str)] = &[
+    ("Cython", "cython"),
+    ("flit_core", "flit-core"),
+    ("hatchling", "hatchling"),
+    ("maturin", "maturin"),
+    ("numpy", "numpy"),
+    ("pkg_resources", "setuptools"),
+    ("pybind11", "pybind11"),
+    ("setuptools", "setuptools"),
+    ("setuptools_scm", "setuptools-scm"),
+    ("wheel", "wheel"),
+];
+
 /// The default backend to use when PEP 517 is used without a `build-system` section.
 static DEFAULT_BACKEND: Lazy<Pep517Backend> = Lazy::new(|| Pep517Backend {
     backend: "setuptools.build_meta:__legacy__".to_string(),
@@ -122,6 +144,7 @@ enum MissingLibrary {
     Header(String),
     Linker(String),
     PythonPackage(String),
+    PythonModule(String),
 }
 
 #[derive(Debug, Error)]
@@ -155,6 +178,13 @@ impl Display for MissingHeaderCause {
                     package = package, version_id = self.version_id
                 )
             }
+            MissingLibrary::PythonModule(module) => {
+                write!(
+                    f,
+                    "This error likely indicates that you need to install a package that provides the `{module}` module into the build environment for {version_id}",
+                    module = module, version_id = self.version_id
+                )
+            }
         }
     }
 }
@@ -183,6 +213,14 @@ impl Error {
                 Some(MissingLibrary::Linker(library.to_string()))
             } else if WHEEL_NOT_FOUND_RE.is_match(line.trim()) {
                 Some(MissingLibrary::PythonPackage("wheel".to_string()))
+            } else if let Some((_, [module])) = MODULE_NOT_FOUND_RE
+                .captures(line.trim())
+                .map(|c| c.extract())
+            {
+                match MODULE_PACKAGES.iter().find(|(name, _)| *name == module) {
+                    Some(&(_, package)) => Some(MissingLibrary::PythonPackage(package.to_string())),
+                    None => Some(MissingLibrary::PythonModule(module.to_string())),
+                }
             } else {
                 None
             }
@@ -428,8 +466,12 @@ impl SourceBuild {
                 .map_err(|err| *err)?;
 
         // Create a virtual environment, or install into the shared environment if requested.
+        // Package-specific isolation is expected to be resolved by the caller (see
+        // `BuildIsolation::for_package`); if not, the build is isolated.
         let venv = match build_isolation {
-            BuildIsolation::Isolated => uv_virtualenv::create_venv(
+            BuildIsolation::Isolated
+            | BuildIsolation::Inherited(_)
+            | BuildIsolation::SharedPackage(..) => uv_virtualenv::create_venv(
                 temp_dir.path(),
                 interpreter.clone(),
                 uv_virtualenv::Prompt::None,
@@ -439,6 +481,18 @@ impl SourceBuild {
             BuildIsolation::Shared(venv) => venv.clone(),
         };
 
+        // If requested, expose the packages installed in the target environment via a `.pth` file,
+        // such that they're importable, but shadowed by the build requirements.
+        if let BuildIsolation::Inherited(target) = build_isolation {
+            if let Some(site_packages) = venv.site_packages().next() {
+                let contents = target
+                    .site_packages()
+                    .map(|path| format!("{}\n", path.simplified().display()))
+                    .collect::<String>();
+                fs::write(site_packages.join("_uv_inherited.pth"), contents)?;
+            }
+        }
+
         // Setup the build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
         if build_isolation.is_isolated() {
@@ -1242,4 +1296,75 @@ mod test {
             @"This error likely indicates that you need to `uv pip install wheel` into the build environment for pygraphviz-1.11"
         );
     }
+
+    #[test]
+    fn missing_python_module() {
+        let output = Output {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            stdout: Vec::new(),
+            stderr: indoc!(
+                r#"
+            Traceback (most recent call last):
+              File "<string>", line 8, in <module>
+              File "/usr/lib/python3.12/importlib/__init__.py", line 90, in import_module
+                return _bootstrap._gcd_import(name[level:], package, level)
+                       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+              File "<frozen importlib._bootstrap>", line 1387, in _gcd_import
+              File "<frozen importlib._bootstrap>", line 1360, in _find_and_load
+              File "<frozen importlib._bootstrap>", line 1310, in _find_and_load_unlocked
+              File "<frozen importlib._bootstrap>", line 488, in _call_with_frames_removed
+              File "<frozen importlib._bootstrap>", line 1387, in _gcd_import
+              File "<frozen importlib._bootstrap>", line 1360, in _find_and_load
+              File "<frozen importlib._bootstrap>", line 1324, in _find_and_load_unlocked
+            ModuleNotFoundError: No module named 'setuptools'
+                "#
+            )
+            .as_bytes()
+            .to_vec(),
+        };
+
+        let err = Error::from_command_output(
+            "Build backend failed to determine metadata through `prepare_metadata_for_build_wheel`"
+                .to_string(),
+            &output,
+            "pygraphviz-1.11",
+        );
+        assert!(matches!(err, Error::MissingHeader { .. }));
+        insta::assert_snapshot!(
+            std::error::Error::source(&err).unwrap(),
+            @"This error likely indicates that you need to `uv pip install setuptools` into the build environment for pygraphviz-1.11"
+        );
+    }
+
+    #[test]
+    fn missing_unknown_python_module() {
+        let output = Output {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            stdout: Vec::new(),
+            stderr: indoc!(
+                r#"
+            Traceback (most recent call last):
+              File "<string>", line 8, in <module>
+              File "/usr/lib/python3.12/importlib/__init__.py", line 90, in import_module
+                return _bootstrap._gcd_import(name[level:], package, level)
+                       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+            ModuleNotFoundError: No module named 'yaml'
+                "#
+            )
+            .as_bytes()
+            .to_vec(),
+        };
+
+        let err = Error::from_command_output(
+            "Build backend failed to determine metadata through `prepare_metadata_for_build_wheel`"
+                .to_string(),
+            &output,
+            "pygraphviz-1.11",
+        );
+        assert!(matches!(err, Error::MissingHeader { .. }));
+        insta::assert_snapshot!(
+            std::error::Error::source(&err).unwrap(),
+            @"This error likely indicates that you need to install a package that provides the `yaml` module into the build environment for pygraphviz-1.11"
+        );
+    }
 }
@@ -10,17 +10,11 @@ pub trait CompatArgs {
 /// Arguments for `pip-compile` compatibility.
 ///
 /// These represent a subset of the `pip-compile` interface that uv supports by default.
-/// For example, users often pass `--allow-unsafe`, which is unnecessary with uv. But it's a
-/// nice user experience to warn, rather than fail, when users pass `--allow-unsafe`.
+/// For example, users often pass `--no-reuse-hashes`, which is unnecessary with uv. But it's a
+/// nice user experience to warn, rather than fail, when users pass `--no-reuse-hashes`.
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PipCompileCompatArgs {
-    #[clap(long, hide = true)]
-    allow_unsafe: bool,
-
-    #[clap(long, hide = true)]
-    no_allow_unsafe: bool,
-
     #[clap(long, hide = true)]
     reuse_hashes: bool,
 
@@ -62,6 +56,31 @@ pub struct PipCompileCompatArgs {
 
     #[clap(long, hide = true)]
     pip_args: Option<String>,
+
+    /// Warn about, rather than reject, `pip-compile` options that have no equivalent in uv.
+    ///
+    /// Intended to ease migration from wrapper scripts that pass `pip-compile` options through
+    /// verbatim. The following options are ignored with a warning: `--reuse-hashes`,
+    /// `--resolver=legacy`, `--max-rounds`, `--emit-trusted-host`, `--config`, `--emit-options`,
+    /// and `--pip-args`.
+    ///
+    /// Options that affect how uv connects to the index (`--client-cert` and `--trusted-host`)
+    /// are always rejected.
+    #[clap(long)]
+    ignore_unknown_flags: bool,
+}
+
+impl PipCompileCompatArgs {
+    /// Reject an unsupported `pip-compile` option, or, with `--ignore-unknown-flags`, warn about
+    /// it and continue.
+    fn unsupported(&self, message: &str) -> Result<()> {
+        if self.ignore_unknown_flags {
+            warn_user!("{message} Ignoring due to `--ignore-unknown-flags`.");
+            Ok(())
+        } else {
+            Err(anyhow!("{message}"))
+        }
+    }
 }
 
 impl CompatArgs for PipCompileCompatArgs {
@@ -69,22 +88,13 @@ impl CompatArgs for PipCompileCompatArgs {
     ///
     /// This method will warn when an argument is passed that has no effect but matches uv's
     /// behavior. If an argument is passed that does _not_ match uv's behavior (e.g.,
-    /// `--no-build-isolation`), this method will return an error.
+    /// `--no-build-isolation`), this method will return an error, unless the argument is safe to
+    /// ignore and `--ignore-unknown-flags` was provided.
     fn validate(&self) -> Result<()> {
-        if self.allow_unsafe {
-            warn_user!(
-                "pip-compile's `--allow-unsafe` has no effect (uv can safely pin `pip` and other packages)."
-            );
-        }
-
-        if self.no_allow_unsafe {
-            warn_user!("pip-compile's `--no-allow-unsafe` has no effect (uv can safely pin `pip` and other packages).");
-        }
-
         if self.reuse_hashes {
-            return Err(anyhow!(
-                "pip-compile's `--reuse-hashes` is unsupported (uv doesn't reuse hashes)."
-            ));
+            self.unsupported(
+                "pip-compile's `--reuse-hashes` is unsupported (uv doesn't reuse hashes).",
+            )?;
         }
 
         if self.no_reuse_hashes {
@@ -101,17 +111,17 @@ impl CompatArgs for PipCompileCompatArgs {
                     );
                 }
                 Resolver::Legacy => {
-                    return Err(anyhow!(
-                        "pip-compile's `--resolver=legacy` is unsupported (uv always backtracks)."
-                    ));
+                    self.unsupported(
+                        "pip-compile's `--resolver=legacy` is unsupported (uv always backtracks).",
+                    )?;
                 }
             }
         }
 
         if self.max_rounds.is_some() {
-            return Err(anyhow!(
-                "pip-compile's `--max-rounds` is unsupported (uv always resolves until convergence)."
-            ));
+            self.unsupported(
+                "pip-compile's `--max-rounds` is unsupported (uv always resolves until convergence).",
+            )?;
         }
 
         if self.client_cert.is_some() {
@@ -127,9 +137,9 @@ impl CompatArgs for PipCompileCompatArgs {
         }
 
         if self.emit_trusted_host {
-            return Err(anyhow!(
-                "pip-compile's `--emit-trusted-host` is unsupported (uv always requires HTTPS)."
-            ));
+            self.unsupported(
+                "pip-compile's `--emit-trusted-host` is unsupported (uv always requires HTTPS).",
+            )?;
         }
 
         if self.no_emit_trusted_host {
@@ -139,9 +149,9 @@ impl CompatArgs for PipCompileCompatArgs {
         }
 
         if self.config.is_some() {
-            return Err(anyhow!(
-                "pip-compile's `--config` is unsupported (uv does not use a configuration file)."
-            ));
+            self.unsupported(
+                "pip-compile's `--config` is unsupported (uv does not use a configuration file).",
+            )?;
         }
 
         if self.no_config {
@@ -151,9 +161,9 @@ impl CompatArgs for PipCompileCompatArgs {
         }
 
         if self.emit_options {
-            return Err(anyhow!(
-                "pip-compile's `--emit-options` is unsupported (uv never emits options)."
-            ));
+            self.unsupported(
+                "pip-compile's `--emit-options` is unsupported (uv never emits options).",
+            )?;
         }
 
         if self.no_emit_options {
@@ -161,9 +171,9 @@ impl CompatArgs for PipCompileCompatArgs {
         }
 
         if self.pip_args.is_some() {
-            return Err(anyhow!(
-                "pip-compile's `--pip-args` is unsupported (try passing arguments to uv directly)."
-            ));
+            self.unsupported(
+                "pip-compile's `--pip-args` is unsupported (try passing arguments to uv directly).",
+            )?;
         }
 
         Ok(())
@@ -9,10 +9,14 @@ use clap::{Args, Parser, Subcommand};
 use distribution_types::{FlatIndexLocation, IndexUrl};
 use uv_cache::CacheArgs;
 use uv_configuration::{
-    ConfigSettingEntry, IndexStrategy, KeyringProviderType, PackageNameSpecifier, TargetTriple,
+    ConfigSettingEntry, IndexStrategy, KeyringProviderType, MarkerOverride,
+    PackageConfigSettingEntry, PackageNameSpecifier, TargetTriple, UpgradeStrategy,
 };
 use uv_normalize::{ExtraName, PackageName};
-use uv_resolver::{AnnotationStyle, ExcludeNewer, PreReleaseMode, ResolutionMode};
+use uv_resolver::{
+    AnnotationStyle, ExcludeNewer, ExcludeNewerPackageEntry, LocalVersionPolicy, PreReleaseMode,
+    ResolutionMode,
+};
 use uv_toolchain::{PythonVersion, ToolchainPreference};
 
 pub mod compat;
@@ -39,6 +43,92 @@ pub enum ListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum TreeFormat {
+    /// Display the dependency tree in a human-readable format.
+    #[default]
+    Text,
+    /// Display each dependency edge as a `parent,child,parent_version,child_version` row, in CSV
+    /// format.
+    Csv,
+    /// Display the dependency tree as nested JSON.
+    ///
+    /// Each package is expanded at its first occurrence; subsequent occurrences are emitted as
+    /// references (`{"ref": "<id>"}`). The full definition of each package is included in a
+    /// top-level `packages` dictionary, keyed by its normalized name.
+    Json,
+    /// Display the dependency graph as flattened JSON, in which the roots and dependencies of each
+    /// package are listed by ID.
+    JsonFlat,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum CompileFormat {
+    /// Write the output file in the `requirements.txt` format, with extras stripped from each
+    /// pinned requirement (unless `--no-strip-extras` is provided).
+    #[default]
+    RequirementsTxt,
+    /// Write the output file in the `requirements.txt` format, retaining any extras on each pinned
+    /// requirement (e.g., `requests[socks]==2.31.0`).
+    ///
+    /// Equivalent to `--no-strip-extras`.
+    RequirementsWithExtras,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum TreeField {
+    /// The name of the package.
+    Name,
+    /// The installed version of the package.
+    Version,
+    /// The path to the package's installed metadata directory.
+    Path,
+    /// The license declared in the package's metadata.
+    License,
+    /// The summary declared in the package's metadata.
+    Summary,
+    /// The `Requires-Python` specifier declared in the package's metadata.
+    RequiresPython,
+    /// The hash of the installed artifact, as recorded in the package's `direct_url.json`.
+    Hash,
+    /// The origin of the package (`git`, `url`, `path`, or `registry`), as recorded in the
+    /// package's `direct_url.json`.
+    Source,
+    /// Whether the package's files are linked from the cache (`linked`) or were copied into the
+    /// environment (`copied`).
+    Reused,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ProgressDisplay {
+    /// Display progress bars when writing to a terminal.
+    #[default]
+    Auto,
+    /// Display progress bars, even when not writing to a terminal.
+    Bar,
+    /// Report progress as plain lines of text, without progress bars or cursor movement.
+    Plain,
+    /// Don't display any progress.
+    None,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum TreeConnector {
+    /// Connect each package to its dependents with Unicode box-drawing characters (e.g., `├──`).
+    #[default]
+    Unicode,
+    /// Connect each package to its dependents with ASCII characters (e.g., `|--`).
+    Ascii,
+    /// Indent each package with spaces alone, without any connectors.
+    Spaces,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum TreeColorBy {
+    /// Color each package based on its depth in the dependency tree.
+    Depth,
+}
+
 fn extra_name_with_clap_error(arg: &str) -> Result<ExtraName> {
     ExtraName::from_str(arg).map_err(|_err| {
         anyhow!(
@@ -118,8 +208,19 @@ pub struct GlobalArgs {
     #[arg(global = true, long, overrides_with("offline"), hide = true)]
     pub no_offline: bool,
 
+    /// The number of times to retry a network request that failed due to a transient error.
+    ///
+    /// Only timeouts, connection errors, and `5xx`, `408`, and `429` responses are retried, with
+    /// exponential backoff between attempts. If the server provides a `Retry-After` header, the
+    /// requested delay is respected instead. Delays are capped at 60 seconds.
+    #[arg(global = true, long, env = "UV_HTTP_RETRIES", value_name = "N")]
+    pub retries: Option<u32>,
+
     /// Whether to use system or uv-managed Python toolchains.
-    #[arg(global = true, long)]
+    ///
+    /// Also determines which interpreters are considered when discovering the environment for the
+    /// `pip` commands.
+    #[arg(global = true, long, alias = "python-preference")]
     pub toolchain_preference: Option<ToolchainPreference>,
 
     /// Whether to enable experimental, preview features.
@@ -260,6 +361,8 @@ pub enum PipCommand {
     Tree(PipTreeArgs),
     /// Verify installed packages have compatible dependencies.
     Check(PipCheckArgs),
+    /// Emit a JSON report describing an environment and its installed packages.
+    Inspect(PipInspectArgs),
 }
 
 #[derive(Subcommand)]
@@ -359,6 +462,24 @@ pub struct PipCompileArgs {
     #[arg(long, short, env = "UV_CONSTRAINT", value_delimiter = ' ', value_parser = parse_maybe_file_path)]
     pub constraint: Vec<Maybe<PathBuf>>,
 
+    /// Constrain build dependencies using the given requirements files when building source
+    /// distributions.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed. Build constraints are applied when resolving the build
+    /// requirements (e.g., PEP 518 `build-system.requires`) of any source distributions, but not
+    /// when resolving the requirements themselves.
+    #[arg(long, env = "UV_BUILD_CONSTRAINT", value_delimiter = ' ', value_parser = parse_maybe_file_path)]
+    pub build_constraint: Vec<Maybe<PathBuf>>,
+
+    /// Constrain versions to those pinned in the given compiled lockfiles.
+    ///
+    /// Each `==` pin in the given `requirements.txt`-format lockfiles (e.g., as produced by
+    /// `uv pip compile`) is applied as a constraint, such that any packages shared with those
+    /// lockfiles resolve to the same versions. Other entries are ignored.
+    #[arg(long, value_parser = parse_file_path)]
+    pub constraint_from_lock: Vec<PathBuf>,
+
     /// Override versions using the given requirements files.
     ///
     /// Overrides files are `requirements.txt`-like files that force a specific version of a
@@ -402,6 +523,64 @@ pub struct PipCompileArgs {
     #[arg(long, short)]
     pub output_file: Option<PathBuf>,
 
+    /// Verify that the output file is up-to-date with the input requirements, without
+    /// overwriting it.
+    ///
+    /// If the resolution differs from the contents of the output file, the differences are
+    /// displayed, and uv exits with a non-zero status code. Requires `--output-file`.
+    #[arg(long, requires = "output_file")]
+    pub verify: bool,
+
+    /// Keep a backup of the existing output file at `<output-file>.bak` before overwriting it.
+    ///
+    /// Requires `--output-file`.
+    #[arg(long, requires = "output_file")]
+    pub backup: bool,
+
+    /// Check that the input hash recorded in the output file matches the current inputs, without
+    /// resolving.
+    ///
+    /// If the input requirements, constraints, overrides, index configuration, or any other
+    /// settings that affect the output file have changed since the output file was generated with
+    /// `--emit-input-hash`, uv exits with a non-zero status code. Requires `--output-file`.
+    #[arg(long, requires = "output_file")]
+    pub check_input_hash: bool,
+
+    /// The policy for PEP 440 local versions (e.g., `1.2.3+local`).
+    ///
+    /// By default (`keep`), local versions are allowed during resolution and written to the output
+    /// file as-is. With `strip`, local versions are allowed, but the local segment is omitted from
+    /// the output file. With `forbid`, local versions are excluded from resolution.
+    ///
+    /// `strip` can't be combined with `--generate-hashes`, since the generated hashes would belong
+    /// to the local versions.
+    #[arg(long, value_enum, default_value_t = LocalVersionPolicy::default())]
+    pub local_version_policy: LocalVersionPolicy,
+
+    /// Write each pinned version in its canonical PEP 440 form, stripping any trailing zeros from
+    /// the release segment (e.g., `iniconfig==2` rather than `iniconfig==2.0.0`).
+    ///
+    /// By default, versions are written as published by the index, such that equivalent versions
+    /// may be written differently depending on the index that served them.
+    #[arg(long)]
+    pub strip_trailing_zeros: bool,
+
+    /// Prune the given package from the output file, along with any of its dependencies that
+    /// aren't required by another package.
+    ///
+    /// Unlike `--no-emit-package`, the dependencies of a pruned package are omitted too, unless
+    /// they're still reachable from the input requirements through some other path.
+    #[arg(long)]
+    pub prune: Vec<PackageName>,
+
+    /// The format in which to write the output file.
+    ///
+    /// By default (`requirements-txt`), extras are stripped from each pinned requirement. With
+    /// `requirements-with-extras`, extras are retained (e.g., `requests[socks]==2.31.0`), for
+    /// installers that need them to pull in the optional dependencies.
+    #[arg(long, value_enum, default_value_t = CompileFormat::default())]
+    pub format: CompileFormat,
+
     /// Include extras in the output file.
     ///
     /// By default, `uv` strips extras, as any packages pulled in by the extras are already included
@@ -507,6 +686,24 @@ pub struct PipCompileArgs {
     #[arg(long, overrides_with("no_build_isolation"), hide = true)]
     pub build_isolation: bool,
 
+    /// Settings to pass to the PEP 517 build backend of a specific package, specified as
+    /// `PACKAGE:KEY=VALUE` pairs.
+    ///
+    /// The settings are passed in addition to those provided via `--config-setting`, and only
+    /// affect packages that are built from source during resolution.
+    #[arg(long, alias = "config-settings-package")]
+    pub config_setting_package: Option<Vec<PackageConfigSettingEntry>>,
+
+    /// Ignore the `tool.uv.sources` table when resolving dependencies.
+    ///
+    /// All requirements are resolved from the configured indexes, as if no source overrides
+    /// (e.g., Git, path, or workspace sources) were declared in any `pyproject.toml`.
+    #[arg(long, overrides_with("sources"))]
+    pub no_sources: bool,
+
+    #[arg(long, overrides_with("no_sources"), hide = true)]
+    pub sources: bool,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary code. The cached wheels of already-built
@@ -568,6 +765,15 @@ pub struct PipCompileArgs {
     #[arg(long)]
     pub python_platform: Option<TargetTriple>,
 
+    /// Override a field of the marker environment for which requirements should be resolved, as in
+    /// `--marker implementation_name=pypy`; may be provided more than once.
+    ///
+    /// Applied on top of any `--python-version` and `--python-platform`. Accepts any PEP 508
+    /// environment marker (e.g., `implementation_name`, `platform_python_implementation`,
+    /// `sys_platform`). Tags used to select compatible wheels are unaffected.
+    #[arg(long, value_name = "KEY=VALUE", conflicts_with("universal"))]
+    pub marker: Vec<MarkerOverride>,
+
     /// Perform a universal resolution, attempting to generate a single `requirements.txt` output
     /// file that is compatible with all operating systems, architectures, and Python
     /// implementations.
@@ -586,11 +792,33 @@ pub struct PipCompileArgs {
     pub no_universal: bool,
 
     /// Specify a package to omit from the output resolution. Its dependencies will still be
-    /// included in the resolution. Equivalent to pip-compile's `--unsafe-package` option.
-    #[arg(long, alias = "unsafe-package")]
+    /// included in the resolution.
+    #[arg(long)]
     pub no_emit_package: Option<Vec<PackageName>>,
 
+    /// Specify a package to consider unsafe, omitting it from the generated output file.
+    ///
+    /// The package (and its dependencies) will still be included in the resolution, but will be
+    /// listed in a trailing comment rather than pinned. May be provided multiple times, in
+    /// addition to the built-in unsafe packages. Unlike the built-in unsafe packages, packages
+    /// provided via `--unsafe-package` are omitted even when `--allow-unsafe` is set.
+    #[arg(long)]
+    pub unsafe_package: Option<Vec<PackageName>>,
+
+    /// Include "unsafe" packages in the generated output file.
+    ///
+    /// By default, packages that are considered unsafe to pin in a requirements file (`pip`,
+    /// `setuptools`, and `distribute`) are omitted from the output, matching pip-compile's
+    /// behavior. Packages provided via `--unsafe-package` are omitted regardless.
+    #[arg(long, overrides_with("no_allow_unsafe"))]
+    pub allow_unsafe: bool,
+
+    #[arg(long, overrides_with("allow_unsafe"), hide = true)]
+    pub no_allow_unsafe: bool,
+
     /// Include `--index-url` and `--extra-index-url` entries in the generated output file.
+    ///
+    /// If the index is disabled via `--no-index`, a `--no-index` entry is included instead.
     #[arg(long, overrides_with("no_emit_index_url"))]
     pub emit_index_url: bool,
 
@@ -617,6 +845,9 @@ pub struct PipCompileArgs {
     /// The pinned dependencies may be valid even when the marker expression is
     /// false, but when the expression is true, the requirements are known to
     /// be correct.
+    ///
+    /// In universal mode, the marker expression reflects the minimum Python
+    /// version, if one was provided via `--python-version`.
     #[arg(long, overrides_with("no_emit_marker_expression"), hide = true)]
     pub emit_marker_expression: bool,
 
@@ -631,6 +862,86 @@ pub struct PipCompileArgs {
     #[arg(long, overrides_with("emit_index_annotation"), hide = true)]
     pub no_emit_index_annotation: bool,
 
+    /// Include comment annotations indicating the reason each yanked package was yanked (e.g.,
+    /// `# YANKED: security issue`).
+    ///
+    /// Yanked versions are only selected when pinned exactly, so these annotations highlight
+    /// pins that should be reviewed.
+    #[arg(long, overrides_with("no_keep_yanked_reason"))]
+    pub keep_yanked_reason: bool,
+
+    #[arg(long, overrides_with("keep_yanked_reason"), hide = true)]
+    pub no_keep_yanked_reason: bool,
+
+    /// Include comment annotations indicating the packages that were built from source (e.g.,
+    /// `# built from source`).
+    ///
+    /// Installing these packages requires a build backend, and possibly a compiler, unless a
+    /// pre-built wheel is available at install time.
+    #[arg(long, overrides_with("no_emit_build_isolation_note"))]
+    pub emit_build_isolation_note: bool,
+
+    #[arg(long, overrides_with("emit_build_isolation_note"), hide = true)]
+    pub no_emit_build_isolation_note: bool,
+
+    /// Include a hash of the inputs to the resolution in the output file's header (e.g.,
+    /// `# input-hash: sha256:...`).
+    ///
+    /// The hash covers the input requirements, constraints, and overrides, along with the index
+    /// configuration and any other settings that affect the resolution or the contents of the
+    /// output file (e.g., `--python-version`), and can be validated without resolving via
+    /// `--check-input-hash`.
+    #[arg(long, overrides_with("no_emit_input_hash"))]
+    pub emit_input_hash: bool,
+
+    #[arg(long, overrides_with("emit_input_hash"), hide = true)]
+    pub no_emit_input_hash: bool,
+
+    /// Include the Python version targeted by the resolution in the output file's header (e.g.,
+    /// `# python-version: 3.11`).
+    ///
+    /// The version is taken from `--python-version`, if provided, and otherwise from the
+    /// interpreter used for the resolution.
+    #[arg(long, overrides_with("no_emit_python_version"))]
+    pub emit_python_version: bool,
+
+    #[arg(long, overrides_with("emit_python_version"), hide = true)]
+    pub no_emit_python_version: bool,
+
+    /// Include the time at which the output file was generated in its header (e.g.,
+    /// `# generated-at: 2024-03-25T00:00:00Z`).
+    ///
+    /// By default, the output file is not timestamped, such that repeated resolutions with the same
+    /// inputs produce identical output.
+    #[arg(long, overrides_with("no_emit_timestamp"))]
+    pub emit_timestamp: bool,
+
+    #[arg(long, overrides_with("emit_timestamp"), hide = true)]
+    pub no_emit_timestamp: bool,
+
+    /// Include the workspace members that contributed requirements in the header of the output
+    /// file (e.g., `# workspace-members:`).
+    ///
+    /// Members are listed if they're provided as an input (e.g., via their `pyproject.toml`), or if
+    /// they're included in the resolution. Has no effect outside of a workspace.
+    #[arg(long, overrides_with("no_emit_workspace_members"))]
+    pub emit_workspace_members: bool,
+
+    #[arg(long, overrides_with("emit_workspace_members"), hide = true)]
+    pub no_emit_workspace_members: bool,
+
+    /// The maximum number of distributions to download concurrently.
+    ///
+    /// Values below one are treated as one. Defaults to 50.
+    #[arg(long, env = "UV_CONCURRENT_DOWNLOADS")]
+    pub concurrent_downloads: Option<usize>,
+
+    /// The maximum number of concurrent requests for package metadata during resolution.
+    ///
+    /// Values below one are treated as one. Defaults to 50.
+    #[arg(long, env = "UV_CONCURRENT_REQUESTS")]
+    pub concurrent_requests: Option<usize>,
+
     #[command(flatten)]
     pub compat_args: compat::PipCompileCompatArgs,
 }
@@ -857,6 +1168,18 @@ pub struct PipSyncArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// The maximum number of distributions to download concurrently.
+    ///
+    /// Values below one are treated as one. Defaults to 50.
+    #[arg(long, env = "UV_CONCURRENT_DOWNLOADS")]
+    pub concurrent_downloads: Option<usize>,
+
+    /// The maximum number of concurrent requests for package metadata during resolution.
+    ///
+    /// Values below one are treated as one. Defaults to 50.
+    #[arg(long, env = "UV_CONCURRENT_REQUESTS")]
+    pub concurrent_requests: Option<usize>,
+
     #[command(flatten)]
     pub compat_args: compat::PipSyncCompatArgs,
 }
@@ -882,6 +1205,19 @@ pub struct PipInstallArgs {
     #[arg(long, short, group = "sources")]
     pub editable: Vec<String>,
 
+    /// Reinstall the given installed package at its installed version, without resolving or
+    /// modifying its dependencies; may be provided more than once.
+    ///
+    /// Useful for repairing a broken package. The package must already be installed, and its
+    /// installed version must be available from the configured indexes.
+    #[arg(
+        long,
+        group = "sources",
+        value_name = "NAME",
+        conflicts_with_all = ["package", "requirement", "editable"]
+    )]
+    pub only: Vec<PackageName>,
+
     /// Constrain versions using the given requirements files.
     ///
     /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
@@ -904,6 +1240,14 @@ pub struct PipInstallArgs {
     #[arg(long, value_parser = parse_file_path)]
     pub r#override: Vec<PathBuf>,
 
+    /// Override versions using the given requirements (e.g., `--dependency-overrides
+    /// "idna==3.3"`); may be provided more than once.
+    ///
+    /// These are treated identically to the contents of an overrides file passed via
+    /// `--override`.
+    #[arg(long, value_name = "REQUIREMENT")]
+    pub dependency_overrides: Vec<String>,
+
     /// Include optional dependencies from the extra group name; may be provided more than once.
     /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
     #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
@@ -923,6 +1267,15 @@ pub struct PipInstallArgs {
     #[command(flatten)]
     pub refresh: RefreshArgs,
 
+    /// The strategy to use when upgrading packages with `--upgrade`.
+    ///
+    /// By default, uv uses the `only-if-needed` strategy, which upgrades the packages that were
+    /// explicitly requested, but leaves their dependencies at the installed versions unless they
+    /// no longer satisfy the requirements of the upgraded packages. The `eager` strategy upgrades
+    /// all dependencies of the requested packages.
+    #[arg(long, value_enum)]
+    pub upgrade_strategy: Option<UpgradeStrategy>,
+
     /// Ignore package dependencies, instead only installing those packages explicitly listed
     /// on the command line or in the requirements files.
     #[arg(long, overrides_with("deps"))]
@@ -953,6 +1306,39 @@ pub struct PipInstallArgs {
     #[arg(long, overrides_with("require_hashes"), hide = true)]
     pub no_require_hashes: bool,
 
+    #[arg(long, overrides_with("no_verify_hashes"), hide = true)]
+    pub verify_hashes: bool,
+
+    /// Ignore any hashes provided in the requirements, when `--require-hashes` is not enabled.
+    ///
+    /// By default, requirements that include hashes are validated against them, even outside of
+    /// hash-checking mode, while requirements without hashes are installed without validation.
+    #[arg(long, overrides_with("verify_hashes"))]
+    pub no_verify_hashes: bool,
+
+    /// The name to record in the `INSTALLER` file of each installed package.
+    ///
+    /// Tools that manage environments can use this to attribute installs to themselves, rather
+    /// than to `uv`.
+    #[arg(long, default_value = "uv", value_name = "NAME")]
+    pub installer_name: String,
+
+    /// Don't warn about installed packages with incompatible dependencies when validating the
+    /// environment with `--strict`.
+    ///
+    /// Other diagnostics, such as missing dependencies, are still reported.
+    #[arg(long)]
+    pub no_warn_conflicts: bool,
+
+    /// Don't roll back the environment if the installation fails partway.
+    ///
+    /// By default, packages are uninstalled by moving their files aside, such that if any package
+    /// fails to install, the packages installed so far are removed and the uninstalled packages
+    /// are restored, leaving the environment as it was prior to the installation. With
+    /// `--no-rollback`, the environment is left partially updated.
+    #[arg(long)]
+    pub no_rollback: bool,
+
     /// The Python interpreter into which packages should be installed.
     ///
     /// By default, `uv` installs into the virtual environment in the current working directory or
@@ -1042,6 +1428,50 @@ pub struct PipInstallArgs {
     #[arg(long, overrides_with("no_build_isolation"), hide = true)]
     pub build_isolation: bool,
 
+    /// Allow isolated build environments to import the packages installed in the target
+    /// environment.
+    ///
+    /// Build requirements are still installed into the isolated environment and take precedence
+    /// over any packages inherited from the target environment. Defaults to off.
+    #[arg(long, conflicts_with = "no_build_isolation")]
+    pub system_site_packages: bool,
+
+    /// Disable isolation when building source distributions for a specific package; may be
+    /// provided more than once.
+    ///
+    /// The given packages are built against the target environment, which is assumed to contain
+    /// their build dependencies. All other packages are built in isolated environments.
+    #[arg(long, conflicts_with = "system_site_packages")]
+    pub no_build_isolation_package: Vec<PackageName>,
+
+    /// Prefer versions available via `--find-links` over those available from the index.
+    ///
+    /// By default, the best-matching version is selected regardless of whether it's provided by the
+    /// index or a `--find-links` location. When enabled, versions from `--find-links` locations are
+    /// selected first, even if the index provides a newer version, and the index is only consulted
+    /// if none of them are satisfactory.
+    #[arg(long)]
+    pub prefer_find_links: bool,
+
+    /// Limit candidate packages for a specific package to those that were uploaded prior to the
+    /// given date, in the format `PACKAGE=DATE` (e.g., `requests=2024-03-25`).
+    ///
+    /// Overrides `--exclude-newer` for the given package, allowing a single package to track a
+    /// different cutoff than the rest of the resolution. Accepts both RFC 3339 timestamps and UTC
+    /// dates. May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE=DATE")]
+    pub exclude_newer_package: Vec<ExcludeNewerPackageEntry>,
+
+    /// Control how progress is displayed while resolving, downloading, building, and installing
+    /// packages.
+    ///
+    /// The `plain` mode reports each download and build as a separate line, which is suitable for
+    /// CI logs.
+    ///
+    /// Defaults to `auto`, or to `none` if the `UV_NO_PROGRESS` environment variable is set.
+    #[arg(long, value_enum)]
+    pub progress: Option<ProgressDisplay>,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary code. The cached wheels of already-built
@@ -1122,6 +1552,18 @@ pub struct PipInstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// The maximum number of distributions to download concurrently.
+    ///
+    /// Values below one are treated as one. Defaults to 50.
+    #[arg(long, env = "UV_CONCURRENT_DOWNLOADS")]
+    pub concurrent_downloads: Option<usize>,
+
+    /// The maximum number of concurrent requests for package metadata during resolution.
+    ///
+    /// Values below one are treated as one. Defaults to 50.
+    #[arg(long, env = "UV_CONCURRENT_REQUESTS")]
+    pub concurrent_requests: Option<usize>,
+
     #[command(flatten)]
     pub compat_args: compat::PipInstallCompatArgs,
 }
@@ -1360,6 +1802,44 @@ pub struct PipCheckArgs {
     pub no_system: bool,
 }
 
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PipInspectArgs {
+    /// The Python interpreter to inspect.
+    ///
+    /// By default, `uv` inspects the currently activated virtual environment, or a virtual
+    /// environment (`.venv`) located in the current working directory or any parent directory,
+    /// falling back to the system Python if no virtual environment is found.
+    ///
+    /// Supported formats:
+    /// - `3.10` looks for an installed Python 3.10 using `py --list-paths` on Windows, or
+    ///   `python3.10` on Linux and macOS.
+    /// - `python3.10` or `python.exe` looks for a binary with the given name in `PATH`.
+    /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
+    #[arg(long, short, env = "UV_PYTHON", verbatim_doc_comment)]
+    pub python: Option<String>,
+
+    /// Inspect the system Python.
+    ///
+    /// By default, `uv` inspects the currently activated virtual environment, or a virtual
+    /// environment (`.venv`) located in the current working directory or any parent directory,
+    /// falling back to the system Python if no virtual environment is found. The `--system` option
+    /// instructs `uv` to use the first Python found in the system `PATH`.
+    ///
+    /// WARNING: `--system` is intended for use in continuous integration (CI) environments and
+    /// should be used with caution.
+    #[arg(
+        long,
+        env = "UV_SYSTEM_PYTHON",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PipShowArgs {
@@ -1416,17 +1896,238 @@ pub struct PipTreeArgs {
     #[arg(long, short, default_value_t = 255)]
     pub depth: u8,
 
+    /// Measure `--depth` relative to the given package, rather than the roots of the tree.
+    ///
+    /// Packages above the given package, or in other branches of the tree, are displayed in full.
+    #[arg(long)]
+    pub depth_from: Option<PackageName>,
+
+    /// Stop traversing the dependency tree past the given depth.
+    ///
+    /// Unlike `--depth`, packages at the given depth are neither expanded nor tracked for
+    /// de-duplication or cycle detection, such that their metadata is never read. Useful for
+    /// limiting the cost of rendering environments with large dependency graphs.
+    #[arg(long)]
+    pub prune_after_depth: Option<u8>,
+
+    /// Stop rendering the dependency tree after the given number of packages, noting that the
+    /// output was truncated.
+    ///
+    /// By default, the tree is rendered in full. Useful for guarding against runaway output in
+    /// automated contexts.
+    #[arg(long)]
+    pub max_nodes: Option<usize>,
+
     /// Prune the given package from the display of the dependency tree.
     #[arg(long)]
     pub prune: Vec<PackageName>,
+
+    /// Hide the given package from the display of the dependency tree, while still displaying its
+    /// dependencies.
+    ///
+    /// Unlike `--prune`, the dependencies of an excluded package are displayed in its place, as
+    /// dependencies of its parent (or as roots of the tree, if the excluded package is a root).
+    #[arg(long)]
+    pub exclude: Vec<PackageName>,
+
+    /// Display only the dependency tree of the given package, rendered at the root.
+    ///
+    /// The package is always displayed as a root of the tree, even if it's a (transitive)
+    /// dependency of another installed package. When multiple packages are provided, they're
+    /// displayed in the order in which they were given.
+    #[arg(long, alias = "root")]
+    pub package: Vec<PackageName>,
+
+    /// Validate that a package matching the given requirement (e.g., `requests==2.31.0`) is
+    /// installed, rather than displaying the dependency tree.
+    ///
+    /// Each matching package is displayed. If any requirement isn't satisfied, `uv` exits with a
+    /// non-zero status code. May be provided multiple times.
+    #[arg(long, value_name = "REQUIREMENT")]
+    pub installed: Vec<String>,
+
     /// Do not de-duplicate repeated dependencies.
     /// Usually, when a package has already displayed its dependencies,
     /// further occurrences will not re-display its dependencies,
     /// and will include a (*) to indicate it has already been shown.
-    /// This flag will cause those duplicates to be repeated.
-    #[arg(long)]
+    /// This flag will cause those duplicates to be repeated, such that only dependency cycles are
+    /// marked with a (*).
+    #[arg(long, alias = "repeat")]
     pub no_dedupe: bool,
 
+    /// De-duplicate repeated dependencies within each root's subtree, rather than across the
+    /// entire tree.
+    ///
+    /// By default, a package whose dependencies were displayed under one root is marked with a
+    /// (*) under any later roots. With this flag, each root's subtree is expanded independently.
+    #[arg(long, overrides_with("dedupe_across_roots"))]
+    pub dedupe_per_root: bool,
+
+    /// De-duplicate repeated dependencies across the entire tree (the default).
+    #[arg(long, overrides_with("dedupe_per_root"), hide = true)]
+    pub dedupe_across_roots: bool,
+
+    /// The fields to display for each package in the dependency tree, as a comma-separated list.
+    #[arg(long, value_enum, value_delimiter = ',', default_values = ["name", "version"])]
+    pub fields: Vec<TreeField>,
+
+    /// Annotate each package with the one-line summary from its metadata.
+    ///
+    /// Equivalent to including `summary` in `--fields`.
+    #[arg(long)]
+    pub show_summary: bool,
+
+    /// Annotate each package with the hash of the artifact from which it was installed.
+    ///
+    /// Hashes are read from the `archive_info` recorded in the package's `direct_url.json`.
+    /// Packages without a recorded hash (e.g., editable or local directory installs) are annotated
+    /// with `(no hash)`. Equivalent to including `hash` in `--fields`.
+    #[arg(long)]
+    pub show_hashes: bool,
+
+    /// Annotate each package with its origin, as recorded in its `direct_url.json`.
+    ///
+    /// Packages installed from a version control system are annotated with the system (e.g.,
+    /// `(git)`), those installed from a remote archive with `(url)`, and those installed from a
+    /// local directory or archive with `(path)`. Packages without a `direct_url.json` are annotated
+    /// with `(registry)`. Equivalent to including `source` in `--fields`.
+    #[arg(long)]
+    pub show_source: bool,
+
+    /// Annotate each package with `(linked)` if its files are hard-linked or symlinked from the
+    /// cache, or `(copied)` if they were copied into the environment.
+    ///
+    /// Useful for verifying the space savings of `--link-mode`. Cloned (copy-on-write) files are
+    /// indistinguishable from copies. Packages for which detection isn't possible (e.g., legacy
+    /// `.egg-info` installs, or on Windows) aren't annotated. Equivalent to including `reused` in
+    /// `--fields`.
+    #[arg(long)]
+    pub show_reused: bool,
+
+    /// Annotate packages that are only required via an extra with `(optional)`.
+    ///
+    /// A package is considered optional if at least one installed package requires it under an
+    /// extra (e.g., `requests[socks]` requires `pysocks`), but no installed package requires it
+    /// unconditionally. Has no effect with `--no-markers`.
+    #[arg(long)]
+    pub annotate_optional: bool,
+
+    /// Display each line of the dependency tree in full, allowing long lines to wrap.
+    ///
+    /// By default, when writing to a terminal, each line is truncated with `…` to fit the terminal
+    /// width. When writing to a pipe or file, lines are displayed in full.
+    #[arg(long, overrides_with("no_wrap"))]
+    pub wrap: bool,
+
+    /// Truncate each line of the dependency tree with `…` to fit the terminal width.
+    ///
+    /// When not writing to a terminal, the width is read from the `COLUMNS` environment variable,
+    /// if set.
+    #[arg(long, overrides_with("wrap"))]
+    pub no_wrap: bool,
+
+    /// Color each package in the dependency tree based on the given property.
+    ///
+    /// By default, packages are not colored. Respects `--color` and `NO_COLOR`.
+    #[arg(long, value_enum)]
+    pub color_by: Option<TreeColorBy>,
+
+    /// Highlight every occurrence of the given package in the dependency tree, without filtering
+    /// the tree; may be provided more than once.
+    ///
+    /// Respects `--color` and `NO_COLOR`.
+    #[arg(long, value_name = "NAME")]
+    pub highlight: Vec<PackageName>,
+
+    /// Omit the version of each package from the dependency tree.
+    ///
+    /// Equivalent to omitting `version` from `--fields`.
+    #[arg(long)]
+    pub hide_version: bool,
+
+    /// Display dependencies that are required but not installed, annotated with
+    /// `(not installed)`.
+    ///
+    /// By default, requirements that aren't installed in the environment are omitted from the
+    /// tree.
+    #[arg(long)]
+    pub show_missing: bool,
+
+    /// Exit with a non-zero status code if any package requires a dependency that isn't installed.
+    ///
+    /// The missing dependencies are reported as warnings. Useful for checking that an environment
+    /// is complete, e.g., in CI.
+    #[arg(long)]
+    pub warn_on_missing: bool,
+
+    /// Select the output format.
+    ///
+    /// The `csv` format emits one row per dependency edge between installed packages, with a
+    /// header, and ignores the display options that only apply to the tree (e.g., `--depth`).
+    #[arg(long, value_enum, default_value_t = TreeFormat::default())]
+    pub format: TreeFormat,
+
+    /// Use ASCII characters (e.g., `|--`) rather than Unicode box-drawing characters to render the
+    /// dependency tree.
+    #[arg(long)]
+    pub ascii: bool,
+
+    /// The characters used to connect each package to its dependents in the dependency tree.
+    ///
+    /// With `spaces`, the tree is rendered using indentation alone, for tools that parse the tree
+    /// by its leading whitespace. `--ascii` is equivalent to `--connector ascii`.
+    #[arg(long, value_enum, conflicts_with = "ascii")]
+    pub connector: Option<TreeConnector>,
+
+    /// The number of columns by which each level of the dependency tree is indented.
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u8).range(2..))]
+    pub indent: u8,
+
+    /// Display packages grouped by their depth in the dependency tree, rather than as a nested
+    /// tree.
+    ///
+    /// Each package is listed once, under the shallowest level at which it appears (e.g., `Level
+    /// 0` for the roots of the tree, `Level 1` for their direct dependencies, and so on).
+    #[arg(long, conflicts_with = "depth_from")]
+    pub group_by_depth: bool,
+
+    /// Display one line per installed package, listing the packages that directly require it,
+    /// rather than a nested tree (e.g., `idna v3.6 <- requests`).
+    ///
+    /// Packages that aren't required by any other installed package are listed as
+    /// `(top-level)`.
+    #[arg(long, conflicts_with_all = ["group_by_depth", "depth_from"])]
+    pub flat: bool,
+
+    /// Display only the dependencies that apply to the given environment marker expression (e.g.,
+    /// `sys_platform == 'win32'`).
+    ///
+    /// The values in the expression (e.g., `sys_platform`, `python_version`) override those of the
+    /// current interpreter, and each requirement's marker is evaluated against the resulting
+    /// environment. Only `key == 'value'` clauses joined by `and` are supported.
+    #[arg(long, value_name = "MARKER")]
+    pub filter_by_marker: Option<String>,
+
+    /// Ignore environment markers entirely, displaying every declared dependency of each package.
+    ///
+    /// By default, dependencies whose markers don't apply to the current environment (including
+    /// those that are only required by an extra) are omitted.
+    #[arg(long, conflicts_with = "filter_by_marker")]
+    pub no_markers: bool,
+
+    /// Print the marker environment against which dependency markers are evaluated, before
+    /// displaying the tree.
+    ///
+    /// Includes any values overridden by `--filter-by-marker`. Useful for determining why a
+    /// dependency is or isn't displayed.
+    #[arg(long)]
+    pub root_markers: bool,
+
+    /// Display only the number of installed packages and dependency edges, rather than the
+    /// dependency tree.
+    #[arg(long)]
+    pub count_only: bool,
+
     /// Validate the virtual environment, to detect packages with missing dependencies or other
     /// issues.
     #[arg(long, overrides_with("no_strict"))]
@@ -2253,8 +2954,13 @@ pub struct ResolverArgs {
     #[arg(long, value_enum, env = "UV_PRERELEASE")]
     pub prerelease: Option<PreReleaseMode>,
 
-    #[arg(long, hide = true)]
-    pub pre: bool,
+    /// Allow pre-release versions for all packages, or, if a package name is provided (e.g.,
+    /// `--pre=foo`), for the given package only.
+    ///
+    /// `--pre` is equivalent to `--prerelease=allow`. `--pre=<PACKAGE>` may be provided multiple
+    /// times.
+    #[arg(long, num_args = 0..=1, require_equals = true, value_name = "PACKAGE")]
+    pub pre: Option<Vec<PackageName>>,
 
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(long, short = 'C', alias = "config-settings")]
@@ -2340,8 +3046,13 @@ pub struct ResolverInstallerArgs {
     #[arg(long, value_enum, env = "UV_PRERELEASE")]
     pub prerelease: Option<PreReleaseMode>,
 
-    #[arg(long, hide = true)]
-    pub pre: bool,
+    /// Allow pre-release versions for all packages, or, if a package name is provided (e.g.,
+    /// `--pre=foo`), for the given package only.
+    ///
+    /// `--pre` is equivalent to `--prerelease=allow`. `--pre=<PACKAGE>` may be provided multiple
+    /// times.
+    #[arg(long, num_args = 0..=1, require_equals = true, value_name = "PACKAGE")]
+    pub pre: Option<Vec<PackageName>>,
 
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(long, short = 'C', alias = "config-settings")]
@@ -52,11 +52,12 @@ impl From<ResolverArgs> for PipOptions {
             index_strategy,
             keyring_provider,
             resolution,
-            prerelease: if pre {
+            prerelease: if pre.as_ref().is_some_and(Vec::is_empty) {
                 Some(PreReleaseMode::Allow)
             } else {
                 prerelease
             },
+            prerelease_package: pre.filter(|packages| !packages.is_empty()),
             config_settings: config_setting
                 .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
             exclude_newer,
@@ -125,11 +126,12 @@ impl From<ResolverInstallerArgs> for PipOptions {
             index_strategy,
             keyring_provider,
             resolution,
-            prerelease: if pre {
+            prerelease: if pre.as_ref().is_some_and(Vec::is_empty) {
                 Some(PreReleaseMode::Allow)
             } else {
                 prerelease
             },
+            prerelease_package: pre.filter(|packages| !packages.is_empty()),
             config_settings: config_setting
                 .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
             exclude_newer,
@@ -262,11 +264,12 @@ pub fn resolver_options(resolver_args: ResolverArgs, build_args: BuildArgs) -> R
         index_strategy,
         keyring_provider,
         resolution,
-        prerelease: if pre {
+        prerelease: if pre.as_ref().is_some_and(Vec::is_empty) {
             Some(PreReleaseMode::Allow)
         } else {
             prerelease
         },
+        prerelease_package: pre.filter(|packages| !packages.is_empty()),
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
         exclude_newer,
@@ -333,11 +336,12 @@ pub fn resolver_installer_options(
         index_strategy,
         keyring_provider,
         resolution,
-        prerelease: if pre {
+        prerelease: if pre.as_ref().is_some_and(Vec::is_empty) {
             Some(PreReleaseMode::Allow)
         } else {
             prerelease
         },
+        prerelease_package: pre.filter(|packages| !packages.is_empty()),
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
         exclude_newer,
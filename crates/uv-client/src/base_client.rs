@@ -7,10 +7,7 @@ use std::{env, iter};
 use itertools::Itertools;
 use reqwest::{Client, ClientBuilder, Response};
 use reqwest_middleware::ClientWithMiddleware;
-use reqwest_retry::policies::ExponentialBackoff;
-use reqwest_retry::{
-    DefaultRetryableStrategy, RetryTransientMiddleware, Retryable, RetryableStrategy,
-};
+use reqwest_retry::{DefaultRetryableStrategy, Retryable, RetryableStrategy};
 use tracing::debug;
 
 use pep508_rs::MarkerEnvironment;
@@ -22,7 +19,7 @@ use uv_version::version;
 use uv_warnings::warn_user_once;
 
 use crate::linehaul::LineHaul;
-use crate::middleware::OfflineMiddleware;
+use crate::middleware::{OfflineMiddleware, RetryMiddleware};
 use crate::tls::read_identity;
 use crate::Connectivity;
 
@@ -184,13 +181,7 @@ impl<'a> BaseClientBuilder<'a> {
                 let client = reqwest_middleware::ClientBuilder::new(client.clone());
 
                 // Initialize the retry strategy.
-                let retry_policy =
-                    ExponentialBackoff::builder().build_with_max_retries(self.retries);
-                let retry_strategy = RetryTransientMiddleware::new_with_policy_and_strategy(
-                    retry_policy,
-                    LoggingRetryableStrategy,
-                );
-                let client = client.with(retry_strategy);
+                let client = client.with(RetryMiddleware::new(self.retries));
 
                 // Initialize the authentication middleware to set headers.
                 let client =
@@ -251,7 +242,7 @@ impl Deref for BaseClient {
 
 /// The same as [`DefaultRetryableStrategy`], but retry attempts on transient request failures are
 /// logged, so we can tell whether a request was retried before failing or not.
-struct LoggingRetryableStrategy;
+pub(crate) struct LoggingRetryableStrategy;
 
 impl RetryableStrategy for LoggingRetryableStrategy {
     fn handle(&self, res: &Result<Response, reqwest_middleware::Error>) -> Option<Retryable> {
@@ -28,6 +28,17 @@ impl Error {
         &self.kind
     }
 
+    /// Returns `true` if the error was caused by a failed network request, or by a request that
+    /// could not be made because network connectivity is disabled.
+    pub fn is_network_error(&self) -> bool {
+        matches!(
+            &*self.kind,
+            ErrorKind::ReqwestError(_)
+                | ErrorKind::ReqwestMiddlewareError(_)
+                | ErrorKind::Offline(_)
+        )
+    }
+
     /// Create a new error from a JSON parsing error.
     pub(crate) fn from_json_err(err: serde_json::Error, url: Url) -> Self {
         ErrorKind::BadJson { source: err, url }.into()
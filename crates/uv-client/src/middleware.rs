@@ -1,10 +1,19 @@
 use http::Extensions;
 use std::fmt::Debug;
+use std::time::{Duration, SystemTime};
 
-use reqwest::{Request, Response};
+use chrono::{DateTime, Utc};
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Request, Response, StatusCode};
 use reqwest_middleware::{Middleware, Next};
+use reqwest_retry::{Retryable, RetryableStrategy};
+use retry_policies::policies::ExponentialBackoff;
+use retry_policies::{RetryDecision, RetryPolicy};
+use tracing::debug;
 use url::Url;
 
+use crate::base_client::LoggingRetryableStrategy;
+
 /// A custom error type for the offline middleware.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct OfflineError {
@@ -45,3 +54,152 @@ impl Middleware for OfflineMiddleware {
         ))
     }
 }
+
+/// The maximum delay between retries, whether computed by the backoff policy or requested by the
+/// server via `Retry-After`.
+const MAX_RETRY_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A middleware that retries transient request failures with exponential backoff.
+///
+/// Unlike [`reqwest_retry::RetryTransientMiddleware`], the `Retry-After` header is respected on
+/// `429 Too Many Requests` and `503 Service Unavailable` responses, and non-idempotent requests are
+/// never retried.
+pub(crate) struct RetryMiddleware {
+    retries: u32,
+    policy: ExponentialBackoff,
+}
+
+impl RetryMiddleware {
+    /// Create a [`RetryMiddleware`] that retries each request up to `retries` times.
+    pub(crate) fn new(retries: u32) -> Self {
+        Self {
+            retries,
+            policy: ExponentialBackoff::builder()
+                .retry_bounds(Duration::from_secs(1), MAX_RETRY_INTERVAL)
+                .build_with_max_retries(retries),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for RetryMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        // Retrying a non-idempotent request (e.g., a `POST`) could repeat its side effects.
+        if !req.method().is_idempotent() {
+            return next.run(req, extensions).await;
+        }
+
+        let start_time = SystemTime::now();
+        let mut n_past_retries = 0;
+        loop {
+            // Requests with streaming bodies can't be cloned, and so can't be retried.
+            let Some(duplicate) = req.try_clone() else {
+                return next.run(req, extensions).await;
+            };
+
+            let result = next.clone().run(duplicate, extensions).await;
+            if LoggingRetryableStrategy.handle(&result) != Some(Retryable::Transient) {
+                return result;
+            }
+
+            let RetryDecision::Retry { execute_after } =
+                self.policy.should_retry(start_time, n_past_retries)
+            else {
+                return result;
+            };
+
+            // Prefer the server-provided delay, if any, over the backoff policy.
+            let delay = result
+                .as_ref()
+                .ok()
+                .and_then(retry_after)
+                .unwrap_or_else(|| {
+                    execute_after
+                        .duration_since(SystemTime::now())
+                        .unwrap_or_default()
+                });
+            debug!(
+                "Retrying request to {} in {}ms (attempt {} of {})",
+                req.url(),
+                delay.as_millis(),
+                n_past_retries + 1,
+                self.retries
+            );
+            tokio::time::sleep(delay).await;
+            n_past_retries += 1;
+        }
+    }
+}
+
+/// Parse the `Retry-After` header from a `429` or `503` response, if present.
+///
+/// The header may contain either a number of seconds or an HTTP date. The delay is capped at
+/// [`MAX_RETRY_INTERVAL`], so that a misbehaving server can't stall the request indefinitely.
+fn retry_after(response: &Response) -> Option<Duration> {
+    if !matches!(
+        response.status(),
+        StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+    ) {
+        return None;
+    }
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?.trim();
+    let delay = if let Ok(seconds) = value.parse::<u64>() {
+        Duration::from_secs(seconds)
+    } else {
+        let date = DateTime::parse_from_rfc2822(value).ok()?;
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or(Duration::ZERO)
+    };
+    Some(delay.min(MAX_RETRY_INTERVAL))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::Utc;
+    use reqwest::header::RETRY_AFTER;
+    use reqwest::{Response, StatusCode};
+
+    use super::{retry_after, MAX_RETRY_INTERVAL};
+
+    fn response_with_retry_after(status: StatusCode, retry_after: &str) -> Response {
+        Response::from(
+            http::Response::builder()
+                .status(status)
+                .header(RETRY_AFTER, retry_after)
+                .body("")
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn retry_after_seconds() {
+        let response = response_with_retry_after(StatusCode::TOO_MANY_REQUESTS, "5");
+        assert_eq!(retry_after(&response), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn retry_after_clamped() {
+        let response = response_with_retry_after(StatusCode::SERVICE_UNAVAILABLE, "86400");
+        assert_eq!(retry_after(&response), Some(MAX_RETRY_INTERVAL));
+
+        let response = response_with_retry_after(
+            StatusCode::SERVICE_UNAVAILABLE,
+            &(Utc::now() + chrono::Duration::days(1)).to_rfc2822(),
+        );
+        assert_eq!(retry_after(&response), Some(MAX_RETRY_INTERVAL));
+    }
+
+    #[test]
+    fn retry_after_ignored() {
+        let response = response_with_retry_after(StatusCode::INTERNAL_SERVER_ERROR, "5");
+        assert_eq!(retry_after(&response), None);
+    }
+}
@@ -0,0 +1,129 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use anyhow::Result;
+use futures::future;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::header::RETRY_AFTER;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+
+use uv_cache::Cache;
+use uv_client::RegistryClientBuilder;
+
+/// Spawn a server that responds with the given status for the first `failures` requests, and
+/// `200 OK` thereafter. Returns the server address and a counter of the requests received.
+async fn flaky_server(
+    status: StatusCode,
+    failures: usize,
+) -> Result<(std::net::SocketAddr, Arc<AtomicUsize>)> {
+    // Set up the TCP listener on a random available port
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+
+    // Spawn the server loop in a background task, accepting a new connection for each retry.
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let socket = TokioIo::new(socket);
+            let counter = counter.clone();
+            tokio::task::spawn(async move {
+                let svc = service_fn(move |_req: Request<hyper::body::Incoming>| {
+                    let response = if counter.fetch_add(1, Ordering::SeqCst) < failures {
+                        Response::builder()
+                            .status(status)
+                            .header(RETRY_AFTER, "0")
+                            .body(Full::new(Bytes::new()))
+                            .unwrap()
+                    } else {
+                        Response::new(Full::new(Bytes::from("ok")))
+                    };
+                    future::ok::<_, hyper::Error>(response)
+                });
+                http1::Builder::new()
+                    .serve_connection(socket, svc)
+                    .await
+                    .expect("Server Started");
+            });
+        }
+    });
+
+    Ok((addr, requests))
+}
+
+#[tokio::test]
+async fn retry_transient_failures() -> Result<()> {
+    let (addr, requests) = flaky_server(StatusCode::SERVICE_UNAVAILABLE, 2).await?;
+
+    // Initialize uv-client
+    let cache = Cache::temp()?.init()?;
+    let client = RegistryClientBuilder::new(cache).retries(3).build();
+
+    // Send request to our flaky server
+    let res = client
+        .cached_client()
+        .uncached()
+        .get(format!("http://{addr}"))
+        .send()
+        .await?;
+
+    // The request should succeed on the third attempt.
+    assert!(res.status().is_success());
+    assert_eq!(res.text().await?, "ok");
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn retry_exhausted() -> Result<()> {
+    let (addr, requests) = flaky_server(StatusCode::SERVICE_UNAVAILABLE, 2).await?;
+
+    // Initialize uv-client
+    let cache = Cache::temp()?.init()?;
+    let client = RegistryClientBuilder::new(cache).retries(1).build();
+
+    // Send request to our flaky server
+    let res = client
+        .cached_client()
+        .uncached()
+        .get(format!("http://{addr}"))
+        .send()
+        .await?;
+
+    // The final failure should be returned once the retries are exhausted.
+    assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn no_retry_client_error() -> Result<()> {
+    let (addr, requests) = flaky_server(StatusCode::NOT_FOUND, 2).await?;
+
+    // Initialize uv-client
+    let cache = Cache::temp()?.init()?;
+    let client = RegistryClientBuilder::new(cache).retries(3).build();
+
+    // Send request to our flaky server
+    let res = client
+        .cached_client()
+        .uncached()
+        .get(format!("http://{addr}"))
+        .send()
+        .await?;
+
+    // A `404` is not transient, and so should not be retried.
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
@@ -7,6 +7,10 @@ pub struct Concurrency {
     ///
     /// Note this value must be non-zero.
     pub downloads: usize,
+    /// The maximum number of concurrent requests for package metadata during resolution.
+    ///
+    /// Note this value must be non-zero.
+    pub requests: usize,
     /// The maximum number of concurrent builds.
     ///
     /// Note this value must be non-zero.
@@ -21,6 +25,7 @@ impl Default for Concurrency {
     fn default() -> Self {
         Concurrency {
             downloads: Concurrency::DEFAULT_DOWNLOADS,
+            requests: Concurrency::DEFAULT_REQUESTS,
             builds: Concurrency::threads(),
             installs: Concurrency::threads(),
         }
@@ -31,6 +36,9 @@ impl Concurrency {
     // The default concurrent downloads limit.
     pub const DEFAULT_DOWNLOADS: usize = 50;
 
+    // The default concurrent metadata requests limit.
+    pub const DEFAULT_REQUESTS: usize = 50;
+
     // The default concurrent builds and install limit.
     pub fn threads() -> usize {
         std::thread::available_parallelism()
@@ -3,6 +3,8 @@ use std::{
     str::FromStr,
 };
 
+use uv_normalize::PackageName;
+
 #[derive(Debug, Clone)]
 pub struct ConfigSettingEntry {
     /// The key of the setting. For example, given `key=value`, this would be `key`.
@@ -189,6 +191,86 @@ impl<'de> serde::Deserialize<'de> for ConfigSettings {
     }
 }
 
+/// A config setting to pass to the PEP 517 build backend of a specific package.
+#[derive(Debug, Clone)]
+pub struct PackageConfigSettingEntry {
+    /// The package to which the setting applies. For example, given `name:key=value`, this would
+    /// be `name`.
+    package: PackageName,
+    /// The setting itself. For example, given `name:key=value`, this would be `key=value`.
+    setting: ConfigSettingEntry,
+}
+
+impl FromStr for PackageConfigSettingEntry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((package, setting)) = s.split_once(':') else {
+            return Err(format!(
+                "Invalid config setting: {s} (expected `PACKAGE:KEY=VALUE`)"
+            ));
+        };
+        let package = PackageName::from_str(package.trim()).map_err(|err| err.to_string())?;
+        let setting = ConfigSettingEntry::from_str(setting)?;
+        Ok(Self { package, setting })
+    }
+}
+
+/// Settings to pass to the PEP 517 build backends of specific packages, structured as a map from
+/// package name to [`ConfigSettings`].
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PackageConfigSettings(BTreeMap<PackageName, ConfigSettings>);
+
+impl FromIterator<PackageConfigSettingEntry> for PackageConfigSettings {
+    fn from_iter<T: IntoIterator<Item = PackageConfigSettingEntry>>(iter: T) -> Self {
+        let mut entries: BTreeMap<PackageName, Vec<ConfigSettingEntry>> = BTreeMap::default();
+        for entry in iter {
+            entries
+                .entry(entry.package)
+                .or_default()
+                .push(entry.setting);
+        }
+        Self(
+            entries
+                .into_iter()
+                .map(|(package, settings)| (package, settings.into_iter().collect()))
+                .collect(),
+        )
+    }
+}
+
+impl PackageConfigSettings {
+    /// Return the [`ConfigSettings`] for the given package, if any.
+    pub fn get(&self, package: &PackageName) -> Option<&ConfigSettings> {
+        self.0.get(package)
+    }
+
+    /// Return `true` if there are no package-specific settings.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Merge two sets of package-specific config settings, with the values in `self` taking
+    /// precedence.
+    #[must_use]
+    pub fn merge(self, other: PackageConfigSettings) -> PackageConfigSettings {
+        let mut config = self.0;
+        for (package, settings) in other.0 {
+            match config.entry(package) {
+                Entry::Vacant(vacant) => {
+                    vacant.insert(settings);
+                }
+                Entry::Occupied(mut occupied) => {
+                    let existing = std::mem::take(occupied.get_mut());
+                    occupied.insert(existing.merge(settings));
+                }
+            }
+        }
+        Self(config)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -231,6 +313,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn collect_package_config_settings() {
+        let settings: PackageConfigSettings = vec![
+            PackageConfigSettingEntry::from_str("foo:key=value").unwrap(),
+            PackageConfigSettingEntry::from_str("foo:key=value2").unwrap(),
+            PackageConfigSettingEntry::from_str("bar:key=value3").unwrap(),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            settings
+                .get(&PackageName::from_str("foo").unwrap())
+                .unwrap()
+                .escape_for_python(),
+            r#"{"key":["value","value2"]}"#
+        );
+        assert_eq!(
+            settings
+                .get(&PackageName::from_str("bar").unwrap())
+                .unwrap()
+                .escape_for_python(),
+            r#"{"key":"value3"}"#
+        );
+        assert!(settings
+            .get(&PackageName::from_str("baz").unwrap())
+            .is_none());
+
+        assert!(PackageConfigSettingEntry::from_str("key=value").is_err());
+    }
+
     #[test]
     fn escape_for_python() {
         let mut settings = ConfigSettings::default();
@@ -4,10 +4,12 @@ pub use concurrency::*;
 pub use config_settings::*;
 pub use constraints::*;
 pub use extras::*;
+pub use marker_override::*;
 pub use name_specifiers::*;
 pub use overrides::*;
 pub use package_options::*;
 pub use preview::*;
+pub use sources::*;
 pub use target_triple::*;
 
 mod authentication;
@@ -16,8 +18,10 @@ mod concurrency;
 mod config_settings;
 mod constraints;
 mod extras;
+mod marker_override;
 mod name_specifiers;
 mod overrides;
 mod package_options;
 mod preview;
+mod sources;
 mod target_triple;
@@ -0,0 +1,97 @@
+use std::str::FromStr;
+
+use pep508_rs::{MarkerEnvironment, StringVersion};
+
+/// The marker environment keys that may be overridden, in the order they're reported to the user.
+const MARKER_KEYS: &[&str] = &[
+    "implementation_name",
+    "implementation_version",
+    "os_name",
+    "platform_machine",
+    "platform_python_implementation",
+    "platform_release",
+    "platform_system",
+    "platform_version",
+    "python_full_version",
+    "python_version",
+    "sys_platform",
+];
+
+/// An override for a single field of a [`MarkerEnvironment`], as in `implementation_name=pypy`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarkerOverride {
+    ImplementationName(String),
+    ImplementationVersion(StringVersion),
+    OsName(String),
+    PlatformMachine(String),
+    PlatformPythonImplementation(String),
+    PlatformRelease(String),
+    PlatformSystem(String),
+    PlatformVersion(String),
+    PythonFullVersion(StringVersion),
+    PythonVersion(StringVersion),
+    SysPlatform(String),
+}
+
+impl MarkerOverride {
+    /// Apply the override to the given [`MarkerEnvironment`].
+    #[must_use]
+    pub fn apply(&self, markers: MarkerEnvironment) -> MarkerEnvironment {
+        match self {
+            Self::ImplementationName(value) => markers.with_implementation_name(value.clone()),
+            Self::ImplementationVersion(value) => {
+                markers.with_implementation_version(value.clone())
+            }
+            Self::OsName(value) => markers.with_os_name(value.clone()),
+            Self::PlatformMachine(value) => markers.with_platform_machine(value.clone()),
+            Self::PlatformPythonImplementation(value) => {
+                markers.with_platform_python_implementation(value.clone())
+            }
+            Self::PlatformRelease(value) => markers.with_platform_release(value.clone()),
+            Self::PlatformSystem(value) => markers.with_platform_system(value.clone()),
+            Self::PlatformVersion(value) => markers.with_platform_version(value.clone()),
+            Self::PythonFullVersion(value) => markers.with_python_full_version(value.clone()),
+            Self::PythonVersion(value) => markers.with_python_version(value.clone()),
+            Self::SysPlatform(value) => markers.with_sys_platform(value.clone()),
+        }
+    }
+}
+
+impl FromStr for MarkerOverride {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((key, value)) = s.split_once('=') else {
+            return Err(format!(
+                "Invalid marker override: {s} (expected `KEY=VALUE`)"
+            ));
+        };
+        let key = key.trim();
+        let value = value.trim();
+
+        let version = |value: &str| {
+            StringVersion::from_str(value)
+                .map_err(|err| format!("Invalid version for marker `{key}`: {err}"))
+        };
+
+        match key {
+            "implementation_name" => Ok(Self::ImplementationName(value.to_string())),
+            "implementation_version" => Ok(Self::ImplementationVersion(version(value)?)),
+            "os_name" => Ok(Self::OsName(value.to_string())),
+            "platform_machine" => Ok(Self::PlatformMachine(value.to_string())),
+            "platform_python_implementation" => {
+                Ok(Self::PlatformPythonImplementation(value.to_string()))
+            }
+            "platform_release" => Ok(Self::PlatformRelease(value.to_string())),
+            "platform_system" => Ok(Self::PlatformSystem(value.to_string())),
+            "platform_version" => Ok(Self::PlatformVersion(value.to_string())),
+            "python_full_version" => Ok(Self::PythonFullVersion(version(value)?)),
+            "python_version" => Ok(Self::PythonVersion(version(value)?)),
+            "sys_platform" => Ok(Self::SysPlatform(value.to_string())),
+            _ => Err(format!(
+                "Unknown marker: `{key}` (expected one of: {})",
+                MARKER_KEYS.join(", ")
+            )),
+        }
+    }
+}
@@ -83,3 +83,18 @@ impl Upgrade {
         matches!(self, Self::All)
     }
 }
+
+/// The strategy to use when upgrading packages.
+#[derive(Debug, Default, Clone, Copy, Hash, Eq, PartialEq, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum UpgradeStrategy {
+    /// Only upgrade the explicitly requested packages, leaving any dependencies at their
+    /// installed versions, unless they no longer satisfy the requirements of the upgraded packages.
+    #[default]
+    OnlyIfNeeded,
+
+    /// Upgrade the requested packages along with all of their dependencies.
+    Eager,
+}
@@ -0,0 +1,26 @@
+/// Whether to respect `tool.uv.sources` when reading the requirements of a project.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum SourceStrategy {
+    /// Lower requirements using the `tool.uv.sources` table, if present.
+    #[default]
+    Enabled,
+    /// Ignore `tool.uv.sources`, such that all requirements are resolved from the configured
+    /// indexes.
+    Disabled,
+}
+
+impl SourceStrategy {
+    /// Determine the [`SourceStrategy`] from the command-line arguments.
+    pub fn from_args(no_sources: bool) -> Self {
+        if no_sources {
+            Self::Disabled
+        } else {
+            Self::Enabled
+        }
+    }
+
+    /// Returns `true` if `tool.uv.sources` should be respected.
+    pub fn is_enabled(&self) -> bool {
+        matches!(self, Self::Enabled)
+    }
+}
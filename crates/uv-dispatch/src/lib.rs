@@ -17,14 +17,16 @@ use uv_build::{SourceBuild, SourceBuildContext};
 use uv_cache::Cache;
 use uv_client::RegistryClient;
 use uv_configuration::{
-    BuildKind, BuildOptions, ConfigSettings, IndexStrategy, Reinstall, SetupPyStrategy,
+    BuildKind, BuildOptions, ConfigSettings, Constraints, IndexStrategy, Overrides,
+    PackageConfigSettings, Reinstall, SetupPyStrategy,
 };
-use uv_configuration::{Concurrency, PreviewMode};
+use uv_configuration::{Concurrency, PreviewMode, SourceStrategy};
 use uv_distribution::DistributionDatabase;
 use uv_git::GitResolver;
 use uv_installer::{Installer, Plan, Planner, Preparer, SitePackages};
 use uv_resolver::{
-    ExcludeNewer, FlatIndex, InMemoryIndex, Manifest, OptionsBuilder, PythonRequirement, Resolver,
+    ExcludeNewer, Exclusions, FlatIndex, InMemoryIndex, Manifest, OptionsBuilder, Preferences,
+    PythonRequirement, Resolver,
 };
 use uv_toolchain::{Interpreter, PythonEnvironment};
 use uv_types::{BuildContext, BuildIsolation, EmptyInstalledPackages, HashStrategy, InFlight};
@@ -46,10 +48,13 @@ pub struct BuildDispatch<'a> {
     link_mode: install_wheel_rs::linker::LinkMode,
     build_options: &'a BuildOptions,
     config_settings: &'a ConfigSettings,
+    package_config_settings: PackageConfigSettings,
+    build_constraints: Constraints,
     exclude_newer: Option<ExcludeNewer>,
     source_build_context: SourceBuildContext,
     build_extra_env_vars: FxHashMap<OsString, OsString>,
     concurrency: Concurrency,
+    sources: SourceStrategy,
     preview_mode: PreviewMode,
 }
 
@@ -85,6 +90,8 @@ impl<'a> BuildDispatch<'a> {
             index_strategy,
             setup_py,
             config_settings,
+            package_config_settings: PackageConfigSettings::default(),
+            build_constraints: Constraints::default(),
             build_isolation,
             link_mode,
             build_options,
@@ -92,10 +99,37 @@ impl<'a> BuildDispatch<'a> {
             concurrency,
             source_build_context: SourceBuildContext::default(),
             build_extra_env_vars: FxHashMap::default(),
+            sources: SourceStrategy::default(),
             preview_mode,
         }
     }
 
+    /// Set the [`SourceStrategy`] to use when reading the requirements of local and Git projects.
+    #[must_use]
+    pub fn with_sources(mut self, sources: SourceStrategy) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    /// Set the package-specific settings to pass to the PEP 517 build backend, in addition to the
+    /// global config settings.
+    #[must_use]
+    pub fn with_package_config_settings(
+        mut self,
+        package_config_settings: PackageConfigSettings,
+    ) -> Self {
+        self.package_config_settings = package_config_settings;
+        self
+    }
+
+    /// Set the constraints to apply when resolving the build requirements of source
+    /// distributions.
+    #[must_use]
+    pub fn with_build_constraints(mut self, build_constraints: Constraints) -> Self {
+        self.build_constraints = build_constraints;
+        self
+    }
+
     /// Set the environment variables to be used when building a source distribution.
     #[must_use]
     pub fn with_build_extra_env_vars<I, K, V>(mut self, sdist_build_env_variables: I) -> Self
@@ -135,12 +169,26 @@ impl<'a> BuildContext for BuildDispatch<'a> {
         self.index_locations
     }
 
+    fn sources(&self) -> SourceStrategy {
+        self.sources
+    }
+
     async fn resolve<'data>(&'data self, requirements: &'data [Requirement]) -> Result<Resolution> {
         let python_requirement = PythonRequirement::from_interpreter(self.interpreter);
         let markers = self.interpreter.markers();
         let tags = self.interpreter.tags()?;
         let resolver = Resolver::new(
-            Manifest::simple(requirements.to_vec()),
+            Manifest::new(
+                requirements.to_vec(),
+                self.build_constraints.clone(),
+                Overrides::default(),
+                Vec::new(),
+                Preferences::default(),
+                Vec::new(),
+                None,
+                Exclusions::default(),
+                Vec::new(),
+            ),
             OptionsBuilder::new()
                 .exclude_newer(self.exclude_newer)
                 .index_strategy(self.index_strategy)
@@ -156,7 +204,7 @@ impl<'a> BuildContext for BuildDispatch<'a> {
             DistributionDatabase::new(
                 self.client,
                 self,
-                self.concurrency.downloads,
+                self.concurrency.requests,
                 self.preview_mode,
             ),
         )?;
@@ -320,6 +368,17 @@ impl<'a> BuildContext for BuildDispatch<'a> {
             return Err(anyhow!("Building source distributions is disabled"));
         }
 
+        // Merge any package-specific config settings into the global settings.
+        let config_settings = match dist
+            .map(distribution_types::Name::name)
+            .and_then(|name| self.package_config_settings.get(name))
+        {
+            Some(package_config_settings) => package_config_settings
+                .clone()
+                .merge(self.config_settings.clone()),
+            None => self.config_settings.clone(),
+        };
+
         let builder = SourceBuild::setup(
             source,
             subdirectory,
@@ -328,8 +387,9 @@ impl<'a> BuildContext for BuildDispatch<'a> {
             self.source_build_context.clone(),
             version_id.to_string(),
             self.setup_py,
-            self.config_settings.clone(),
-            self.build_isolation,
+            config_settings,
+            self.build_isolation
+                .for_package(dist.map(distribution_types::Name::name)),
             build_kind,
             self.build_extra_env_vars.clone(),
             self.concurrency.builds,
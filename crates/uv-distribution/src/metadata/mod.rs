@@ -5,7 +5,7 @@ use thiserror::Error;
 
 use pep440_rs::{Version, VersionSpecifiers};
 use pypi_types::{HashDigest, Metadata23};
-use uv_configuration::PreviewMode;
+use uv_configuration::{PreviewMode, SourceStrategy};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 
 use crate::metadata::lowering::LoweringError;
@@ -58,6 +58,7 @@ impl Metadata {
     pub async fn from_workspace(
         metadata: Metadata23,
         project_root: &Path,
+        sources: SourceStrategy,
         preview_mode: PreviewMode,
     ) -> Result<Self, MetadataError> {
         // Lower the requirements.
@@ -73,6 +74,7 @@ impl Metadata {
                 provides_extras: metadata.provides_extras,
             },
             project_root,
+            sources,
             preview_mode,
         )
         .await?;
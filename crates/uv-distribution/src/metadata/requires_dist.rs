@@ -2,7 +2,7 @@ use once_cell::sync::Lazy;
 use std::collections::BTreeMap;
 use std::path::Path;
 
-use uv_configuration::PreviewMode;
+use uv_configuration::{PreviewMode, SourceStrategy};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 
 use crate::metadata::lowering::lower_requirement;
@@ -45,8 +45,14 @@ impl RequiresDist {
     pub async fn from_workspace(
         metadata: pypi_types::RequiresDist,
         project_root: &Path,
+        sources: SourceStrategy,
         preview_mode: PreviewMode,
     ) -> Result<Self, MetadataError> {
+        // If `tool.uv.sources` should be ignored, skip workspace discovery entirely.
+        if !sources.is_enabled() {
+            return Ok(Self::from_metadata23(metadata));
+        }
+
         // TODO(konsti): Limit discovery for Git checkouts to Git root.
         // TODO(konsti): Cache workspace discovery.
         let Some(project_workspace) =
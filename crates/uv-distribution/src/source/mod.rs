@@ -427,8 +427,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     /// Return the [`RequiresDist`] from a `pyproject.toml`, if it can be statically extracted.
     pub(crate) async fn requires_dist(&self, project_root: &Path) -> Result<RequiresDist, Error> {
         let requires_dist = read_requires_dist(project_root).await?;
-        let requires_dist =
-            RequiresDist::from_workspace(requires_dist, project_root, self.preview_mode).await?;
+        let requires_dist = RequiresDist::from_workspace(
+            requires_dist,
+            project_root,
+            self.build_context.sources(),
+            self.preview_mode,
+        )
+        .await?;
         Ok(requires_dist)
     }
 
@@ -982,8 +987,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         if let Some(metadata) = read_cached_metadata(&metadata_entry).await? {
             debug!("Using cached metadata for: {source}");
             return Ok(ArchiveMetadata::from(
-                Metadata::from_workspace(metadata, resource.path.as_ref(), self.preview_mode)
-                    .await?,
+                Metadata::from_workspace(
+                    metadata,
+                    resource.path.as_ref(),
+                    self.build_context.sources(),
+                    self.preview_mode,
+                )
+                .await?,
             ));
         }
 
@@ -1002,8 +1012,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .map_err(Error::CacheWrite)?;
 
             return Ok(ArchiveMetadata::from(
-                Metadata::from_workspace(metadata, resource.path.as_ref(), self.preview_mode)
-                    .await?,
+                Metadata::from_workspace(
+                    metadata,
+                    resource.path.as_ref(),
+                    self.build_context.sources(),
+                    self.preview_mode,
+                )
+                .await?,
             ));
         }
 
@@ -1029,7 +1044,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .map_err(Error::CacheWrite)?;
 
         Ok(ArchiveMetadata::from(
-            Metadata::from_workspace(metadata, resource.path.as_ref(), self.preview_mode).await?,
+            Metadata::from_workspace(
+                metadata,
+                resource.path.as_ref(),
+                self.build_context.sources(),
+                self.preview_mode,
+            )
+            .await?,
         ))
     }
 
@@ -1229,7 +1250,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             if let Some(metadata) = read_cached_metadata(&metadata_entry).await? {
                 debug!("Using cached metadata for: {source}");
                 return Ok(ArchiveMetadata::from(
-                    Metadata::from_workspace(metadata, fetch.path(), self.preview_mode).await?,
+                    Metadata::from_workspace(
+                        metadata,
+                        fetch.path(),
+                        self.build_context.sources(),
+                        self.preview_mode,
+                    )
+                    .await?,
                 ));
             }
         }
@@ -1249,7 +1276,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .map_err(Error::CacheWrite)?;
 
             return Ok(ArchiveMetadata::from(
-                Metadata::from_workspace(metadata, fetch.path(), self.preview_mode).await?,
+                Metadata::from_workspace(
+                    metadata,
+                    fetch.path(),
+                    self.build_context.sources(),
+                    self.preview_mode,
+                )
+                .await?,
             ));
         }
 
@@ -1275,7 +1308,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .map_err(Error::CacheWrite)?;
 
         Ok(ArchiveMetadata::from(
-            Metadata::from_workspace(metadata, fetch.path(), self.preview_mode).await?,
+            Metadata::from_workspace(
+                metadata,
+                fetch.path(),
+                self.build_context.sources(),
+                self.preview_mode,
+            )
+            .await?,
         ))
     }
 
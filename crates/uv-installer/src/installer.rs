@@ -10,6 +10,7 @@ pub struct Installer<'a> {
     link_mode: install_wheel_rs::linker::LinkMode,
     reporter: Option<Box<dyn Reporter>>,
     installer_name: Option<String>,
+    rollback: bool,
 }
 
 impl<'a> Installer<'a> {
@@ -20,6 +21,7 @@ impl<'a> Installer<'a> {
             link_mode: install_wheel_rs::linker::LinkMode::default(),
             reporter: None,
             installer_name: Some("uv".to_string()),
+            rollback: false,
         }
     }
 
@@ -47,6 +49,12 @@ impl<'a> Installer<'a> {
         }
     }
 
+    /// Revert any wheel that fails to install partway, restoring the files that it overwrote.
+    #[must_use]
+    pub fn with_rollback(self, rollback: bool) -> Self {
+        Self { rollback, ..self }
+    }
+
     /// Install a set of wheels into a Python virtual environment.
     #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
     pub fn install(self, wheels: &[CachedDist]) -> Result<()> {
@@ -65,6 +73,7 @@ impl<'a> Installer<'a> {
                         .as_ref(),
                     self.installer_name.as_deref(),
                     self.link_mode,
+                    self.rollback,
                 )
                 .with_context(|| format!("Failed to install: {} ({wheel})", wheel.filename()))?;
 
@@ -2,6 +2,7 @@ pub use compile::{compile_tree, CompileError};
 pub use installer::{Installer, Reporter as InstallReporter};
 pub use plan::{Plan, Planner};
 pub use preparer::{Preparer, Reporter as PrepareReporter};
+pub use rollback::Rollback;
 pub use site_packages::{SatisfiesResult, SitePackages, SitePackagesDiagnostic};
 pub use uninstall::{uninstall, UninstallError};
 
@@ -10,6 +11,7 @@ mod preparer;
 
 mod installer;
 mod plan;
+mod rollback;
 mod satisfies;
 mod site_packages;
 mod uninstall;
@@ -0,0 +1,141 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use fs_err as fs;
+use tempfile::TempDir;
+use tracing::debug;
+
+use distribution_types::{CachedDist, InstalledDist, Name};
+use install_wheel_rs::StashedFile;
+use uv_toolchain::PythonEnvironment;
+
+use crate::uninstall::{uninstall, UninstallError};
+
+/// A journal of the changes made to an environment during an installation, such that the
+/// environment can be restored to its prior state if the installation fails partway.
+///
+/// Distributions are uninstalled by moving their files into a temporary directory within
+/// `site-packages`, rather than deleting them, such that they can be moved back into place if the
+/// installation is rolled back.
+#[derive(Debug)]
+pub struct Rollback {
+    /// The `site-packages` directories into which wheels are installed.
+    site_packages: Vec<PathBuf>,
+    /// The temporary directory in which uninstalled files are stashed, created on first use.
+    stash: Option<TempDir>,
+    /// The files that were stashed, in the order in which they were moved.
+    stashed: Vec<StashedFile>,
+    /// The distributions that were uninstalled without being stashed, and so can't be restored.
+    unrestorable: Vec<InstalledDist>,
+}
+
+impl Rollback {
+    /// Initialize a [`Rollback`] for the given environment.
+    pub fn new(venv: &PythonEnvironment) -> Self {
+        let scheme = venv.interpreter().layout().scheme;
+        let mut site_packages = vec![scheme.purelib];
+        if scheme.platlib != site_packages[0] {
+            site_packages.push(scheme.platlib);
+        }
+        Self {
+            site_packages,
+            stash: None,
+            stashed: Vec::new(),
+            unrestorable: Vec::new(),
+        }
+    }
+
+    /// Return the temporary directory in which uninstalled files are stashed, creating it if
+    /// necessary.
+    fn stash(&mut self) -> Result<&TempDir, std::io::Error> {
+        if let Some(ref stash) = self.stash {
+            return Ok(stash);
+        }
+        let purelib = &self.site_packages[0];
+        fs::create_dir_all(purelib)?;
+        let stash = tempfile::Builder::new()
+            .prefix(".uv-rollback-")
+            .tempdir_in(purelib)?;
+        Ok(self.stash.insert(stash))
+    }
+
+    /// Uninstall a distribution, stashing its files such that it can be restored on rollback.
+    ///
+    /// Distributions without a `RECORD` (e.g., `.egg-info` installs) can't be stashed, and are
+    /// uninstalled as usual.
+    pub async fn uninstall(
+        &mut self,
+        dist: &InstalledDist,
+    ) -> Result<install_wheel_rs::Uninstall, UninstallError> {
+        match dist {
+            InstalledDist::Registry(_) | InstalledDist::Url(_) => {
+                let index = self.stashed.len();
+                let stash = self
+                    .stash()
+                    .map_err(install_wheel_rs::Error::from)?
+                    .path()
+                    .join(index.to_string());
+                let (summary, stashed) = tokio::task::spawn_blocking({
+                    let dist_info = dist.path().to_path_buf();
+                    move || install_wheel_rs::stash_wheel(&dist_info, &stash)
+                })
+                .await??;
+                debug!("Stashed {} ({} files)", dist.name(), stashed.len());
+                self.stashed.extend(stashed);
+                Ok(summary)
+            }
+            InstalledDist::EggInfoDirectory(_)
+            | InstalledDist::EggInfoFile(_)
+            | InstalledDist::LegacyEditable(_) => {
+                let summary = uninstall(dist).await?;
+                self.unrestorable.push(dist.clone());
+                Ok(summary)
+            }
+        }
+    }
+
+    /// Roll back the installation, removing any of the given wheels that were installed and
+    /// restoring any stashed distributions.
+    ///
+    /// Returns the distributions that were uninstalled but couldn't be restored.
+    pub fn restore(self, wheels: &[CachedDist]) -> Result<Vec<InstalledDist>> {
+        // Remove any wheels that were installed. (Wheels that failed partway through installation
+        // are cleaned up by the installer itself.)
+        for wheel in wheels {
+            let Some(dist_info) = self.installed_dist_info(wheel)? else {
+                continue;
+            };
+            debug!("Removing installed distribution: {}", wheel.name());
+            install_wheel_rs::uninstall_wheel(&dist_info)?;
+        }
+
+        // Move the stashed files back into place, undoing the moves in reverse order.
+        for file in self.stashed.iter().rev() {
+            file.restore()?;
+        }
+
+        Ok(self.unrestorable)
+    }
+
+    /// Return the path to the installed `.dist-info` directory for the given wheel, if it exists.
+    fn installed_dist_info(&self, wheel: &CachedDist) -> Result<Option<PathBuf>> {
+        let Some(dist_info) = fs::read_dir(wheel.path())?.find_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension().is_some_and(|ext| ext == "dist-info") {
+                Some(path)
+            } else {
+                None
+            }
+        }) else {
+            return Ok(None);
+        };
+        let Some(file_name) = dist_info.file_name() else {
+            return Ok(None);
+        };
+        Ok(self
+            .site_packages
+            .iter()
+            .map(|site_packages| site_packages.join(file_name))
+            .find(|path| path.is_dir()))
+    }
+}
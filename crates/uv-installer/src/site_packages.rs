@@ -1,6 +1,7 @@
+use std::borrow::Cow;
 use std::collections::BTreeSet;
 use std::iter::Flatten;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use fs_err as fs;
@@ -39,13 +40,49 @@ pub struct SitePackages {
 impl SitePackages {
     /// Build an index of installed packages from the given Python executable.
     pub fn from_environment(venv: &PythonEnvironment) -> Result<SitePackages> {
+        Self::from_paths(venv, venv.site_packages())
+    }
+
+    /// Build an index of installed packages from the given Python executable, including any
+    /// additional directories on the interpreter's `sys.path` (e.g., those added via `.pth` files).
+    ///
+    /// Directories are read in priority order, and directories that resolve to the same location
+    /// are only read once.
+    pub fn from_environment_with_sys_path(venv: &PythonEnvironment) -> Result<SitePackages> {
+        let interpreter = venv.interpreter();
+        let mut paths: Vec<PathBuf> = venv.site_packages().map(Cow::into_owned).collect();
+
+        // If the environment is redirected to a `--target` or `--prefix` directory, the
+        // interpreter's own `sys.path` is irrelevant.
+        if interpreter.target().is_none() && interpreter.prefix().is_none() {
+            paths.extend(
+                interpreter
+                    .sys_path()
+                    .iter()
+                    .filter(|path| !path.starts_with(interpreter.stdlib()))
+                    .filter(|path| path.is_dir())
+                    .cloned(),
+            );
+        }
+
+        let mut seen = FxHashSet::default();
+        paths.retain(|path| seen.insert(fs::canonicalize(path).unwrap_or_else(|_| path.clone())));
+
+        Self::from_paths(venv, paths)
+    }
+
+    /// Build an index of installed packages from the given directories.
+    fn from_paths(
+        venv: &PythonEnvironment,
+        paths: impl IntoIterator<Item = impl AsRef<Path>>,
+    ) -> Result<SitePackages> {
         let mut distributions: Vec<Option<InstalledDist>> = Vec::new();
         let mut by_name = FxHashMap::default();
         let mut by_url = FxHashMap::default();
 
-        for site_packages in venv.site_packages() {
+        for site_packages in paths {
             // Read the site-packages directory.
-            let site_packages = match fs::read_dir(site_packages) {
+            let site_packages = match fs::read_dir(site_packages.as_ref()) {
                 Ok(site_packages) => {
                     // Collect sorted directory paths; `read_dir` is not stable across platforms
                     let dist_likes: BTreeSet<_> = site_packages
@@ -63,14 +100,7 @@ impl SitePackages {
                         .collect::<Result<_, std::io::Error>>()?;
                     dist_likes
                 }
-                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-                    return Ok(Self {
-                        venv: venv.clone(),
-                        distributions,
-                        by_name,
-                        by_url,
-                    });
-                }
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
                 Err(err) => return Err(err).context("Failed to read site-packages directory"),
             };
 
@@ -296,8 +326,14 @@ impl SitePackages {
                         }
                         RequirementSatisfaction::Satisfied => {}
                     }
-                    // Validate that the installed version satisfies the constraints.
-                    for constraint in constraints {
+                    // Validate that the installed version satisfies the constraints. Constraints
+                    // that target other packages, or whose markers don't match the current
+                    // environment, are skipped.
+                    for constraint in constraints.iter().filter(|constraint| {
+                        constraint.name == *distribution.name()
+                            && constraint
+                                .evaluate_markers(Some(self.venv.interpreter().markers()), &[])
+                    }) {
                         match RequirementSatisfaction::check(distribution, &constraint.source)? {
                             RequirementSatisfaction::Mismatch
                             | RequirementSatisfaction::OutOfDate
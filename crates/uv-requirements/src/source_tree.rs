@@ -187,6 +187,15 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
                     path.user_display()
                 ));
             }
+            HashStrategy::Verify { .. } => {
+                if self.hasher.get_url(&url).is_validate() {
+                    return Err(anyhow::anyhow!(
+                        "Hash-checking is not supported for local directories: {}",
+                        path.user_display()
+                    ));
+                }
+                HashPolicy::None
+            }
         };
 
         // Fetch the metadata for the distribution.
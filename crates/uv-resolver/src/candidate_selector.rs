@@ -14,7 +14,7 @@ use crate::preferences::Preferences;
 use crate::prerelease_mode::PreReleaseStrategy;
 use crate::resolution_mode::ResolutionStrategy;
 use crate::version_map::{VersionMap, VersionMapDistHandle};
-use crate::{Exclusions, Manifest, Options};
+use crate::{Exclusions, LocalVersionPolicy, Manifest, Options};
 
 #[derive(Debug, Clone)]
 #[allow(clippy::struct_field_names)]
@@ -22,12 +22,14 @@ pub(crate) struct CandidateSelector {
     resolution_strategy: ResolutionStrategy,
     prerelease_strategy: PreReleaseStrategy,
     index_strategy: IndexStrategy,
+    prefer_find_links: bool,
+    local_version_policy: LocalVersionPolicy,
 }
 
 impl CandidateSelector {
     /// Return a [`CandidateSelector`] for the given [`Manifest`].
     pub(crate) fn for_resolution(
-        options: Options,
+        options: &Options,
         manifest: &Manifest,
         markers: Option<&MarkerEnvironment>,
     ) -> Self {
@@ -45,6 +47,8 @@ impl CandidateSelector {
                 options.dependency_mode,
             ),
             index_strategy: options.index_strategy,
+            prefer_find_links: options.prefer_find_links,
+            local_version_policy: options.local_version_policy,
         }
     }
 
@@ -97,7 +101,12 @@ impl CandidateSelector {
             installed_packages,
             exclusions,
         ) {
-            return Some(preferred);
+            // Ignore any preferred local versions (e.g., `1.2.3+local`), if disallowed.
+            if !(self.local_version_policy == LocalVersionPolicy::Forbid
+                && preferred.version.is_local())
+            {
+                return Some(preferred);
+            }
         }
 
         self.select_no_preference(package_name, range, version_maps)
@@ -218,6 +227,35 @@ impl CandidateSelector {
         );
         let highest = self.use_highest_version(package_name);
         let allow_prerelease = self.allow_prereleases(package_name);
+        let allow_local = self.local_version_policy != LocalVersionPolicy::Forbid;
+
+        // If requested, select from the versions available via `--find-links` first, falling back
+        // to the index if none of them are satisfactory.
+        if self.prefer_find_links {
+            let candidate = version_maps.iter().find_map(|version_map| {
+                let versions = version_map.iter().filter(|(_, dist)| dist.is_flat());
+                if highest {
+                    Self::select_candidate(
+                        versions.rev(),
+                        package_name,
+                        range,
+                        allow_prerelease,
+                        allow_local,
+                    )
+                } else {
+                    Self::select_candidate(
+                        versions,
+                        package_name,
+                        range,
+                        allow_prerelease,
+                        allow_local,
+                    )
+                }
+            });
+            if candidate.is_some() {
+                return candidate;
+            }
+        }
 
         if self.index_strategy == IndexStrategy::UnsafeBestMatch {
             if highest {
@@ -229,6 +267,7 @@ impl CandidateSelector {
                     package_name,
                     range,
                     allow_prerelease,
+                    allow_local,
                 )
             } else {
                 Self::select_candidate(
@@ -239,6 +278,7 @@ impl CandidateSelector {
                     package_name,
                     range,
                     allow_prerelease,
+                    allow_local,
                 )
             }
         } else {
@@ -249,6 +289,7 @@ impl CandidateSelector {
                         package_name,
                         range,
                         allow_prerelease,
+                        allow_local,
                     )
                 })
             } else {
@@ -258,6 +299,7 @@ impl CandidateSelector {
                         package_name,
                         range,
                         allow_prerelease,
+                        allow_local,
                     )
                 })
             }
@@ -283,6 +325,7 @@ impl CandidateSelector {
         package_name: &'a PackageName,
         range: &Range<Version>,
         allow_prerelease: AllowPreRelease,
+        allow_local: bool,
     ) -> Option<Candidate<'a>> {
         #[derive(Debug)]
         enum PreReleaseCandidate<'a> {
@@ -294,6 +337,12 @@ impl CandidateSelector {
         let mut steps = 0usize;
         for (version, maybe_dist) in versions {
             steps += 1;
+
+            // Skip any local versions (e.g., `1.2.3+local`), if disallowed.
+            if !allow_local && version.is_local() {
+                continue;
+            }
+
             let candidate = if version.any_prerelease() {
                 if range.contains(version) {
                     match allow_prerelease {
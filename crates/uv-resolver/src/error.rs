@@ -7,7 +7,7 @@ use pubgrub::range::Range;
 use pubgrub::report::{DefaultStringReporter, DerivationTree, External, Reporter};
 use rustc_hash::{FxHashMap, FxHashSet};
 
-use distribution_types::{BuiltDist, IndexLocations, InstalledDist, SourceDist};
+use distribution_types::{BuiltDist, IndexLocations, InstalledDist, SourceDist, VersionId};
 use pep440_rs::Version;
 use pep508_rs::{MarkerTree, Requirement};
 use uv_normalize::PackageName;
@@ -20,7 +20,8 @@ use crate::pubgrub::{
 };
 use crate::python_requirement::PythonRequirement;
 use crate::resolver::{
-    FxOnceMap, IncompletePackage, UnavailablePackage, UnavailableReason, VersionsResponse,
+    FxOnceMap, IncompletePackage, MetadataResponse, UnavailablePackage, UnavailableReason,
+    VersionsResponse,
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -194,6 +195,7 @@ impl ResolveError {
                     index_locations: None,
                     unavailable_packages: FxHashMap::default(),
                     incomplete_packages: FxHashMap::default(),
+                    url_versions: FxHashMap::default(),
                     fork_urls,
                 })
             }
@@ -217,6 +219,7 @@ pub struct NoSolutionError {
     index_locations: Option<IndexLocations>,
     unavailable_packages: FxHashMap<PackageName, UnavailablePackage>,
     incomplete_packages: FxHashMap<PackageName, BTreeMap<Version, IncompletePackage>>,
+    url_versions: FxHashMap<PackageName, Version>,
     fork_urls: ForkUrls,
 }
 
@@ -240,6 +243,7 @@ impl std::fmt::Display for NoSolutionError {
             &self.index_locations,
             &self.unavailable_packages,
             &self.incomplete_packages,
+            &self.url_versions,
             &self.fork_urls,
         ) {
             write!(f, "\n\n{hint}")?;
@@ -348,6 +352,30 @@ impl NoSolutionError {
         self
     }
 
+    /// Update the versions of any direct URL requirements attached to the error, as determined by
+    /// the metadata of the corresponding distributions.
+    #[must_use]
+    pub(crate) fn with_url_versions(
+        mut self,
+        distributions: &FxOnceMap<VersionId, Arc<MetadataResponse>>,
+    ) -> Self {
+        let mut new = FxHashMap::default();
+        for package in self.derivation_tree.packages() {
+            if let PubGrubPackageInner::Package { name, .. } = &**package {
+                let Some(url) = self.fork_urls.get(name) else {
+                    continue;
+                };
+                if let Some(response) = distributions.get(&VersionId::from_url(&url.verbatim)) {
+                    if let MetadataResponse::Found(ref archive) = *response {
+                        new.insert(name.clone(), archive.metadata.version.clone());
+                    }
+                }
+            }
+        }
+        self.url_versions = new;
+        self
+    }
+
     /// Update the Python requirements attached to the error.
     #[must_use]
     pub(crate) fn with_python_requirement(
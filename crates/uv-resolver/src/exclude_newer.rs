@@ -2,6 +2,8 @@ use std::str::FromStr;
 
 use chrono::{DateTime, Days, NaiveDate, NaiveTime, Utc};
 
+use uv_normalize::PackageName;
+
 /// A timestamp that excludes files newer than it.
 #[derive(Debug, Copy, Clone, serde::Deserialize, serde::Serialize)]
 pub struct ExcludeNewer(DateTime<Utc>);
@@ -52,6 +54,29 @@ impl std::fmt::Display for ExcludeNewer {
     }
 }
 
+/// A per-package override of the [`ExcludeNewer`] cutoff (e.g., `requests=2024-03-25`).
+#[derive(Debug, Clone)]
+pub struct ExcludeNewerPackageEntry {
+    pub package: PackageName,
+    pub timestamp: ExcludeNewer,
+}
+
+impl FromStr for ExcludeNewerPackageEntry {
+    type Err = String;
+
+    /// Parse an [`ExcludeNewerPackageEntry`] from a string in the format `PACKAGE=TIMESTAMP`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let Some((package, timestamp)) = input.split_once('=') else {
+            return Err(format!(
+                "`{input}` is not a valid per-package cutoff (expected `PACKAGE=TIMESTAMP`)"
+            ));
+        };
+        let package = PackageName::from_str(package.trim()).map_err(|err| err.to_string())?;
+        let timestamp = ExcludeNewer::from_str(timestamp.trim())?;
+        Ok(Self { package, timestamp })
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl schemars::JsonSchema for ExcludeNewer {
     fn schema_name() -> String {
@@ -1,8 +1,9 @@
 pub use dependency_mode::DependencyMode;
 pub use error::ResolveError;
-pub use exclude_newer::ExcludeNewer;
+pub use exclude_newer::{ExcludeNewer, ExcludeNewerPackageEntry};
 pub use exclusions::Exclusions;
 pub use flat_index::FlatIndex;
+pub use local_version_policy::LocalVersionPolicy;
 pub use lock::{Lock, LockError};
 pub use manifest::Manifest;
 pub use options::{Options, OptionsBuilder};
@@ -31,6 +32,7 @@ mod exclude_newer;
 mod exclusions;
 mod flat_index;
 mod fork_urls;
+mod local_version_policy;
 mod lock;
 mod manifest;
 mod marker;
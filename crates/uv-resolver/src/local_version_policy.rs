@@ -0,0 +1,17 @@
+/// The policy for handling PEP 440 local versions (e.g., `1.2.3+local`).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum LocalVersionPolicy {
+    /// Allow local versions during resolution, and include the local segment in the output.
+    #[default]
+    Keep,
+
+    /// Allow local versions during resolution, but omit the local segment from the output (e.g.,
+    /// `torch==2.0.0` rather than `torch==2.0.0+cpu`).
+    Strip,
+
+    /// Disallow local versions during resolution.
+    Forbid,
+}
@@ -32,6 +32,10 @@ pub struct Manifest {
     /// lockfile.
     pub(crate) preferences: Preferences,
 
+    /// The packages for which pre-release versions should be allowed, in addition to those
+    /// allowed by the [`PreReleaseMode`](crate::PreReleaseMode).
+    pub(crate) prerelease_packages: Vec<PackageName>,
+
     /// The name of the project.
     pub(crate) project: Option<PackageName>,
 
@@ -56,6 +60,7 @@ impl Manifest {
         overrides: Overrides,
         dev: Vec<GroupName>,
         preferences: Preferences,
+        prerelease_packages: Vec<PackageName>,
         project: Option<PackageName>,
         exclusions: Exclusions,
         lookaheads: Vec<RequestedRequirements>,
@@ -66,6 +71,7 @@ impl Manifest {
             overrides,
             dev,
             preferences,
+            prerelease_packages,
             project,
             exclusions,
             lookaheads,
@@ -79,6 +85,7 @@ impl Manifest {
             overrides: Overrides::default(),
             dev: Vec::new(),
             preferences: Preferences::default(),
+            prerelease_packages: Vec::new(),
             project: None,
             exclusions: Exclusions::default(),
             lookaheads: Vec::new(),
@@ -1,15 +1,21 @@
+use rustc_hash::FxHashMap;
+
 use uv_configuration::IndexStrategy;
+use uv_normalize::PackageName;
 
-use crate::{DependencyMode, ExcludeNewer, PreReleaseMode, ResolutionMode};
+use crate::{DependencyMode, ExcludeNewer, LocalVersionPolicy, PreReleaseMode, ResolutionMode};
 
 /// Options for resolving a manifest.
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Default, Clone)]
 pub struct Options {
     pub resolution_mode: ResolutionMode,
     pub prerelease_mode: PreReleaseMode,
     pub dependency_mode: DependencyMode,
     pub exclude_newer: Option<ExcludeNewer>,
+    pub exclude_newer_package: FxHashMap<PackageName, ExcludeNewer>,
     pub index_strategy: IndexStrategy,
+    pub prefer_find_links: bool,
+    pub local_version_policy: LocalVersionPolicy,
 }
 
 /// Builder for [`Options`].
@@ -19,7 +25,10 @@ pub struct OptionsBuilder {
     prerelease_mode: PreReleaseMode,
     dependency_mode: DependencyMode,
     exclude_newer: Option<ExcludeNewer>,
+    exclude_newer_package: FxHashMap<PackageName, ExcludeNewer>,
     index_strategy: IndexStrategy,
+    prefer_find_links: bool,
+    local_version_policy: LocalVersionPolicy,
 }
 
 impl OptionsBuilder {
@@ -56,6 +65,16 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the per-package exclusion dates, which take precedence over the global exclusion date.
+    #[must_use]
+    pub fn exclude_newer_package(
+        mut self,
+        exclude_newer_package: FxHashMap<PackageName, ExcludeNewer>,
+    ) -> Self {
+        self.exclude_newer_package = exclude_newer_package;
+        self
+    }
+
     /// Sets the index strategy.
     #[must_use]
     pub fn index_strategy(mut self, index_strategy: IndexStrategy) -> Self {
@@ -63,6 +82,20 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets whether to prefer versions from `--find-links` locations over those from the index.
+    #[must_use]
+    pub fn prefer_find_links(mut self, prefer_find_links: bool) -> Self {
+        self.prefer_find_links = prefer_find_links;
+        self
+    }
+
+    /// Sets the [`LocalVersionPolicy`].
+    #[must_use]
+    pub fn local_version_policy(mut self, local_version_policy: LocalVersionPolicy) -> Self {
+        self.local_version_policy = local_version_policy;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
@@ -70,7 +103,10 @@ impl OptionsBuilder {
             prerelease_mode: self.prerelease_mode,
             dependency_mode: self.dependency_mode,
             exclude_newer: self.exclude_newer,
+            exclude_newer_package: self.exclude_newer_package,
             index_strategy: self.index_strategy,
+            prefer_find_links: self.prefer_find_links,
+            local_version_policy: self.local_version_policy,
         }
     }
 }
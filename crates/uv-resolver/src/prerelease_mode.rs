@@ -59,40 +59,46 @@ impl PreReleaseStrategy {
         markers: Option<&MarkerEnvironment>,
         dependencies: DependencyMode,
     ) -> Self {
+        // Collect any packages that declare an explicit pre-release marker in their version
+        // requirements.
+        let explicit = || {
+            manifest
+                .requirements(markers, dependencies)
+                .filter(|requirement| {
+                    let RequirementSource::Registry { specifier, .. } = &requirement.source else {
+                        return false;
+                    };
+                    specifier
+                        .iter()
+                        .any(pep440_rs::VersionSpecifier::any_prerelease)
+                })
+                .map(|requirement| requirement.name.clone())
+        };
+
+        // Pre-releases are always allowed for any packages that were requested explicitly (e.g.,
+        // via `--pre=foo`).
+        let requested = manifest.prerelease_packages.iter().cloned();
+
         match mode {
-            PreReleaseMode::Disallow => Self::Disallow,
+            PreReleaseMode::Disallow => {
+                if manifest.prerelease_packages.is_empty() {
+                    Self::Disallow
+                } else {
+                    Self::Explicit(requested.collect())
+                }
+            }
             PreReleaseMode::Allow => Self::Allow,
-            PreReleaseMode::IfNecessary => Self::IfNecessary,
-            PreReleaseMode::Explicit => Self::Explicit(
-                manifest
-                    .requirements(markers, dependencies)
-                    .filter(|requirement| {
-                        let RequirementSource::Registry { specifier, .. } = &requirement.source
-                        else {
-                            return false;
-                        };
-                        specifier
-                            .iter()
-                            .any(pep440_rs::VersionSpecifier::any_prerelease)
-                    })
-                    .map(|requirement| requirement.name.clone())
-                    .collect(),
-            ),
-            PreReleaseMode::IfNecessaryOrExplicit => Self::IfNecessaryOrExplicit(
-                manifest
-                    .requirements(markers, dependencies)
-                    .filter(|requirement| {
-                        let RequirementSource::Registry { specifier, .. } = &requirement.source
-                        else {
-                            return false;
-                        };
-                        specifier
-                            .iter()
-                            .any(pep440_rs::VersionSpecifier::any_prerelease)
-                    })
-                    .map(|requirement| requirement.name.clone())
-                    .collect(),
-            ),
+            PreReleaseMode::IfNecessary => {
+                if manifest.prerelease_packages.is_empty() {
+                    Self::IfNecessary
+                } else {
+                    Self::IfNecessaryOrExplicit(requested.collect())
+                }
+            }
+            PreReleaseMode::Explicit => Self::Explicit(explicit().chain(requested).collect()),
+            PreReleaseMode::IfNecessaryOrExplicit => {
+                Self::IfNecessaryOrExplicit(explicit().chain(requested).collect())
+            }
         }
     }
 
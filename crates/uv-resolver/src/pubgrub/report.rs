@@ -408,6 +408,7 @@ impl PubGrubReportFormatter<'_> {
         index_locations: &Option<IndexLocations>,
         unavailable_packages: &FxHashMap<PackageName, UnavailablePackage>,
         incomplete_packages: &FxHashMap<PackageName, BTreeMap<Version, IncompletePackage>>,
+        url_versions: &FxHashMap<PackageName, Version>,
         fork_urls: &ForkUrls,
     ) -> IndexSet<PubGrubHint> {
         let mut hints = IndexSet::default();
@@ -426,6 +427,20 @@ impl PubGrubReportFormatter<'_> {
                     }
                 }
 
+                if let PubGrubPackageInner::Package { name, .. } = &**package {
+                    // Check for no versions due to a direct URL that doesn't satisfy the
+                    // requirements (e.g., a constraint).
+                    if let Some(version) = url_versions.get(name) {
+                        if !set.contains(version) {
+                            hints.insert(PubGrubHint::IncompatibleUrl {
+                                package: package.clone(),
+                                version: version.clone(),
+                                range: self.simplify_set(set, package).into_owned(),
+                            });
+                        }
+                    }
+                }
+
                 if let PubGrubPackageInner::Package { name, .. } = &**package {
                     // Check for no versions due to no `--find-links` flat index
                     if let Some(index_locations) = index_locations {
@@ -473,6 +488,7 @@ impl PubGrubReportFormatter<'_> {
                     index_locations,
                     unavailable_packages,
                     incomplete_packages,
+                    url_versions,
                     fork_urls,
                 ));
                 hints.extend(self.hints(
@@ -481,6 +497,7 @@ impl PubGrubReportFormatter<'_> {
                     index_locations,
                     unavailable_packages,
                     incomplete_packages,
+                    url_versions,
                     fork_urls,
                 ));
             }
@@ -688,6 +705,15 @@ pub(crate) enum PubGrubHint {
         #[derivative(PartialEq = "ignore", Hash = "ignore")]
         reason: String,
     },
+    /// A package was requested via a direct URL, but the version of the distribution at that URL
+    /// did not satisfy the requirements (e.g., a constraint).
+    IncompatibleUrl {
+        package: PubGrubPackage,
+        #[derivative(PartialEq = "ignore", Hash = "ignore")]
+        version: Version,
+        #[derivative(PartialEq = "ignore", Hash = "ignore")]
+        range: Range<Version>,
+    },
     /// The `Requires-Python` requirement was not satisfied.
     RequiresPython {
         requires_python: RequiresPython,
@@ -706,21 +732,23 @@ impl std::fmt::Display for PubGrubHint {
             Self::PreReleaseAvailable { package, version } => {
                 write!(
                     f,
-                    "{}{} Pre-releases are available for {} in the requested range (e.g., {}), but pre-releases weren't enabled (try: `--prerelease=allow`)",
+                    "{}{} Pre-releases are available for {} in the requested range (e.g., {}), but pre-releases weren't enabled (try: {})",
                     "hint".bold().cyan(),
                     ":".bold(),
                     package.bold(),
-                    version.bold()
+                    version.bold(),
+                    PreReleaseSuggestion(package)
                 )
             }
             Self::PreReleaseRequested { package, range } => {
                 write!(
                     f,
-                    "{}{} {} was requested with a pre-release marker (e.g., {}), but pre-releases weren't enabled (try: `--prerelease=allow`)",
+                    "{}{} {} was requested with a pre-release marker (e.g., {}), but pre-releases weren't enabled (try: {})",
                     "hint".bold().cyan(),
                     ":".bold(),
                     package.bold(),
-                    PackageRange::compatibility(package, range).bold()
+                    PackageRange::compatibility(package, range).bold(),
+                    PreReleaseSuggestion(package)
                 )
             }
             Self::NoIndex => {
@@ -823,6 +851,21 @@ impl std::fmt::Display for PubGrubHint {
                     textwrap::indent(reason, "  ")
                 )
             }
+            Self::IncompatibleUrl {
+                package,
+                version,
+                range,
+            } => {
+                write!(
+                    f,
+                    "{}{} {} was requested with a direct URL, but the version at that URL ({}) does not satisfy {}",
+                    "hint".bold().cyan(),
+                    ":".bold(),
+                    package.bold(),
+                    version.bold(),
+                    PackageRange::compatibility(package, range).bold(),
+                )
+            }
             Self::RequiresPython {
                 requires_python,
                 package,
@@ -1118,3 +1161,16 @@ impl<T: std::fmt::Display> std::fmt::Display for Padded<'_, T> {
         write!(f, "{result}")
     }
 }
+
+/// The flags that would enable pre-release versions for a package, for use in a hint.
+struct PreReleaseSuggestion<'a>(&'a PubGrubPackage);
+
+impl std::fmt::Display for PreReleaseSuggestion<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.0.name() {
+            write!(f, "`--pre={name}` or `--prerelease=allow`")
+        } else {
+            write!(f, "`--prerelease=allow`")
+        }
+    }
+}
@@ -4,11 +4,12 @@ use owo_colors::OwoColorize;
 use petgraph::algo::greedy_feedback_arc_set;
 use petgraph::visit::{EdgeRef, Topo};
 use petgraph::Direction;
-use rustc_hash::{FxBuildHasher, FxHashMap};
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 
 use distribution_types::{DistributionMetadata, Name, SourceAnnotation, SourceAnnotations};
 use pep508_rs::MarkerEnvironment;
 use pep508_rs::MarkerTree;
+use pypi_types::Yanked;
 use uv_normalize::PackageName;
 
 use crate::resolution::{RequirementsTxtDist, ResolutionGraphNode};
@@ -24,6 +25,9 @@ pub struct DisplayResolutionGraph<'a> {
     marker_env: Option<&'a MarkerEnvironment>,
     /// The packages to exclude from the output.
     no_emit_packages: &'a [PackageName],
+    /// The packages to prune from the output, along with any dependencies that are only reachable
+    /// through them.
+    prune_packages: &'a [PackageName],
     /// Whether to include hashes in the output.
     show_hashes: bool,
     /// Whether to include extras in the output (e.g., `black[colorama]`).
@@ -35,12 +39,22 @@ pub struct DisplayResolutionGraph<'a> {
     include_annotations: bool,
     /// Whether to include indexes in the output, to indicate which index was used for each package.
     include_index_annotation: bool,
+    /// Whether to include the reason for each yanked package in the output.
+    include_yanked_reason: bool,
+    /// Whether to include a note for each package that was built from source.
+    include_build_isolation_note: bool,
+    /// Whether to include the local segment of each pinned version in the output (e.g., the
+    /// `+cpu` in `torch==2.0.0+cpu`).
+    include_local_versions: bool,
+    /// Whether to write each pinned version in its canonical form, without trailing zeros (e.g.,
+    /// `2` rather than `2.0.0`).
+    strip_trailing_zeros: bool,
     /// The style of annotation comments, used to indicate the dependencies that requested each
     /// package.
     annotation_style: AnnotationStyle,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum DisplayResolutionGraphNode {
     Root,
     Dist(RequirementsTxtDist),
@@ -52,6 +66,11 @@ impl<'a> From<&'a ResolutionGraph> for DisplayResolutionGraph<'a> {
             resolution,
             None,
             &[],
+            &[],
+            false,
+            false,
+            false,
+            true,
             false,
             false,
             false,
@@ -69,22 +88,32 @@ impl<'a> DisplayResolutionGraph<'a> {
         underlying: &'a ResolutionGraph,
         marker_env: Option<&'a MarkerEnvironment>,
         no_emit_packages: &'a [PackageName],
+        prune_packages: &'a [PackageName],
         show_hashes: bool,
         include_extras: bool,
         include_markers: bool,
         include_annotations: bool,
         include_index_annotation: bool,
+        include_yanked_reason: bool,
+        include_build_isolation_note: bool,
+        include_local_versions: bool,
+        strip_trailing_zeros: bool,
         annotation_style: AnnotationStyle,
     ) -> DisplayResolutionGraph<'a> {
         Self {
             resolution: underlying,
             marker_env,
             no_emit_packages,
+            prune_packages,
             show_hashes,
             include_extras,
             include_markers,
             include_annotations,
             include_index_annotation,
+            include_yanked_reason,
+            include_build_isolation_note,
+            include_local_versions,
+            strip_trailing_zeros,
             annotation_style,
         }
     }
@@ -147,6 +176,10 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
         // Convert from `AnnotatedDist` to `RequirementsTxtDist`.
         let petgraph = to_requirements_txt_graph(&self.resolution.petgraph);
 
+        // Prune any requested packages, along with any dependencies that are only reachable
+        // through them.
+        let petgraph = prune(petgraph, self.prune_packages);
+
         // Propagate markers across the graph.
         let petgraph = propagate_markers(petgraph);
 
@@ -175,7 +208,12 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
         for (index, node) in nodes {
             // Display the node itself.
             let mut line = node
-                .to_requirements_txt(self.include_extras, self.include_markers)
+                .to_requirements_txt(
+                    self.include_extras,
+                    self.include_markers,
+                    self.include_local_versions,
+                    self.strip_trailing_zeros,
+                )
                 .to_string();
 
             // Display the distribution hashes, if any.
@@ -282,6 +320,26 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                     writeln!(f, "{}", format!("    # from {url}").green())?;
                 }
             }
+
+            // If enabled, include the reason for any yanked packages (e.g.,
+            // `# YANKED: security issue`).
+            if self.include_yanked_reason {
+                match node.dist.yanked() {
+                    None | Some(Yanked::Bool(false)) => {}
+                    Some(Yanked::Bool(true)) => {
+                        writeln!(f, "{}", "    # YANKED".green())?;
+                    }
+                    Some(Yanked::Reason(reason)) => {
+                        writeln!(f, "{}", format!("    # YANKED: {}", reason.trim()).green())?;
+                    }
+                }
+            }
+
+            // If enabled, include a note for any packages that were built from source, and so will
+            // require a build backend at install time (e.g., `# built from source`).
+            if self.include_build_isolation_note && node.dist.is_source() {
+                writeln!(f, "{}", "    # built from source".green())?;
+            }
         }
 
         Ok(())
@@ -344,6 +402,39 @@ fn to_requirements_txt_graph(graph: &ResolutionPetGraph) -> IntermediatePetGraph
     next
 }
 
+/// Remove the given packages from the graph, along with any packages that are no longer
+/// reachable from the root once they're removed.
+fn prune(graph: IntermediatePetGraph, packages: &[PackageName]) -> IntermediatePetGraph {
+    if packages.is_empty() {
+        return graph;
+    }
+
+    // Walk the graph from the root, without traversing into any of the pruned packages.
+    let mut reachable = FxHashSet::default();
+    let mut queue = graph
+        .node_indices()
+        .filter(|index| matches!(graph[*index], DisplayResolutionGraphNode::Root))
+        .collect::<Vec<_>>();
+    while let Some(index) = queue.pop() {
+        if !reachable.insert(index) {
+            continue;
+        }
+        for child in graph.neighbors_directed(index, Direction::Outgoing) {
+            if let DisplayResolutionGraphNode::Dist(dist) = &graph[child] {
+                if packages.contains(dist.name()) {
+                    continue;
+                }
+            }
+            queue.push(child);
+        }
+    }
+
+    graph.filter_map(
+        |index, node| reachable.contains(&index).then(|| node.clone()),
+        |_, edge| Some(edge.clone()),
+    )
+}
+
 /// Propagate the [`MarkerTree`] qualifiers across the graph.
 ///
 /// The graph is directed, so if any edge contains a marker, we need to propagate it to all
@@ -27,10 +27,18 @@ impl RequirementsTxtDist {
     /// This typically results in a PEP 508 representation of the requirement, but will write an
     /// unnamed requirement for relative paths, which can't be represented with PEP 508 (but are
     /// supported in `requirements.txt`).
+    ///
+    /// If `include_local_versions` is `false`, the local segment of the pinned version is omitted
+    /// (e.g., `torch==2.0.0` rather than `torch==2.0.0+cpu`). If `strip_trailing_zeros` is `true`,
+    /// the pinned version is written in its canonical form (e.g., `iniconfig==2` rather than
+    /// `iniconfig==2.0.0`).
+    #[allow(clippy::fn_params_excessive_bools)]
     pub(crate) fn to_requirements_txt(
         &self,
         include_extras: bool,
         include_markers: bool,
+        include_local_versions: bool,
+        strip_trailing_zeros: bool,
     ) -> Cow<str> {
         // If the URL is editable, write it as an editable requirement.
         if self.dist.is_editable() {
@@ -97,13 +105,44 @@ impl RequirementsTxtDist {
             }
         }
 
+        // If requested, strip the local segment and any trailing zeros from the pinned version.
+        let local_version = match self.version_or_url() {
+            VersionOrUrlRef::Version(version) => {
+                let strip_local = !include_local_versions && version.is_local();
+                let strip_zeros = strip_trailing_zeros && version.release().last() == Some(&0);
+                if strip_local || strip_zeros {
+                    let mut version = version.clone();
+                    if strip_local {
+                        version = version.without_local();
+                    }
+                    if strip_zeros {
+                        version = version.without_trailing_zeros();
+                    }
+                    Some(version)
+                } else {
+                    None
+                }
+            }
+            VersionOrUrlRef::Url(_) => None,
+        };
+
         if self.extras.is_empty() || !include_extras {
-            if let Some(markers) = self.markers.as_ref().filter(|_| include_markers) {
-                Cow::Owned(format!("{} ; {}", self.dist.verbatim(), markers,))
+            let verbatim = if let Some(version) = local_version.as_ref() {
+                Cow::Owned(format!("{}=={}", self.name(), version))
             } else {
                 self.dist.verbatim()
+            };
+            if let Some(markers) = self.markers.as_ref().filter(|_| include_markers) {
+                Cow::Owned(format!("{verbatim} ; {markers}"))
+            } else {
+                verbatim
             }
         } else {
+            let version_or_url = if let Some(version) = local_version.as_ref() {
+                Cow::Owned(format!("=={version}"))
+            } else {
+                self.version_or_url().verbatim()
+            };
             let mut extras = self.extras.clone();
             extras.sort_unstable();
             extras.dedup();
@@ -112,7 +151,7 @@ impl RequirementsTxtDist {
                     "{}[{}]{} ; {}",
                     self.name(),
                     extras.into_iter().join(", "),
-                    self.version_or_url().verbatim(),
+                    version_or_url,
                     markers,
                 ))
             } else {
@@ -120,7 +159,7 @@ impl RequirementsTxtDist {
                     "{}[{}]{}",
                     self.name(),
                     extras.into_iter().join(", "),
-                    self.version_or_url().verbatim()
+                    version_or_url
                 ))
             }
         }
@@ -158,6 +158,7 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             AllowedYanks::from_manifest(&manifest, markers, options.dependency_mode),
             hasher,
             options.exclude_newer,
+            options.exclude_newer_package.clone(),
             build_context.build_options(),
         );
 
@@ -200,7 +201,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             git: git.clone(),
             unavailable_packages: DashMap::default(),
             incomplete_packages: DashMap::default(),
-            selector: CandidateSelector::for_resolution(options, &manifest, markers),
+            selector: CandidateSelector::for_resolution(&options, &manifest, markers),
             dependency_mode: options.dependency_mode,
             urls: Urls::from_manifest(&manifest, markers, git, options.dependency_mode)?,
             locals: Locals::from_manifest(&manifest, markers, options.dependency_mode),
@@ -284,7 +285,8 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
                             .with_python_requirement(&state.python_requirement)
                             .with_index_locations(provider.index_locations())
                             .with_unavailable_packages(&state.unavailable_packages)
-                            .with_incomplete_packages(&state.incomplete_packages),
+                            .with_incomplete_packages(&state.incomplete_packages)
+                            .with_url_versions(state.index.distributions()),
                     )
                 } else {
                     err
@@ -2,6 +2,7 @@ use std::future::Future;
 
 use distribution_types::{Dist, IndexLocations};
 use platform_tags::Tags;
+use rustc_hash::FxHashMap;
 use uv_configuration::BuildOptions;
 use uv_distribution::{ArchiveMetadata, DistributionDatabase};
 use uv_normalize::PackageName;
@@ -81,6 +82,7 @@ pub struct DefaultResolverProvider<'a, Context: BuildContext> {
     allowed_yanks: AllowedYanks,
     hasher: HashStrategy,
     exclude_newer: Option<ExcludeNewer>,
+    exclude_newer_package: FxHashMap<PackageName, ExcludeNewer>,
     build_options: &'a BuildOptions,
 }
 
@@ -94,6 +96,7 @@ impl<'a, Context: BuildContext> DefaultResolverProvider<'a, Context> {
         allowed_yanks: AllowedYanks,
         hasher: &'a HashStrategy,
         exclude_newer: Option<ExcludeNewer>,
+        exclude_newer_package: FxHashMap<PackageName, ExcludeNewer>,
         build_options: &'a BuildOptions,
     ) -> Self {
         Self {
@@ -104,6 +107,7 @@ impl<'a, Context: BuildContext> DefaultResolverProvider<'a, Context> {
             allowed_yanks,
             hasher: hasher.clone(),
             exclude_newer,
+            exclude_newer_package,
             build_options,
         }
     }
@@ -134,7 +138,9 @@ impl<'a, Context: BuildContext> ResolverProvider for DefaultResolverProvider<'a,
                             &self.python_requirement,
                             &self.allowed_yanks,
                             &self.hasher,
-                            self.exclude_newer.as_ref(),
+                            self.exclude_newer_package
+                                .get(package_name)
+                                .or(self.exclude_newer.as_ref()),
                             self.flat_index.get(package_name).cloned(),
                             self.build_options,
                         )
@@ -236,6 +236,17 @@ enum VersionMapDistHandleInner<'a> {
 }
 
 impl<'a> VersionMapDistHandle<'a> {
+    /// Returns `true` if the distribution includes files from a `--find-links` location.
+    pub(crate) fn is_flat(&self) -> bool {
+        match self.inner {
+            VersionMapDistHandleInner::Eager(_) => true,
+            VersionMapDistHandleInner::Lazy { dist, .. } => matches!(
+                dist,
+                LazyPrioritizedDist::OnlyFlat(_) | LazyPrioritizedDist::Both { .. }
+            ),
+        }
+    }
+
     /// Returns a prioritized distribution from this handle.
     pub(crate) fn prioritized_dist(&self) -> Option<&'a PrioritizedDist> {
         match self.inner {
@@ -3,7 +3,10 @@ use std::path::PathBuf;
 
 use distribution_types::IndexUrl;
 use install_wheel_rs::linker::LinkMode;
-use uv_configuration::{ConfigSettings, IndexStrategy, KeyringProviderType, TargetTriple};
+use uv_configuration::{
+    ConfigSettings, IndexStrategy, KeyringProviderType, PackageConfigSettings, TargetTriple,
+    UpgradeStrategy,
+};
 use uv_resolver::{AnnotationStyle, ExcludeNewer, PreReleaseMode, ResolutionMode};
 use uv_toolchain::{PythonVersion, ToolchainPreference};
 
@@ -70,6 +73,7 @@ impl_combine_or!(ResolutionMode);
 impl_combine_or!(String);
 impl_combine_or!(TargetTriple);
 impl_combine_or!(ToolchainPreference);
+impl_combine_or!(UpgradeStrategy);
 impl_combine_or!(bool);
 
 impl<T> Combine for Option<Vec<T>> {
@@ -96,3 +100,14 @@ impl Combine for Option<ConfigSettings> {
         }
     }
 }
+
+impl Combine for Option<PackageConfigSettings> {
+    /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
+    /// `Some`.
+    fn combine(self, other: Option<PackageConfigSettings>) -> Option<PackageConfigSettings> {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.merge(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
@@ -6,7 +6,8 @@ use distribution_types::{FlatIndexLocation, IndexUrl};
 use install_wheel_rs::linker::LinkMode;
 use pypi_types::VerbatimParsedUrl;
 use uv_configuration::{
-    ConfigSettings, IndexStrategy, KeyringProviderType, PackageNameSpecifier, TargetTriple,
+    ConfigSettings, IndexStrategy, KeyringProviderType, PackageConfigSettings,
+    PackageNameSpecifier, TargetTriple, UpgradeStrategy,
 };
 use uv_macros::CombineOptions;
 use uv_normalize::{ExtraName, PackageName};
@@ -99,6 +100,7 @@ pub struct ResolverOptions {
     pub keyring_provider: Option<KeyringProviderType>,
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PreReleaseMode>,
+    pub prerelease_package: Option<Vec<PackageName>>,
     pub config_settings: Option<ConfigSettings>,
     pub exclude_newer: Option<ExcludeNewer>,
     pub link_mode: Option<LinkMode>,
@@ -125,6 +127,7 @@ pub struct ResolverInstallerOptions {
     pub keyring_provider: Option<KeyringProviderType>,
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PreReleaseMode>,
+    pub prerelease_package: Option<Vec<PackageName>>,
     pub config_settings: Option<ConfigSettings>,
     pub exclude_newer: Option<ExcludeNewer>,
     pub link_mode: Option<LinkMode>,
@@ -160,12 +163,14 @@ pub struct PipOptions {
     pub no_binary: Option<Vec<PackageNameSpecifier>>,
     pub only_binary: Option<Vec<PackageNameSpecifier>>,
     pub no_build_isolation: Option<bool>,
+    pub no_sources: Option<bool>,
     pub strict: Option<bool>,
     pub extra: Option<Vec<ExtraName>>,
     pub all_extras: Option<bool>,
     pub no_deps: Option<bool>,
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PreReleaseMode>,
+    pub prerelease_package: Option<Vec<PackageName>>,
     pub output_file: Option<PathBuf>,
     pub no_strip_extras: Option<bool>,
     pub no_strip_markers: Option<bool>,
@@ -175,25 +180,36 @@ pub struct PipOptions {
     pub generate_hashes: Option<bool>,
     pub legacy_setup_py: Option<bool>,
     pub config_settings: Option<ConfigSettings>,
+    pub config_settings_package: Option<PackageConfigSettings>,
     pub python_version: Option<PythonVersion>,
     pub python_platform: Option<TargetTriple>,
     pub universal: Option<bool>,
     pub exclude_newer: Option<ExcludeNewer>,
     pub no_emit_package: Option<Vec<PackageName>>,
+    pub allow_unsafe: Option<bool>,
+    pub unsafe_package: Option<Vec<PackageName>>,
     pub emit_index_url: Option<bool>,
     pub emit_find_links: Option<bool>,
     pub emit_build_options: Option<bool>,
     pub emit_marker_expression: Option<bool>,
     pub emit_index_annotation: Option<bool>,
+    pub keep_yanked_reason: Option<bool>,
+    pub emit_build_isolation_note: Option<bool>,
+    pub emit_input_hash: Option<bool>,
+    pub emit_python_version: Option<bool>,
+    pub emit_timestamp: Option<bool>,
+    pub emit_workspace_members: Option<bool>,
     pub annotation_style: Option<AnnotationStyle>,
     pub link_mode: Option<LinkMode>,
     pub compile_bytecode: Option<bool>,
     pub require_hashes: Option<bool>,
     pub upgrade: Option<bool>,
     pub upgrade_package: Option<Vec<PackageName>>,
+    pub upgrade_strategy: Option<UpgradeStrategy>,
     pub reinstall: Option<bool>,
     pub reinstall_package: Option<Vec<PackageName>>,
     pub concurrent_downloads: Option<NonZeroUsize>,
+    pub concurrent_requests: Option<NonZeroUsize>,
     pub concurrent_builds: Option<NonZeroUsize>,
     pub concurrent_installs: Option<NonZeroUsize>,
 }
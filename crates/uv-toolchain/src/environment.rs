@@ -79,13 +79,24 @@ impl PythonEnvironment {
         preference: EnvironmentPreference,
         cache: &Cache,
     ) -> Result<Self, Error> {
-        let toolchain = match find_toolchain(
+        // Ignore managed toolchains when looking for environments
+        Self::find_with_toolchain_preference(
             request,
             preference,
-            // Ignore managed toolchains when looking for environments
             ToolchainPreference::OnlySystem,
             cache,
-        )? {
+        )
+    }
+
+    /// Find a [`PythonEnvironment`] matching the given request and preference, using the given
+    /// [`ToolchainPreference`] to choose between managed and system toolchains.
+    pub fn find_with_toolchain_preference(
+        request: &ToolchainRequest,
+        preference: EnvironmentPreference,
+        toolchain_preference: ToolchainPreference,
+        cache: &Cache,
+    ) -> Result<Self, Error> {
+        let toolchain = match find_toolchain(request, preference, toolchain_preference, cache)? {
             Ok(toolchain) => toolchain,
             Err(err) => return Err(EnvironmentNotFound::from(err).into()),
         };
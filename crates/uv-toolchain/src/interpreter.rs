@@ -817,4 +817,107 @@ mod tests {
             Version::from_str("3.13").unwrap()
         );
     }
+
+    #[test]
+    fn test_externally_managed() {
+        let mock_dir = tempdir().unwrap();
+        let mocked_interpreter = mock_dir.path().join("python");
+        let stdlib = mock_dir.path().join("lib").join("python3.12");
+        fs::create_dir_all(&stdlib).unwrap();
+
+        // A system interpreter, i.e., one for which `sys.prefix` matches `sys.base_prefix`.
+        let json = indoc! {r##"
+            {
+                "result": "success",
+                "platform": {
+                    "os": {
+                        "name": "manylinux",
+                        "major": 2,
+                        "minor": 38
+                    },
+                    "arch": "x86_64"
+                },
+                "markers": {
+                    "implementation_name": "cpython",
+                    "implementation_version": "3.12.0",
+                    "os_name": "posix",
+                    "platform_machine": "x86_64",
+                    "platform_python_implementation": "CPython",
+                    "platform_release": "6.5.0-13-generic",
+                    "platform_system": "Linux",
+                    "platform_version": "#13-Ubuntu SMP PREEMPT_DYNAMIC Fri Nov  3 12:16:05 UTC 2023",
+                    "python_full_version": "3.12.0",
+                    "python_version": "3.12",
+                    "sys_platform": "linux"
+                },
+                "sys_base_exec_prefix": "/usr",
+                "sys_base_prefix": "/usr",
+                "sys_prefix": "/usr",
+                "sys_executable": "/usr/bin/python3",
+                "sys_path": [
+                    "/usr/lib/python3.12",
+                    "/usr/lib/python3.12/site-packages"
+                ],
+                "stdlib": "[STDLIB]",
+                "scheme": {
+                    "data": "/usr",
+                    "include": "/usr/include/python3.12",
+                    "platlib": "/usr/lib/python3.12/site-packages",
+                    "purelib": "/usr/lib/python3.12/site-packages",
+                    "scripts": "/usr/bin"
+                },
+                "virtualenv": {
+                    "data": "",
+                    "include": "include",
+                    "platlib": "lib/python3.12/site-packages",
+                    "purelib": "lib/python3.12/site-packages",
+                    "scripts": "bin"
+                },
+                "pointer_size": "64",
+                "gil_disabled": false
+            }
+        "##}
+        .replace("[STDLIB]", stdlib.to_str().unwrap());
+
+        let cache = Cache::temp().unwrap().init().unwrap();
+
+        fs::write(
+            &mocked_interpreter,
+            formatdoc! {r##"
+            #!/bin/bash
+            echo '{json}'
+            "##},
+        )
+        .unwrap();
+
+        fs::set_permissions(
+            &mocked_interpreter,
+            std::os::unix::fs::PermissionsExt::from_mode(0o770),
+        )
+        .unwrap();
+
+        // Without an `EXTERNALLY-MANAGED` file, the interpreter is not externally managed.
+        let interpreter = Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert!(interpreter.is_externally_managed().is_none());
+
+        // With an `EXTERNALLY-MANAGED` file, the error message should be surfaced.
+        fs::write(
+            stdlib.join("EXTERNALLY-MANAGED"),
+            indoc! {r"
+            [externally-managed]
+            Error=This Python installation is managed by the operating system.
+            "},
+        )
+        .unwrap();
+        let externally_managed = interpreter.is_externally_managed().unwrap();
+        assert_eq!(
+            externally_managed.into_error().as_deref(),
+            Some("This Python installation is managed by the operating system.")
+        );
+
+        // A file without an `Error` key still marks the interpreter as externally managed.
+        fs::write(stdlib.join("EXTERNALLY-MANAGED"), "[externally-managed]\n").unwrap();
+        let externally_managed = interpreter.is_externally_managed().unwrap();
+        assert!(externally_managed.into_error().is_none());
+    }
 }
@@ -100,9 +100,11 @@ mod tests {
         ToolchainPreference,
     };
     use crate::{
-        implementation::ImplementationName, managed::InstalledToolchains, toolchain::Toolchain,
-        virtualenv::virtualenv_python_executable, PythonVersion, ToolchainNotFound,
-        ToolchainRequest, ToolchainSource,
+        implementation::ImplementationName,
+        managed::{platform_key_from_env, InstalledToolchains},
+        toolchain::Toolchain,
+        virtualenv::virtualenv_python_executable,
+        PythonEnvironment, PythonVersion, ToolchainNotFound, ToolchainRequest, ToolchainSource,
     };
 
     struct TestContext {
@@ -1862,4 +1864,48 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn find_environment_toolchain_preference() -> Result<()> {
+        let mut context = TestContext::new()?;
+        context.add_python_versions(&["3.10.0"])?;
+
+        // Install a managed toolchain alongside the system interpreter.
+        let managed = context
+            .toolchains
+            .root()
+            .join(format!("cpython-3.12.0-{}", platform_key_from_env()))
+            .join("install")
+            .join("bin")
+            .join("python3");
+        TestContext::create_mock_interpreter(
+            &managed,
+            &PythonVersion::from_str("3.12.0").unwrap(),
+            ImplementationName::CPython,
+            true,
+        )?;
+
+        for (preference, expected) in [
+            (ToolchainPreference::OnlyManaged, "3.12.0"),
+            (ToolchainPreference::PreferManaged, "3.12.0"),
+            (ToolchainPreference::PreferSystem, "3.10.0"),
+            (ToolchainPreference::OnlySystem, "3.10.0"),
+        ] {
+            let environment = context.run(|| {
+                PythonEnvironment::find_with_toolchain_preference(
+                    &ToolchainRequest::Any,
+                    EnvironmentPreference::OnlySystem,
+                    preference,
+                    &context.cache,
+                )
+            })?;
+            assert_eq!(
+                environment.interpreter().python_full_version().to_string(),
+                expected,
+                "With {preference:?}, the {expected} interpreter should be selected"
+            );
+        }
+
+        Ok(())
+    }
 }
@@ -292,7 +292,7 @@ impl InstalledToolchain {
 }
 
 /// Generate a platform portion of a key from the environment.
-fn platform_key_from_env() -> String {
+pub(crate) fn platform_key_from_env() -> String {
     let os = Os::from_env();
     let arch = Arch::from_env();
     let libc = Libc::from_env();
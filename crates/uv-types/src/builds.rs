@@ -1,3 +1,4 @@
+use uv_normalize::PackageName;
 use uv_toolchain::PythonEnvironment;
 
 /// Whether to enforce build isolation when building source distributions.
@@ -5,12 +6,39 @@ use uv_toolchain::PythonEnvironment;
 pub enum BuildIsolation<'a> {
     #[default]
     Isolated,
+    /// Build in an isolated environment that can also import the packages installed in the given
+    /// environment, with lower priority than the build requirements.
+    Inherited(&'a PythonEnvironment),
     Shared(&'a PythonEnvironment),
+    /// Build the given packages in the shared environment, and all other packages in an isolated
+    /// environment.
+    SharedPackage(&'a PythonEnvironment, &'a [PackageName]),
 }
 
 impl<'a> BuildIsolation<'a> {
     /// Returns `true` if build isolation is enforced.
     pub fn is_isolated(&self) -> bool {
-        matches!(self, Self::Isolated)
+        matches!(
+            self,
+            Self::Isolated | Self::Inherited(_) | Self::SharedPackage(..)
+        )
+    }
+
+    /// Resolve the build isolation for the given package.
+    ///
+    /// [`BuildIsolation::SharedPackage`] is replaced with [`BuildIsolation::Shared`] if the package
+    /// is one of the named packages, and [`BuildIsolation::Isolated`] otherwise.
+    #[must_use]
+    pub fn for_package(self, package: Option<&PackageName>) -> Self {
+        match self {
+            Self::SharedPackage(environment, packages) => {
+                if package.is_some_and(|package| packages.contains(package)) {
+                    Self::Shared(environment)
+                } else {
+                    Self::Isolated
+                }
+            }
+            _ => self,
+        }
     }
 }
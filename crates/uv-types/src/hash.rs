@@ -18,6 +18,9 @@ pub enum HashStrategy {
     /// Hashes should be validated against a pre-defined list of hashes. If necessary, hashes should
     /// be generated so as to ensure that the archive is valid.
     Validate(FxHashMap<PackageId, Vec<HashDigest>>),
+    /// Hashes should be validated for those packages that are included in the pre-defined list of
+    /// hashes. Packages without hashes are allowed, and are not validated.
+    Verify(FxHashMap<PackageId, Vec<HashDigest>>),
 }
 
 impl HashStrategy {
@@ -32,6 +35,10 @@ impl HashStrategy {
                     .map(Vec::as_slice)
                     .unwrap_or_default(),
             ),
+            Self::Verify(hashes) => hashes
+                .get(&distribution.package_id())
+                .map(Vec::as_slice)
+                .map_or(HashPolicy::None, HashPolicy::Validate),
         }
     }
 
@@ -46,6 +53,10 @@ impl HashStrategy {
                     .map(Vec::as_slice)
                     .unwrap_or_default(),
             ),
+            Self::Verify(hashes) => hashes
+                .get(&PackageId::from_registry(name.clone()))
+                .map(Vec::as_slice)
+                .map_or(HashPolicy::None, HashPolicy::Validate),
         }
     }
 
@@ -60,6 +71,10 @@ impl HashStrategy {
                     .map(Vec::as_slice)
                     .unwrap_or_default(),
             ),
+            Self::Verify(hashes) => hashes
+                .get(&PackageId::from_url(url))
+                .map(Vec::as_slice)
+                .map_or(HashPolicy::None, HashPolicy::Validate),
         }
     }
 
@@ -69,6 +84,7 @@ impl HashStrategy {
             Self::None => true,
             Self::Generate => true,
             Self::Validate(hashes) => hashes.contains_key(&PackageId::from_registry(name.clone())),
+            Self::Verify(_) => true,
         }
     }
 
@@ -78,6 +94,7 @@ impl HashStrategy {
             Self::None => true,
             Self::Generate => true,
             Self::Validate(hashes) => hashes.contains_key(&PackageId::from_url(url)),
+            Self::Verify(_) => true,
         }
     }
 
@@ -127,6 +144,54 @@ impl HashStrategy {
 
         Ok(Self::Validate(hashes))
     }
+
+    /// Generate the hashes to verify from a set of [`UnresolvedRequirement`] entries.
+    ///
+    /// Unlike [`HashStrategy::from_requirements`], requirements without hashes are allowed (and
+    /// are not validated), and requirements with hashes need not be pinned.
+    pub fn verify_from_requirements<'a>(
+        requirements: impl Iterator<Item = (&'a UnresolvedRequirement, &'a [String])>,
+        markers: Option<&MarkerEnvironment>,
+    ) -> Result<Self, HashStrategyError> {
+        let mut hashes = FxHashMap::<PackageId, Vec<HashDigest>>::default();
+
+        // For each requirement, map from name to allowed hashes. We use the last entry for each
+        // package.
+        for (requirement, digests) in requirements {
+            if digests.is_empty() {
+                continue;
+            }
+
+            if !requirement.evaluate_markers(markers, &[]) {
+                continue;
+            }
+
+            let id = match &requirement {
+                UnresolvedRequirement::Named(requirement) => match &requirement.source {
+                    RequirementSource::Registry { .. } => {
+                        PackageId::from_registry(requirement.name.clone())
+                    }
+                    RequirementSource::Url { url, .. }
+                    | RequirementSource::Git { url, .. }
+                    | RequirementSource::Path { url, .. }
+                    | RequirementSource::Directory { url, .. } => PackageId::from_url(url),
+                },
+                UnresolvedRequirement::Unnamed(requirement) => {
+                    PackageId::from_url(&requirement.url.verbatim)
+                }
+            };
+
+            // Parse the hashes.
+            let digests = digests
+                .iter()
+                .map(|digest| HashDigest::from_str(digest))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            hashes.insert(id, digests);
+        }
+
+        Ok(Self::Verify(hashes))
+    }
 }
 
 fn uv_requirement_to_package_id(requirement: &Requirement) -> Result<PackageId, HashStrategyError> {
@@ -7,7 +7,7 @@ use distribution_types::{CachedDist, IndexLocations, InstalledDist, Resolution,
 use pep508_rs::PackageName;
 use pypi_types::Requirement;
 use uv_cache::Cache;
-use uv_configuration::{BuildKind, BuildOptions};
+use uv_configuration::{BuildKind, BuildOptions, SourceStrategy};
 use uv_git::GitResolver;
 use uv_toolchain::{Interpreter, PythonEnvironment};
 
@@ -70,6 +70,10 @@ pub trait BuildContext {
     /// The index locations being searched.
     fn index_locations(&self) -> &IndexLocations;
 
+    /// Whether to respect `tool.uv.sources` when reading the requirements of local and Git
+    /// projects.
+    fn sources(&self) -> SourceStrategy;
+
     /// Resolve the given requirements into a ready-to-install set of package versions.
     fn resolve<'a>(
         &'a self,
@@ -11,6 +11,7 @@ use distribution_types::InstalledMetadata;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
 pub(crate) use pip::freeze::pip_freeze;
+pub(crate) use pip::inspect::pip_inspect;
 pub(crate) use pip::install::pip_install;
 pub(crate) use pip::list::pip_list;
 pub(crate) use pip::show::pip_show;
@@ -55,6 +56,16 @@ mod self_update;
 mod venv;
 mod version;
 
+/// The exit status of a command.
+///
+/// uv uses a small set of exit codes, such that scripts can distinguish between the different
+/// classes of failure:
+///
+/// - `0`: The command succeeded.
+/// - `1`: The command failed (e.g., due to an invalid input or an unexpected error).
+/// - `2`: No solution could be found when resolving dependencies.
+/// - `3`: The command failed due to a network error.
+/// - `4`: The command-line arguments were invalid.
 #[derive(Copy, Clone)]
 pub(crate) enum ExitStatus {
     /// The command succeeded.
@@ -65,18 +76,59 @@ pub(crate) enum ExitStatus {
 
     /// The command failed with an unexpected error.
     Error,
+
+    /// The command failed because no solution could be found when resolving dependencies.
+    NoSolution,
+
+    /// The command failed due to a network error.
+    Network,
+
+    /// The command failed due to invalid command-line arguments.
+    Usage,
+}
+
+impl ExitStatus {
+    /// Determine the [`ExitStatus`] for an error that was propagated out of a command.
+    pub(crate) fn from_error(err: &anyhow::Error) -> Self {
+        if err.chain().any(is_network_error) {
+            Self::Network
+        } else {
+            Self::Error
+        }
+    }
 }
 
 impl From<ExitStatus> for ExitCode {
     fn from(status: ExitStatus) -> Self {
         match status {
             ExitStatus::Success => Self::from(0),
-            ExitStatus::Failure => Self::from(1),
-            ExitStatus::Error => Self::from(2),
+            ExitStatus::Failure | ExitStatus::Error => Self::from(1),
+            ExitStatus::NoSolution => Self::from(2),
+            ExitStatus::Network => Self::from(3),
+            ExitStatus::Usage => Self::from(4),
         }
     }
 }
 
+/// Returns `true` if the error was caused by a failed network request (or a request that could
+/// not be made, as network connectivity is disabled).
+fn is_network_error(err: &(dyn std::error::Error + 'static)) -> bool {
+    if let Some(err) = err.downcast_ref::<uv_client::Error>() {
+        return err.is_network_error();
+    }
+    match err.downcast_ref::<uv_distribution::Error>() {
+        Some(uv_distribution::Error::Client(err)) => return err.is_network_error(),
+        Some(uv_distribution::Error::Reqwest(_)) => return true,
+        _ => {}
+    }
+    if let Some(uv_resolver::ResolveError::Client(err)) =
+        err.downcast_ref::<uv_resolver::ResolveError>()
+    {
+        return err.is_network_error();
+    }
+    false
+}
+
 /// Format a duration as a human-readable string, Cargo-style.
 pub(super) fn elapsed(duration: Duration) -> String {
     let secs = duration.as_secs();
@@ -10,7 +10,9 @@ use uv_cache::Cache;
 use uv_configuration::PreviewMode;
 use uv_fs::Simplified;
 use uv_installer::{SitePackages, SitePackagesDiagnostic};
-use uv_toolchain::{EnvironmentPreference, PythonEnvironment, ToolchainRequest};
+use uv_toolchain::{
+    EnvironmentPreference, PythonEnvironment, ToolchainPreference, ToolchainRequest,
+};
 
 use crate::commands::{elapsed, ExitStatus};
 use crate::printer::Printer;
@@ -19,6 +21,7 @@ use crate::printer::Printer;
 pub(crate) fn pip_check(
     python: Option<&str>,
     system: bool,
+    toolchain_preference: ToolchainPreference,
     _preview: PreviewMode,
     cache: &Cache,
     printer: Printer,
@@ -26,9 +29,10 @@ pub(crate) fn pip_check(
     let start = Instant::now();
 
     // Detect the current Python interpreter.
-    let environment = PythonEnvironment::find(
+    let environment = PythonEnvironment::find_with_toolchain_preference(
         &python.map(ToolchainRequest::parse).unwrap_or_default(),
         EnvironmentPreference::from_system_flag(system, false),
+        toolchain_preference,
         cache,
     )?;
 
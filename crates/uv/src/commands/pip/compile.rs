@@ -1,25 +1,31 @@
+use std::borrow::Cow;
 use std::env;
+use std::fmt::Write;
 use std::io::stdout;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anstream::{eprint, AutoStream, StripStream};
 use anyhow::{anyhow, Result};
+use chrono::{SecondsFormat, Utc};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use sha2::{Digest, Sha256};
 use tracing::debug;
 
 use distribution_types::{IndexLocations, UnresolvedRequirementSpecification, Verbatim};
 use install_wheel_rs::linker::LinkMode;
-use pypi_types::Requirement;
+use pep440_rs::Operator;
+use pypi_types::{Requirement, RequirementSource};
 use uv_auth::store_credentials_from_url;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, ExtrasSpecification, IndexStrategy, NoBinary,
-    NoBuild, PreviewMode, Reinstall, SetupPyStrategy, Upgrade,
+    BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification, IndexStrategy,
+    NoBinary, NoBuild, PackageConfigSettings, PreviewMode, Reinstall, SetupPyStrategy, Upgrade,
 };
-use uv_configuration::{KeyringProviderType, TargetTriple};
+use uv_configuration::{KeyringProviderType, MarkerOverride, SourceStrategy, TargetTriple};
 use uv_dispatch::BuildDispatch;
+use uv_distribution::{Workspace, WorkspaceError};
 use uv_fs::Simplified;
 use uv_git::GitResolver;
 use uv_normalize::PackageName;
@@ -28,7 +34,8 @@ use uv_requirements::{
 };
 use uv_resolver::{
     AnnotationStyle, DependencyMode, DisplayResolutionGraph, ExcludeNewer, FlatIndex,
-    InMemoryIndex, OptionsBuilder, PreReleaseMode, PythonRequirement, ResolutionMode,
+    InMemoryIndex, LocalVersionPolicy, OptionsBuilder, PreReleaseMode, PythonRequirement,
+    ResolutionMode,
 };
 use uv_toolchain::{
     EnvironmentPreference, PythonEnvironment, PythonVersion, Toolchain, ToolchainPreference,
@@ -46,16 +53,26 @@ use crate::printer::Printer;
 pub(crate) async fn pip_compile(
     requirements: &[RequirementsSource],
     constraints: &[RequirementsSource],
+    build_constraints: &[RequirementsSource],
+    constraints_from_lock: &[RequirementsSource],
     overrides: &[RequirementsSource],
     overrides_from_workspace: Vec<Requirement>,
     extras: ExtrasSpecification,
     output_file: Option<&Path>,
+    verify: bool,
+    backup: bool,
+    check_input_hash: bool,
+    local_version_policy: LocalVersionPolicy,
+    strip_trailing_zeros: bool,
+    prune: Vec<PackageName>,
     resolution_mode: ResolutionMode,
     prerelease_mode: PreReleaseMode,
+    prerelease_packages: Vec<PackageName>,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     generate_hashes: bool,
     no_emit_packages: Vec<PackageName>,
+    unsafe_packages: Vec<PackageName>,
     include_extras: bool,
     include_markers: bool,
     include_annotations: bool,
@@ -66,16 +83,25 @@ pub(crate) async fn pip_compile(
     include_build_options: bool,
     include_marker_expression: bool,
     include_index_annotation: bool,
+    include_yanked_reason: bool,
+    include_build_isolation_note: bool,
+    include_input_hash: bool,
+    include_python_version: bool,
+    include_timestamp: bool,
+    include_workspace_members: bool,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     keyring_provider: KeyringProviderType,
     setup_py: SetupPyStrategy,
     config_settings: ConfigSettings,
+    config_settings_package: PackageConfigSettings,
     connectivity: Connectivity,
     no_build_isolation: bool,
+    sources: SourceStrategy,
     build_options: BuildOptions,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
+    marker_overrides: Vec<MarkerOverride>,
     universal: bool,
     exclude_newer: Option<ExcludeNewer>,
     annotation_style: AnnotationStyle,
@@ -85,6 +111,7 @@ pub(crate) async fn pip_compile(
     toolchain_preference: ToolchainPreference,
     concurrency: Concurrency,
     native_tls: bool,
+    retries: u32,
     quiet: bool,
     preview: PreviewMode,
     cache: Cache,
@@ -98,9 +125,19 @@ pub(crate) async fn pip_compile(
         ));
     }
 
+    // Stripping the local segment would pair each pin with the hashes of a local version (e.g.,
+    // `torch==2.0.0` with the hashes of `torch==2.0.0+cpu`), which may not be the version that's
+    // installed for that pin.
+    if generate_hashes && local_version_policy == LocalVersionPolicy::Strip {
+        return Err(anyhow!(
+            "`--local-version-policy strip` can't be used with `--generate-hashes`, since the hashes would belong to the local versions that were stripped"
+        ));
+    }
+
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .retries(retries)
         .keyring(keyring_provider);
 
     // Read all requirements from the provided sources.
@@ -125,6 +162,35 @@ pub(crate) async fn pip_compile(
     )
     .await?;
 
+    // Read the build constraints from the provided sources.
+    let build_constraints = Constraints::from_requirements(
+        RequirementsSpecification::from_sources(&[], build_constraints, &[], &client_builder)
+            .await?
+            .constraints,
+    );
+
+    // Read the pinned versions from any other lockfiles, and apply them as constraints.
+    let constraints = constraints
+        .into_iter()
+        .chain(
+            RequirementsSpecification::from_sources(
+                &[],
+                constraints_from_lock,
+                &[],
+                &client_builder,
+            )
+            .await?
+            .constraints
+            .into_iter()
+            .filter(|constraint| match &constraint.source {
+                RequirementSource::Registry { specifier, .. } => specifier
+                    .iter()
+                    .any(|specifier| *specifier.operator() == Operator::Equal),
+                _ => false,
+            }),
+        )
+        .collect::<Vec<_>>();
+
     let overrides: Vec<UnresolvedRequirementSpecification> = overrides
         .iter()
         .cloned()
@@ -202,10 +268,10 @@ pub(crate) async fn pip_compile(
     // Create a shared in-memory index.
     let source_index = InMemoryIndex::default();
 
-    // If we're resolving against a different Python version, use a separate index. Source
-    // distributions will be built against the installed version, and so the index may contain
-    // different package priorities than in the top-level resolution.
-    let top_level_index = if python_version.is_some() {
+    // If we're resolving against a different Python version (or marker environment), use a
+    // separate index. Source distributions will be built against the installed version, and so
+    // the index may contain different package priorities than in the top-level resolution.
+    let top_level_index = if python_version.is_some() || !marker_overrides.is_empty() {
         InMemoryIndex::default()
     } else {
         source_index.clone()
@@ -223,7 +289,21 @@ pub(crate) async fn pip_compile(
         (None, None)
     } else {
         let (tags, markers) =
-            resolution_environment(python_version, python_platform, &interpreter)?;
+            resolution_environment(python_version.clone(), python_platform, &interpreter)?;
+
+        // Apply any user-provided overrides to the marker environment.
+        let markers = if marker_overrides.is_empty() {
+            markers
+        } else {
+            Cow::Owned(
+                marker_overrides
+                    .iter()
+                    .fold(markers.into_owned(), |markers, marker| {
+                        marker.apply(markers)
+                    }),
+            )
+        };
+
         (Some(tags), Some(markers))
     };
 
@@ -241,6 +321,89 @@ pub(crate) async fn pip_compile(
     let index_locations =
         index_locations.combine(index_url, extra_index_urls, find_links, no_index);
 
+    // If requested, discover the enclosing workspace, if any, and record the source trees provided
+    // as inputs, to determine the workspace members that contributed requirements.
+    let workspace = if include_workspace_members {
+        match Workspace::discover(&env::current_dir()?, None).await {
+            Ok(workspace) => Some(workspace),
+            Err(WorkspaceError::MissingPyprojectToml | WorkspaceError::MissingProject(_)) => None,
+            Err(err) => return Err(err.into()),
+        }
+    } else {
+        None
+    };
+    let input_source_trees = if workspace.is_some() {
+        source_trees
+            .iter()
+            .filter_map(|path| fs_err::canonicalize(path).ok())
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+
+    // Hash the inputs to the resolution, along with any settings that affect the resolution or
+    // the contents of the output file.
+    let input_hash = input_hash(
+        &requirements,
+        &constraints,
+        &overrides,
+        &source_trees,
+        &index_locations,
+        &[
+            format!("extras: {extras:?}"),
+            format!("python-version: {python_version:?}"),
+            format!("python-platform: {python_platform:?}"),
+            format!("marker: {marker_overrides:?}"),
+            format!("universal: {universal}"),
+            format!("resolution: {resolution_mode:?}"),
+            format!("prerelease: {prerelease_mode:?}"),
+            format!("prerelease-package: {prerelease_packages:?}"),
+            format!("dependency-mode: {dependency_mode:?}"),
+            format!("exclude-newer: {exclude_newer:?}"),
+            format!("index-strategy: {index_strategy:?}"),
+            format!("build-options: {build_options:?}"),
+            format!("sources: {sources:?}"),
+            format!("local-version-policy: {local_version_policy:?}"),
+            format!("strip-trailing-zeros: {strip_trailing_zeros}"),
+            format!("prune: {prune:?}"),
+            format!("no-emit-package: {no_emit_packages:?}"),
+            format!("unsafe-package: {unsafe_packages:?}"),
+            format!("generate-hashes: {generate_hashes}"),
+            format!("annotation-style: {annotation_style:?}"),
+            format!("custom-compile-command: {custom_compile_command:?}"),
+            format!("emit-extras: {include_extras}"),
+            format!("emit-markers: {include_markers}"),
+            format!("emit-annotations: {include_annotations}"),
+            format!("emit-header: {include_header}"),
+            format!("emit-index-url: {include_index_url}"),
+            format!("emit-find-links: {include_find_links}"),
+            format!("emit-build-options: {include_build_options}"),
+            format!("emit-marker-expression: {include_marker_expression}"),
+            format!("emit-index-annotation: {include_index_annotation}"),
+            format!("emit-yanked-reason: {include_yanked_reason}"),
+            format!("emit-build-isolation-note: {include_build_isolation_note}"),
+            format!("emit-python-version: {include_python_version}"),
+            format!("emit-timestamp: {include_timestamp}"),
+            format!("emit-workspace-members: {include_workspace_members}"),
+        ],
+    )?;
+
+    // If requested, validate the input hash recorded in the output file, rather than resolving.
+    if check_input_hash {
+        if let Some(output_file) = output_file {
+            return check_output_input_hash(output_file, &input_hash, printer);
+        }
+    }
+
+    // Selecting the best version across indexes exposes the resolution to dependency confusion.
+    if index_strategy == IndexStrategy::UnsafeBestMatch
+        && index_locations.indexes().nth(1).is_some()
+    {
+        warn_user!(
+            "`--index-strategy unsafe-best-match` selects the best version of each package across all indexes. A package published to any index with a matching name may take precedence over the version from your intended index, exposing the resolution to dependency confusion attacks."
+        );
+    }
+
     // Add all authenticated sources to the cache.
     for url in index_locations.urls() {
         store_credentials_from_url(url);
@@ -249,6 +412,7 @@ pub(crate) async fn pip_compile(
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
+        .retries(retries)
         .connectivity(connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
@@ -301,7 +465,10 @@ pub(crate) async fn pip_compile(
         exclude_newer,
         concurrency,
         preview,
-    );
+    )
+    .with_sources(sources)
+    .with_package_config_settings(config_settings_package)
+    .with_build_constraints(build_constraints);
 
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
@@ -309,6 +476,7 @@ pub(crate) async fn pip_compile(
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
         .index_strategy(index_strategy)
+        .local_version_policy(local_version_policy)
         .build();
 
     // Resolve the requirements.
@@ -325,6 +493,7 @@ pub(crate) async fn pip_compile(
         &hasher,
         &Reinstall::None,
         &upgrade,
+        &prerelease_packages,
         tags.as_deref(),
         markers.as_deref(),
         python_requirement,
@@ -344,13 +513,14 @@ pub(crate) async fn pip_compile(
             let report = miette::Report::msg(format!("{err}"))
                 .context("No solution found when resolving dependencies:");
             eprint!("{report:?}");
-            return Ok(ExitStatus::Failure);
+            return Ok(ExitStatus::NoSolution);
         }
         Err(err) => return Err(err.into()),
     };
 
-    // Write the resolved dependencies to the output channel.
-    let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file)?;
+    // Write the resolved dependencies to the output channel. When verifying, the output is only
+    // compared against the existing output file.
+    let mut writer = OutputWriter::new((!quiet && !verify) || output_file.is_none(), output_file);
 
     if include_header {
         writeln!(
@@ -371,6 +541,53 @@ pub(crate) async fn pip_compile(
             )
             .green()
         )?;
+        if include_input_hash {
+            writeln!(writer, "{}", format!("# input-hash: {input_hash}").green())?;
+        }
+        if include_python_version {
+            let version = if let Some(python_version) = python_version.as_ref() {
+                python_version.to_string()
+            } else {
+                format!(
+                    "{}.{}",
+                    interpreter.python_major(),
+                    interpreter.python_minor()
+                )
+            };
+            writeln!(writer, "{}", format!("# python-version: {version}").green())?;
+        }
+        if include_timestamp {
+            let timestamp = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+            writeln!(writer, "{}", format!("# generated-at: {timestamp}").green())?;
+        }
+        if let Some(workspace) = workspace.as_ref() {
+            let members = workspace
+                .packages()
+                .iter()
+                .filter(|(name, member)| {
+                    resolution.contains(name)
+                        || fs_err::canonicalize(member.root().join("pyproject.toml"))
+                            .is_ok_and(|path| input_source_trees.contains(&path))
+                })
+                .collect::<Vec<_>>();
+            if !members.is_empty() {
+                writeln!(writer, "{}", "# workspace-members:".green())?;
+                for (name, member) in members {
+                    // Display each member relative to the workspace root.
+                    let path = member
+                        .root()
+                        .strip_prefix(workspace.root())
+                        .ok()
+                        .filter(|path| !path.as_os_str().is_empty())
+                        .unwrap_or(Path::new("."));
+                    writeln!(
+                        writer,
+                        "{}",
+                        format!("#    {name} ({})", path.portable_display()).green()
+                    )?;
+                }
+            }
+        }
     }
 
     if include_marker_expression {
@@ -382,13 +599,34 @@ pub(crate) async fn pip_compile(
                 "# Pinned dependencies known to be valid for:".green()
             )?;
             writeln!(writer, "{}", format!("#    {relevant_markers}").green())?;
+        } else if let Some(python_version) = python_version.as_ref() {
+            // In universal mode, the resolution is only constrained by the minimum Python version.
+            let relevant_markers = if python_version.patch().is_some() {
+                format!(
+                    "python_full_version >= '{}'",
+                    python_version.python_full_version()
+                )
+            } else {
+                format!("python_version >= '{}'", python_version.python_version())
+            };
+            writeln!(
+                writer,
+                "{}",
+                "# Pinned dependencies known to be valid for:".green()
+            )?;
+            writeln!(writer, "{}", format!("#    {relevant_markers}").green())?;
         }
     }
 
     let mut wrote_preamble = false;
 
-    // If necessary, include the `--index-url` and `--extra-index-url` locations.
+    // If necessary, include the `--index-url` and `--extra-index-url` locations (or
+    // `--no-index`, if the indexes were disabled).
     if include_index_url {
+        if index_locations.no_index() {
+            writeln!(writer, "--no-index")?;
+            wrote_preamble = true;
+        }
         if let Some(index) = index_locations.index() {
             writeln!(writer, "--index-url {}", index.verbatim())?;
             wrote_preamble = true;
@@ -442,23 +680,40 @@ pub(crate) async fn pip_compile(
         writeln!(writer)?;
     }
 
+    // Omit any "unsafe" packages from the output, in addition to those that were explicitly
+    // excluded.
+    let unsafe_packages = unsafe_packages
+        .into_iter()
+        .filter(|name| !no_emit_packages.contains(name))
+        .collect::<Vec<_>>();
+    let omitted_packages = no_emit_packages
+        .iter()
+        .chain(&unsafe_packages)
+        .cloned()
+        .collect::<Vec<_>>();
+
     write!(
         writer,
         "{}",
         DisplayResolutionGraph::new(
             &resolution,
             markers.as_deref(),
-            &no_emit_packages,
+            &omitted_packages,
+            &prune,
             generate_hashes,
             include_extras,
             include_markers || universal,
             include_annotations,
             include_index_annotation,
+            include_yanked_reason,
+            include_build_isolation_note,
+            local_version_policy != LocalVersionPolicy::Strip,
+            strip_trailing_zeros,
             annotation_style,
         )
     )?;
 
-    // If any "unsafe" packages were excluded, notify the user.
+    // If any packages were excluded, notify the user.
     let excluded = no_emit_packages
         .into_iter()
         .filter(|name| resolution.contains(name))
@@ -475,12 +730,120 @@ pub(crate) async fn pip_compile(
         }
     }
 
+    // If any "unsafe" packages were omitted, notify the user.
+    let unsafe_packages = unsafe_packages
+        .into_iter()
+        .filter(|name| resolution.contains(name))
+        .collect::<Vec<_>>();
+    if !unsafe_packages.is_empty() {
+        writeln!(writer)?;
+        writeln!(
+            writer,
+            "{}",
+            "# The following packages are considered to be unsafe in a requirements file:".green()
+        )?;
+        for package in unsafe_packages {
+            writeln!(writer, "# {package}")?;
+        }
+    }
+
+    // If requested, verify that the output file is up-to-date, rather than overwriting it.
+    if verify {
+        if let Some(output_file) = output_file {
+            let existing = match fs_err::read_to_string(output_file) {
+                Ok(existing) => existing,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+                Err(err) => return Err(err.into()),
+            };
+            let contents = writer.into_contents();
+
+            // Ignore the timestamp, which differs on every run.
+            let existing = without_timestamp(&existing);
+            let contents = without_timestamp(&contents);
+            if existing != contents {
+                writeln!(
+                    printer.stderr(),
+                    "{}{} The output file at `{}` is not up-to-date with the input requirements:",
+                    "error".red().bold(),
+                    ":".bold(),
+                    output_file.user_display().cyan()
+                )?;
+                for (change, line) in diff_lines(&existing, &contents) {
+                    match change {
+                        Change::Removed => {
+                            writeln!(printer.stderr(), "{}", format!("-{line}").red())?;
+                        }
+                        Change::Added => {
+                            writeln!(printer.stderr(), "{}", format!("+{line}").green())?;
+                        }
+                    }
+                }
+                return Ok(ExitStatus::Failure);
+            }
+        }
+    } else {
+        writer.commit(backup)?;
+    }
+
     // Notify the user of any resolution diagnostics.
     operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
     Ok(ExitStatus::Success)
 }
 
+/// Remove the `# generated-at: ...` header line (as written by `--emit-timestamp`) from the given
+/// output file contents.
+fn without_timestamp(contents: &str) -> String {
+    contents
+        .split_inclusive('\n')
+        .filter(|line| !line.starts_with("# generated-at: "))
+        .collect()
+}
+
+/// A change to a line, as computed by [`diff_lines`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Change {
+    Removed,
+    Added,
+}
+
+/// Compute the lines that were removed from `old` and added in `new`, based on the longest common
+/// subsequence of the two inputs.
+fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<(Change, &'a str)> {
+    let old = old.lines().collect::<Vec<_>>();
+    let new = new.lines().collect::<Vec<_>>();
+
+    // Compute the length of the longest common subsequence for every pair of suffixes.
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut changes = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            changes.push((Change::Removed, old[i]));
+            i += 1;
+        } else {
+            changes.push((Change::Added, new[j]));
+            j += 1;
+        }
+    }
+    changes.extend(old[i..].iter().map(|line| (Change::Removed, *line)));
+    changes.extend(new[j..].iter().map(|line| (Change::Added, *line)));
+    changes
+}
+
 /// Format the `uv` command used to generate the output file.
 #[allow(clippy::fn_params_excessive_bools)]
 fn cmd(
@@ -531,6 +894,12 @@ fn cmd(
                 }
             }
 
+            // Always skip the `--verify`, `--backup`, and `--check-input-hash` flags.
+            if arg == "--verify" || arg == "--backup" || arg == "--check-input-hash" {
+                *skip_next = None;
+                return Some(None);
+            }
+
             // Always skip the `--upgrade` flag.
             if arg == "--upgrade" || arg == "-U" {
                 *skip_next = None;
@@ -557,36 +926,121 @@ fn cmd(
     format!("uv {args}")
 }
 
+/// Compute a hash of the inputs to the resolution, in the form `sha256:<digest>`.
+///
+/// The hash covers the requirements, constraints, overrides, and source trees (in the order in which
+/// they were provided), along with the index configuration and the given settings, each of which
+/// should be rendered as a `key: value` line. Source trees are hashed by their contents, such that
+/// editing (e.g.) the dependencies in a `pyproject.toml` invalidates the hash.
+///
+/// Settings that don't affect the resolution or the contents of the output file (like
+/// `--emit-input-hash` itself) must be omitted, such that the hash can be validated with
+/// `--check-input-hash` alone.
+fn input_hash(
+    requirements: &[UnresolvedRequirementSpecification],
+    constraints: &[Requirement],
+    overrides: &[UnresolvedRequirementSpecification],
+    source_trees: &[PathBuf],
+    index_locations: &IndexLocations,
+    settings: &[String],
+) -> Result<String> {
+    let mut hasher = Sha256::new();
+    for requirement in requirements {
+        hasher.update(format!("requirement: {}\n", requirement.requirement));
+    }
+    for constraint in constraints {
+        hasher.update(format!("constraint: {constraint}\n"));
+    }
+    for r#override in overrides {
+        hasher.update(format!("override: {}\n", r#override.requirement));
+    }
+    for source_tree in source_trees {
+        hasher.update(format!("source-tree: {}\n", source_tree.portable_display()));
+        hasher.update(fs_err::read(source_tree)?);
+        hasher.update("\n");
+    }
+    for index in index_locations.indexes() {
+        hasher.update(format!("index-url: {index}\n"));
+    }
+    for flat_index in index_locations.flat_index() {
+        hasher.update(format!("find-links: {flat_index}\n"));
+    }
+    if index_locations.no_index() {
+        hasher.update("no-index\n");
+    }
+    for setting in settings {
+        hasher.update(format!("{setting}\n"));
+    }
+    Ok(format!("sha256:{:x}", hasher.finalize()))
+}
+
+/// Validate that the input hash recorded in the given output file matches the current inputs.
+fn check_output_input_hash(
+    output_file: &Path,
+    input_hash: &str,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let existing = match fs_err::read_to_string(output_file) {
+        Ok(existing) => existing,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err.into()),
+    };
+    let recorded = existing
+        .lines()
+        .find_map(|line| line.strip_prefix("# input-hash: "))
+        .map(str::trim);
+
+    match recorded {
+        Some(recorded) if recorded == input_hash => Ok(ExitStatus::Success),
+        Some(_) => {
+            writeln!(
+                printer.stderr(),
+                "{}{} The input requirements have changed since `{}` was generated",
+                "error".red().bold(),
+                ":".bold(),
+                output_file.user_display().cyan()
+            )?;
+            Ok(ExitStatus::Failure)
+        }
+        None => {
+            writeln!(
+                printer.stderr(),
+                "{}{} The output file at `{}` does not include an input hash (generate it with `--emit-input-hash`)",
+                "error".red().bold(),
+                ":".bold(),
+                output_file.user_display().cyan()
+            )?;
+            Ok(ExitStatus::Failure)
+        }
+    }
+}
+
 /// A multi-casting writer that writes to both the standard output and an output file, if present.
-#[allow(clippy::disallowed_types)]
-struct OutputWriter {
+///
+/// The output file contents are buffered, and only written to disk on [`OutputWriter::commit`].
+struct OutputWriter<'a> {
     stdout: Option<AutoStream<std::io::Stdout>>,
-    output_file: Option<StripStream<std::fs::File>>,
+    output_file: Option<&'a Path>,
+    buffer: StripStream<Vec<u8>>,
 }
 
-#[allow(clippy::disallowed_types)]
-impl OutputWriter {
+impl<'a> OutputWriter<'a> {
     /// Create a new output writer.
-    fn new(include_stdout: bool, output_file: Option<&Path>) -> Result<Self> {
+    fn new(include_stdout: bool, output_file: Option<&'a Path>) -> Self {
         let stdout = include_stdout.then(|| AutoStream::<std::io::Stdout>::auto(stdout()));
-        let output_file = output_file
-            .map(|output_file| -> Result<_, std::io::Error> {
-                let output_file = fs_err::File::create(output_file)?;
-                Ok(StripStream::new(output_file.into()))
-            })
-            .transpose()?;
-        Ok(Self {
+        Self {
             stdout,
             output_file,
-        })
+            buffer: StripStream::new(Vec::new()),
+        }
     }
 
     /// Write the given arguments to both the standard output and the output file, if present.
     fn write_fmt(&mut self, args: std::fmt::Arguments<'_>) -> std::io::Result<()> {
         use std::io::Write;
 
-        if let Some(output_file) = &mut self.output_file {
-            write!(output_file, "{args}")?;
+        if self.output_file.is_some() {
+            write!(self.buffer, "{args}")?;
         }
 
         if let Some(stdout) = &mut self.stdout {
@@ -595,4 +1049,40 @@ impl OutputWriter {
 
         Ok(())
     }
+
+    /// Return the (uncolored) contents destined for the output file.
+    fn into_contents(self) -> String {
+        String::from_utf8_lossy(&self.buffer.into_inner()).into_owned()
+    }
+
+    /// Write the buffered contents to the output file, if present.
+    ///
+    /// An existing output file is replaced atomically, such that an interrupted write never leaves
+    /// a partially-written file behind. If `backup` is set, the existing output file is first
+    /// copied to `<output-file>.bak`.
+    fn commit(self, backup: bool) -> std::io::Result<()> {
+        let Some(output_file) = self.output_file else {
+            return Ok(());
+        };
+
+        match fs_err::metadata(output_file) {
+            Ok(metadata) => {
+                if backup {
+                    let mut backup_file = output_file.as_os_str().to_owned();
+                    backup_file.push(".bak");
+                    fs_err::copy(output_file, backup_file)?;
+                }
+
+                // Retain the permissions of the existing file, since the temporary file is
+                // created with restrictive permissions.
+                uv_fs::write_atomic_sync(output_file, self.buffer.into_inner())?;
+                fs_err::set_permissions(output_file, metadata.permissions())?;
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                fs_err::write(output_file, self.buffer.into_inner())?;
+            }
+            Err(err) => return Err(err),
+        }
+        Ok(())
+    }
 }
@@ -10,7 +10,9 @@ use uv_cache::Cache;
 use uv_configuration::PreviewMode;
 use uv_fs::Simplified;
 use uv_installer::SitePackages;
-use uv_toolchain::{EnvironmentPreference, PythonEnvironment, ToolchainRequest};
+use uv_toolchain::{
+    EnvironmentPreference, PythonEnvironment, ToolchainPreference, ToolchainRequest,
+};
 
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
@@ -21,14 +23,16 @@ pub(crate) fn pip_freeze(
     strict: bool,
     python: Option<&str>,
     system: bool,
+    toolchain_preference: ToolchainPreference,
     _preview: PreviewMode,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
     // Detect the current Python interpreter.
-    let environment = PythonEnvironment::find(
+    let environment = PythonEnvironment::find_with_toolchain_preference(
         &python.map(ToolchainRequest::parse).unwrap_or_default(),
         EnvironmentPreference::from_system_flag(system, false),
+        toolchain_preference,
         cache,
     )?;
 
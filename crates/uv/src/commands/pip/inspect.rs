@@ -0,0 +1,152 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use tracing::debug;
+
+use distribution_types::{InstalledDist, Name};
+use pep508_rs::MarkerEnvironment;
+use pypi_types::DirectUrl;
+use uv_cache::Cache;
+use uv_configuration::PreviewMode;
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_toolchain::{
+    EnvironmentPreference, PythonEnvironment, ToolchainPreference, ToolchainRequest,
+};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Emit a JSON report describing the current environment and its installed packages.
+pub(crate) fn pip_inspect(
+    python: Option<&str>,
+    system: bool,
+    toolchain_preference: ToolchainPreference,
+    _preview: PreviewMode,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Detect the current Python interpreter.
+    let environment = PythonEnvironment::find_with_toolchain_preference(
+        &python.map(ToolchainRequest::parse).unwrap_or_default(),
+        EnvironmentPreference::from_system_flag(system, false),
+        toolchain_preference,
+        cache,
+    )?;
+
+    debug!(
+        "Using Python {} environment at {}",
+        environment.interpreter().python_version(),
+        environment.python_executable().user_display().cyan()
+    );
+
+    // Build the installed index.
+    let site_packages = SitePackages::from_environment(&environment)?;
+
+    // Always sort by name, for deterministic output.
+    let installed = site_packages
+        .iter()
+        .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
+        .map(InstalledEntry::try_from)
+        .collect::<Result<Vec<_>>>()?;
+
+    let report = InspectReport {
+        version: "1",
+        uv_version: env!("CARGO_PKG_VERSION"),
+        installed,
+        environment: environment.interpreter().markers(),
+    };
+
+    writeln!(
+        printer.stdout(),
+        "{}",
+        serde_json::to_string_pretty(&report)?
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// The top-level inspection report, modeled after the output of `pip inspect`.
+///
+/// See: <https://pip.pypa.io/en/stable/reference/inspect-report/>
+#[derive(Debug, Serialize)]
+struct InspectReport<'env> {
+    version: &'static str,
+    uv_version: &'static str,
+    installed: Vec<InstalledEntry>,
+    environment: &'env MarkerEnvironment,
+}
+
+/// An installed distribution in an [`InspectReport`].
+#[derive(Debug, Serialize)]
+struct InstalledEntry {
+    metadata: MetadataEntry,
+    metadata_location: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direct_url: Option<DirectUrl>,
+    requested: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    installer: Option<String>,
+}
+
+/// The JSON-compatible core metadata of an installed distribution.
+#[derive(Debug, Serialize)]
+struct MetadataEntry {
+    name: String,
+    version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    license: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requires_python: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    requires_dist: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    provides_extra: Vec<String>,
+}
+
+impl TryFrom<&InstalledDist> for InstalledEntry {
+    type Error = anyhow::Error;
+
+    fn try_from(dist: &InstalledDist) -> Result<Self> {
+        let metadata = dist.metadata()?;
+        let descriptive = dist.descriptive_metadata()?;
+
+        let direct_url = match dist {
+            InstalledDist::Url(_) => InstalledDist::direct_url(dist.path())?,
+            _ => None,
+        };
+
+        Ok(Self {
+            metadata: MetadataEntry {
+                name: metadata.name.to_string(),
+                version: metadata.version.to_string(),
+                summary: descriptive.summary,
+                license: descriptive.license,
+                requires_python: metadata
+                    .requires_python
+                    .map(|requires_python| requires_python.to_string()),
+                requires_dist: metadata
+                    .requires_dist
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+                provides_extra: metadata
+                    .provides_extras
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+            },
+            metadata_location: dist.path().simplified_display().to_string(),
+            direct_url,
+            requested: dist.path().join("REQUESTED").is_file(),
+            installer: dist
+                .installer()?
+                .map(|installer| installer.trim().to_string()),
+        })
+    }
+}
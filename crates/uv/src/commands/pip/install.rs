@@ -3,35 +3,43 @@ use std::fmt::Write;
 use anstream::eprint;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, enabled, Level};
 
-use distribution_types::{IndexLocations, Resolution, UnresolvedRequirementSpecification};
+use distribution_types::{
+    IndexLocations, Resolution, UnresolvedRequirement, UnresolvedRequirementSpecification,
+};
 use install_wheel_rs::linker::LinkMode;
-use pypi_types::Requirement;
+use pep440_rs::{VersionSpecifier, VersionSpecifiers};
+use pypi_types::{Requirement, RequirementSource};
 use uv_auth::store_credentials_from_url;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, ExtrasSpecification, IndexStrategy, PreviewMode,
-    Reinstall, SetupPyStrategy, Upgrade,
+    Reinstall, SetupPyStrategy, Upgrade, UpgradeStrategy,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::BuildDispatch;
+use uv_distribution::DistributionDatabase;
 use uv_fs::Simplified;
 use uv_git::GitResolver;
 use uv_installer::{SatisfiesResult, SitePackages};
-use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_normalize::PackageName;
+use uv_requirements::{NamedRequirementsResolver, RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
     DependencyMode, ExcludeNewer, FlatIndex, InMemoryIndex, OptionsBuilder, PreReleaseMode,
     PythonRequirement, ResolutionMode,
 };
 use uv_toolchain::{
-    EnvironmentPreference, Prefix, PythonEnvironment, PythonVersion, Target, ToolchainRequest,
+    EnvironmentPreference, Prefix, PythonEnvironment, PythonVersion, Target, ToolchainPreference,
+    ToolchainRequest,
 };
 use uv_types::{BuildIsolation, HashStrategy, InFlight};
 
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::{operations, resolution_environment};
+use crate::commands::reporters::ResolverReporter;
 use crate::commands::{elapsed, ExitStatus};
 use crate::printer::Printer;
 
@@ -45,8 +53,10 @@ pub(crate) async fn pip_install(
     extras: &ExtrasSpecification,
     resolution_mode: ResolutionMode,
     prerelease_mode: PreReleaseMode,
+    prerelease_packages: Vec<PackageName>,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
+    upgrade_strategy: UpgradeStrategy,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     keyring_provider: KeyringProviderType,
@@ -65,14 +75,25 @@ pub(crate) async fn pip_install(
     exclude_newer: Option<ExcludeNewer>,
     python: Option<String>,
     system: bool,
+    toolchain_preference: ToolchainPreference,
     break_system_packages: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
     concurrency: Concurrency,
     native_tls: bool,
+    retries: u32,
     preview: PreviewMode,
     cache: Cache,
     dry_run: bool,
+    system_site_packages: bool,
+    no_build_isolation_package: Vec<PackageName>,
+    verify_hashes: bool,
+    installer_name: &str,
+    no_warn_conflicts: bool,
+    no_rollback: bool,
+    only: Vec<PackageName>,
+    prefer_find_links: bool,
+    exclude_newer_package: FxHashMap<PackageName, ExcludeNewer>,
     printer: Printer,
 ) -> anyhow::Result<ExitStatus> {
     let start = std::time::Instant::now();
@@ -80,6 +101,7 @@ pub(crate) async fn pip_install(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .retries(retries)
         .keyring(keyring_provider);
 
     // Read all requirements from the provided sources.
@@ -116,12 +138,13 @@ pub(crate) async fn pip_install(
         .collect();
 
     // Detect the current Python interpreter.
-    let environment = PythonEnvironment::find(
+    let environment = PythonEnvironment::find_with_toolchain_preference(
         &python
             .as_deref()
             .map(ToolchainRequest::parse)
             .unwrap_or_default(),
         EnvironmentPreference::from_system_flag(system, true),
+        toolchain_preference,
         &cache,
     )?;
 
@@ -175,6 +198,34 @@ pub(crate) async fn pip_install(
     // Determine the set of installed packages.
     let site_packages = SitePackages::from_environment(&environment)?;
 
+    // If requested, reinstall the given packages at their installed versions, without resolving or
+    // modifying their dependencies.
+    let (requirements, reinstall, dependency_mode) = if only.is_empty() {
+        (requirements, reinstall, dependency_mode)
+    } else {
+        let mut pinned = Vec::with_capacity(only.len());
+        for name in &only {
+            let Some(installed) = site_packages.get_packages(name).into_iter().next() else {
+                return Err(anyhow::anyhow!(
+                    "Package `{name}` is not installed; `--only` can only reinstall installed packages"
+                ));
+            };
+            pinned.push(UnresolvedRequirementSpecification::from(Requirement {
+                name: name.clone(),
+                extras: vec![],
+                marker: None,
+                source: RequirementSource::Registry {
+                    specifier: VersionSpecifiers::from(VersionSpecifier::equals_version(
+                        installed.version().clone(),
+                    )),
+                    index: None,
+                },
+                origin: None,
+            }));
+        }
+        (pinned, Reinstall::Packages(only), DependencyMode::Direct)
+    };
+
     // Check if the current environment satisfies the requirements.
     // Ideally, the resolver would be fast enough to let us remove this check. But right now, for large environments,
     // it's an order of magnitude faster to validate the environment than to resolve the requirements.
@@ -237,6 +288,30 @@ pub(crate) async fn pip_install(
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
             Some(&markers),
         )?
+    } else if verify_hashes {
+        // If some requirements include hashes, warn about any that don't, since they'll be
+        // installed without validation.
+        if requirements.iter().any(|entry| !entry.hashes.is_empty()) {
+            let unhashed = requirements
+                .iter()
+                .filter(|entry| entry.hashes.is_empty())
+                .map(|entry| entry.requirement.to_string())
+                .collect::<Vec<_>>();
+            if !unhashed.is_empty() {
+                warn_user!(
+                    "Some requirements are missing hashes, and will be installed without validation: {}",
+                    unhashed.join(", ")
+                );
+            }
+        }
+
+        HashStrategy::verify_from_requirements(
+            requirements
+                .iter()
+                .chain(overrides.iter())
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            Some(&markers),
+        )?
     } else {
         HashStrategy::None
     };
@@ -260,6 +335,7 @@ pub(crate) async fn pip_install(
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
+        .retries(retries)
         .connectivity(connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
@@ -281,6 +357,10 @@ pub(crate) async fn pip_install(
     // Determine whether to enable build isolation.
     let build_isolation = if no_build_isolation {
         BuildIsolation::Shared(&environment)
+    } else if !no_build_isolation_package.is_empty() {
+        BuildIsolation::SharedPackage(&environment, &no_build_isolation_package)
+    } else if system_site_packages {
+        BuildIsolation::Inherited(&environment)
     } else {
         BuildIsolation::Isolated
     };
@@ -317,9 +397,55 @@ pub(crate) async fn pip_install(
         .prerelease_mode(prerelease_mode)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
+        .exclude_newer_package(exclude_newer_package)
         .index_strategy(index_strategy)
+        .prefer_find_links(prefer_find_links)
         .build();
 
+    // With the `only-if-needed` upgrade strategy, only upgrade the explicitly requested packages,
+    // preferring the installed versions of their dependencies. Any unnamed requirements (e.g., URLs
+    // without an `#egg=` fragment) are converted to named requirements first, so that they're
+    // included in the set of upgraded packages.
+    let (requirements, upgrade) = match (upgrade, upgrade_strategy) {
+        (Upgrade::All, UpgradeStrategy::OnlyIfNeeded) => {
+            let requirements = NamedRequirementsResolver::new(
+                requirements,
+                &hasher,
+                &index,
+                DistributionDatabase::new(
+                    &client,
+                    &resolve_dispatch,
+                    concurrency.requests,
+                    preview,
+                ),
+            )
+            .with_reporter(ResolverReporter::from(printer))
+            .resolve()
+            .await?;
+            let upgrade = Upgrade::Packages(
+                requirements
+                    .iter()
+                    .map(|requirement| requirement.name.clone())
+                    .collect(),
+            );
+            let requirements = requirements
+                .into_iter()
+                .map(UnresolvedRequirementSpecification::from)
+                .collect::<Vec<_>>();
+            (requirements, upgrade)
+        }
+        (upgrade, _) => (requirements, upgrade),
+    };
+
+    // Track the overridden packages, to attribute any resulting inconsistencies to the override.
+    let overridden = overrides
+        .iter()
+        .filter_map(|spec| match &spec.requirement {
+            UnresolvedRequirement::Named(requirement) => Some(requirement.name.clone()),
+            UnresolvedRequirement::Unnamed(_) => None,
+        })
+        .collect::<FxHashSet<_>>();
+
     // Resolve the requirements.
     let resolution = match operations::resolve(
         requirements,
@@ -334,6 +460,7 @@ pub(crate) async fn pip_install(
         &hasher,
         &reinstall,
         &upgrade,
+        &prerelease_packages,
         Some(&tags),
         Some(&markers),
         python_requirement,
@@ -353,7 +480,7 @@ pub(crate) async fn pip_install(
             let report = miette::Report::msg(format!("{err}"))
                 .context("No solution found when resolving dependencies:");
             eprint!("{report:?}");
-            return Ok(ExitStatus::Failure);
+            return Ok(ExitStatus::NoSolution);
         }
         Err(err) => return Err(err.into()),
     };
@@ -396,6 +523,7 @@ pub(crate) async fn pip_install(
         &build_options,
         link_mode,
         compile,
+        installer_name,
         &index_locations,
         &hasher,
         &tags,
@@ -406,6 +534,7 @@ pub(crate) async fn pip_install(
         &cache,
         &environment,
         dry_run,
+        !no_rollback,
         printer,
         preview,
     )
@@ -416,7 +545,14 @@ pub(crate) async fn pip_install(
 
     // Notify the user of any environment diagnostics.
     if strict && !dry_run {
-        operations::diagnose_environment(&resolution, &environment, printer)?;
+        operations::diagnose_environment(
+            &resolution,
+            &environment,
+            dependency_mode,
+            &overridden,
+            !no_warn_conflicts,
+            printer,
+        )?;
     }
 
     Ok(ExitStatus::Success)
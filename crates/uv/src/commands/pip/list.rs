@@ -15,6 +15,7 @@ use uv_configuration::PreviewMode;
 use uv_fs::Simplified;
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
+use uv_toolchain::ToolchainPreference;
 use uv_toolchain::ToolchainRequest;
 use uv_toolchain::{EnvironmentPreference, PythonEnvironment};
 
@@ -31,14 +32,16 @@ pub(crate) fn pip_list(
     strict: bool,
     python: Option<&str>,
     system: bool,
+    toolchain_preference: ToolchainPreference,
     _preview: PreviewMode,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
     // Detect the current Python interpreter.
-    let environment = PythonEnvironment::find(
+    let environment = PythonEnvironment::find_with_toolchain_preference(
         &python.map(ToolchainRequest::parse).unwrap_or_default(),
         EnvironmentPreference::from_system_flag(system, false),
+        toolchain_preference,
         cache,
     )?;
 
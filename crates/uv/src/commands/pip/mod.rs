@@ -8,6 +8,7 @@ use uv_toolchain::{Interpreter, PythonVersion};
 pub(crate) mod check;
 pub(crate) mod compile;
 pub(crate) mod freeze;
+pub(crate) mod inspect;
 pub(crate) mod install;
 pub(crate) mod list;
 pub(crate) mod operations;
@@ -6,6 +6,7 @@ use std::path::PathBuf;
 use anyhow::{anyhow, Context};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use tracing::debug;
 
 use distribution_types::{
@@ -27,7 +28,7 @@ use uv_configuration::{
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
 use uv_fs::Simplified;
-use uv_installer::{Plan, Planner, Preparer, SitePackages};
+use uv_installer::{Plan, Planner, Preparer, Rollback, SitePackages, SitePackagesDiagnostic};
 use uv_normalize::{GroupName, PackageName};
 use uv_requirements::{
     LookaheadResolver, NamedRequirementsResolver, RequirementsSource, RequirementsSpecification,
@@ -86,6 +87,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     hasher: &HashStrategy,
     reinstall: &Reinstall,
     upgrade: &Upgrade,
+    prerelease_packages: &[PackageName],
     tags: Option<&Tags>,
     markers: Option<&MarkerEnvironment>,
     python_requirement: PythonRequirement,
@@ -107,7 +109,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
             requirements,
             hasher,
             index,
-            DistributionDatabase::new(client, build_dispatch, concurrency.downloads, preview),
+            DistributionDatabase::new(client, build_dispatch, concurrency.requests, preview),
         )
         .with_reporter(ResolverReporter::from(printer))
         .resolve()
@@ -120,7 +122,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
                 extras,
                 hasher,
                 index,
-                DistributionDatabase::new(client, build_dispatch, concurrency.downloads, preview),
+                DistributionDatabase::new(client, build_dispatch, concurrency.requests, preview),
             )
             .with_reporter(ResolverReporter::from(printer))
             .resolve()
@@ -173,7 +175,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
         overrides,
         hasher,
         index,
-        DistributionDatabase::new(client, build_dispatch, concurrency.downloads, preview),
+        DistributionDatabase::new(client, build_dispatch, concurrency.requests, preview),
     )
     .with_reporter(ResolverReporter::from(printer))
     .resolve()
@@ -194,7 +196,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
                 &dev,
                 hasher,
                 index,
-                DistributionDatabase::new(client, build_dispatch, concurrency.downloads, preview),
+                DistributionDatabase::new(client, build_dispatch, concurrency.requests, preview),
             )
             .with_reporter(ResolverReporter::from(printer))
             .resolve(markers)
@@ -213,6 +215,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
         overrides,
         dev,
         preferences,
+        prerelease_packages.to_vec(),
         project,
         exclusions,
         lookaheads,
@@ -239,7 +242,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
             hasher,
             build_dispatch,
             installed_packages,
-            DistributionDatabase::new(client, build_dispatch, concurrency.downloads, preview),
+            DistributionDatabase::new(client, build_dispatch, concurrency.requests, preview),
         )?
         .with_reporter(reporter);
 
@@ -285,6 +288,7 @@ pub(crate) async fn install(
     build_options: &BuildOptions,
     link_mode: LinkMode,
     compile: bool,
+    installer_name: &str,
     index_urls: &IndexLocations,
     hasher: &HashStrategy,
     tags: &Tags,
@@ -295,6 +299,7 @@ pub(crate) async fn install(
     cache: &Cache,
     venv: &PythonEnvironment,
     dry_run: bool,
+    rollback: bool,
     printer: Printer,
     preview: PreviewMode,
 ) -> Result<(), Error> {
@@ -396,12 +401,24 @@ pub(crate) async fn install(
         wheels
     };
 
+    // If requested, journal any changes to the environment, such that they can be reverted if the
+    // installation fails partway.
+    let mut rollback = if rollback {
+        Some(Rollback::new(venv))
+    } else {
+        None
+    };
+
     // Remove any upgraded or extraneous installations.
     if !extraneous.is_empty() || !reinstalls.is_empty() {
         let start = std::time::Instant::now();
 
         for dist_info in extraneous.iter().chain(reinstalls.iter()) {
-            match uv_installer::uninstall(dist_info).await {
+            let result = match rollback.as_mut() {
+                Some(rollback) => rollback.uninstall(dist_info).await,
+                None => uv_installer::uninstall(dist_info).await,
+            };
+            match result {
                 Ok(summary) => {
                     debug!(
                         "Uninstalled {} ({} file{}, {} director{})",
@@ -411,6 +428,13 @@ pub(crate) async fn install(
                         summary.dir_count,
                         if summary.dir_count == 1 { "y" } else { "ies" },
                     );
+                    for path in &summary.unrecorded_files {
+                        warn_user!(
+                            "Preserved file not listed in the RECORD of {}: {}",
+                            dist_info.name(),
+                            path.user_display().cyan(),
+                        );
+                    }
                 }
                 Err(uv_installer::UninstallError::Uninstall(
                     install_wheel_rs::Error::MissingRecord(_),
@@ -420,7 +444,12 @@ pub(crate) async fn install(
                         dist_info.path().user_display().cyan(),
                     );
                 }
-                Err(err) => return Err(err.into()),
+                Err(err) => {
+                    if let Some(rollback) = rollback {
+                        roll_back(rollback, &[], printer);
+                    }
+                    return Err(err.into());
+                }
             }
         }
 
@@ -445,10 +474,18 @@ pub(crate) async fn install(
     let wheels = wheels.into_iter().chain(cached).collect::<Vec<_>>();
     if !wheels.is_empty() {
         let start = std::time::Instant::now();
-        uv_installer::Installer::new(venv)
+        if let Err(err) = uv_installer::Installer::new(venv)
             .with_link_mode(link_mode)
+            .with_installer_name(Some(installer_name.to_string()))
+            .with_rollback(rollback.is_some())
             .with_reporter(InstallReporter::from(printer).with_length(wheels.len() as u64))
-            .install(&wheels)?;
+            .install(&wheels)
+        {
+            if let Some(rollback) = rollback {
+                roll_back(rollback, &wheels, printer);
+            }
+            return Err(err.into());
+        }
 
         let s = if wheels.len() == 1 { "" } else { "s" };
         writeln!(
@@ -473,6 +510,36 @@ pub(crate) async fn install(
     Ok(())
 }
 
+/// Restore the environment to its state prior to a failed installation, removing any of the given
+/// wheels that were installed and restoring any uninstalled distributions.
+///
+/// Failures are reported as warnings, such that the caller can surface the original error.
+fn roll_back(rollback: Rollback, wheels: &[CachedDist], printer: Printer) {
+    let start = std::time::Instant::now();
+
+    let unrestorable = match rollback.restore(wheels) {
+        Ok(unrestorable) => unrestorable,
+        Err(err) => {
+            warn_user!(
+                "Failed to roll back the installation; the environment may be incomplete: {err:#}"
+            );
+            return;
+        }
+    };
+    for dist in unrestorable {
+        warn_user!(
+            "Unable to restore {} (installed without a RECORD file); it was uninstalled during the failed installation",
+            dist.name().cyan(),
+        );
+    }
+
+    let _ = writeln!(
+        printer.stderr(),
+        "{}",
+        format!("Rolled back changes in {}", elapsed(start.elapsed())).dimmed()
+    );
+}
+
 /// Report on the results of a dry-run installation.
 fn report_dry_run(
     resolution: &Resolution,
@@ -682,24 +749,63 @@ pub(crate) fn diagnose_resolution(
 }
 
 /// Report any diagnostics on installed distributions in the Python environment.
+///
+/// If dependencies were intentionally omitted (i.e., `--no-deps`), missing dependencies of the
+/// resolved packages are not reported.
+///
+/// Incompatibilities involving any of the `overridden` packages are attributed to the override.
 pub(crate) fn diagnose_environment(
     resolution: &Resolution,
     venv: &PythonEnvironment,
+    dependency_mode: DependencyMode,
+    overridden: &FxHashSet<PackageName>,
+    warn_conflicts: bool,
     printer: Printer,
 ) -> Result<(), Error> {
     let site_packages = SitePackages::from_environment(venv)?;
     for diagnostic in site_packages.diagnostics()? {
+        // Ignore incompatible dependencies, if the user opted out of conflict warnings.
+        if !warn_conflicts
+            && matches!(
+                diagnostic,
+                SitePackagesDiagnostic::IncompatibleDependency { .. }
+            )
+        {
+            continue;
+        }
+
+        // Ignore missing dependencies of any packages whose dependencies were excluded from the
+        // resolution.
+        if dependency_mode.is_direct() {
+            if let SitePackagesDiagnostic::MissingDependency { package, .. } = &diagnostic {
+                if resolution.packages().any(|name| name == package) {
+                    continue;
+                }
+            }
+        }
+
         // Only surface diagnostics that are "relevant" to the current resolution.
         if resolution
             .packages()
             .any(|package| diagnostic.includes(package))
         {
+            let message = match &diagnostic {
+                SitePackagesDiagnostic::IncompatibleDependency {
+                    package,
+                    version,
+                    requirement,
+                } if overridden.contains(&requirement.name) => format!(
+                    "`{} {version}` is installed due to an override, but `{package}` requires `{requirement}`.",
+                    requirement.name
+                ),
+                _ => diagnostic.message(),
+            };
             writeln!(
                 printer.stderr(),
                 "{}{} {}",
                 "warning".yellow().bold(),
                 ":".bold(),
-                diagnostic.message().bold()
+                message.bold()
             )?;
         }
     }
@@ -12,7 +12,9 @@ use uv_configuration::PreviewMode;
 use uv_fs::Simplified;
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
-use uv_toolchain::{EnvironmentPreference, PythonEnvironment, ToolchainRequest};
+use uv_toolchain::{
+    EnvironmentPreference, PythonEnvironment, ToolchainPreference, ToolchainRequest,
+};
 
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
@@ -23,6 +25,7 @@ pub(crate) fn pip_show(
     strict: bool,
     python: Option<&str>,
     system: bool,
+    toolchain_preference: ToolchainPreference,
     _preview: PreviewMode,
     cache: &Cache,
     printer: Printer,
@@ -41,9 +44,10 @@ pub(crate) fn pip_show(
     }
 
     // Detect the current Python interpreter.
-    let environment = PythonEnvironment::find(
+    let environment = PythonEnvironment::find_with_toolchain_preference(
         &python.map(ToolchainRequest::parse).unwrap_or_default(),
         EnvironmentPreference::from_system_flag(system, false),
+        toolchain_preference,
         cache,
     )?;
 
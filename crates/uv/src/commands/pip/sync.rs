@@ -3,6 +3,7 @@ use std::fmt::Write;
 use anstream::eprint;
 use anyhow::Result;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use tracing::debug;
 
 use distribution_types::{IndexLocations, Resolution};
@@ -25,7 +26,8 @@ use uv_resolver::{
     PythonRequirement, ResolutionMode,
 };
 use uv_toolchain::{
-    EnvironmentPreference, Prefix, PythonEnvironment, PythonVersion, Target, ToolchainRequest,
+    EnvironmentPreference, Prefix, PythonEnvironment, PythonVersion, Target, ToolchainPreference,
+    ToolchainRequest,
 };
 use uv_types::{BuildIsolation, HashStrategy, InFlight};
 
@@ -57,11 +59,13 @@ pub(crate) async fn pip_sync(
     exclude_newer: Option<ExcludeNewer>,
     python: Option<String>,
     system: bool,
+    toolchain_preference: ToolchainPreference,
     break_system_packages: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
     concurrency: Concurrency,
     native_tls: bool,
+    retries: u32,
     preview: PreviewMode,
     cache: Cache,
     dry_run: bool,
@@ -70,6 +74,7 @@ pub(crate) async fn pip_sync(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .retries(retries)
         .keyring(keyring_provider);
 
     // Initialize a few defaults.
@@ -111,12 +116,13 @@ pub(crate) async fn pip_sync(
     }
 
     // Detect the current Python interpreter.
-    let environment = PythonEnvironment::find(
+    let environment = PythonEnvironment::find_with_toolchain_preference(
         &python
             .as_deref()
             .map(ToolchainRequest::parse)
             .unwrap_or_default(),
         EnvironmentPreference::from_system_flag(system, true),
+        toolchain_preference,
         &cache,
     )?;
 
@@ -203,6 +209,7 @@ pub(crate) async fn pip_sync(
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
+        .retries(retries)
         .connectivity(connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
@@ -286,6 +293,7 @@ pub(crate) async fn pip_sync(
         &hasher,
         &reinstall,
         &upgrade,
+        &[],
         Some(&tags),
         Some(&markers),
         python_requirement,
@@ -305,7 +313,7 @@ pub(crate) async fn pip_sync(
             let report = miette::Report::msg(format!("{err}"))
                 .context("No solution found when resolving dependencies:");
             eprint!("{report:?}");
-            return Ok(ExitStatus::Failure);
+            return Ok(ExitStatus::NoSolution);
         }
         Err(err) => return Err(err.into()),
     };
@@ -348,6 +356,7 @@ pub(crate) async fn pip_sync(
         &build_options,
         link_mode,
         compile,
+        "uv",
         &index_locations,
         &hasher,
         &tags,
@@ -358,6 +367,7 @@ pub(crate) async fn pip_sync(
         &cache,
         &environment,
         dry_run,
+        false,
         printer,
         preview,
     )
@@ -366,9 +376,17 @@ pub(crate) async fn pip_sync(
     // Notify the user of any resolution diagnostics.
     operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
-    // Notify the user of any environment diagnostics.
+    // Notify the user of any environment diagnostics. Since `pip sync` expects a complete set of
+    // requirements, report any missing dependencies, even though they weren't resolved.
     if strict && !dry_run {
-        operations::diagnose_environment(&resolution, &environment, printer)?;
+        operations::diagnose_environment(
+            &resolution,
+            &environment,
+            DependencyMode::Transitive,
+            &FxHashSet::default(),
+            true,
+            printer,
+        )?;
     }
 
     Ok(ExitStatus::Success)
@@ -1,38 +1,83 @@
+use anyhow::Context;
 use distribution_types::{Diagnostic, InstalledDist, Name};
-use owo_colors::OwoColorize;
-use pep508_rs::MarkerEnvironment;
-use pypi_types::VerbatimParsedUrl;
-use std::collections::{HashMap, HashSet};
+use itertools::Itertools;
+use owo_colors::{AnsiColors, OwoColorize};
+use pep440_rs::{Operator, Version};
+use pep508_rs::{
+    MarkerEnvironment, MarkerExpression, MarkerOperator, MarkerTree, MarkerValueString,
+    MarkerValueVersion, StringVersion, VersionOrUrl,
+};
+use pypi_types::{DirectUrl, VerbatimParsedUrl};
+use serde::Serialize;
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::fmt::Write;
+use std::str::FromStr;
+use terminal_size::Width;
 use tracing::debug;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 use uv_cache::Cache;
+use uv_cli::{TreeColorBy, TreeConnector, TreeField, TreeFormat};
 use uv_configuration::PreviewMode;
 use uv_fs::Simplified;
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
 use uv_toolchain::EnvironmentPreference;
 use uv_toolchain::PythonEnvironment;
+use uv_toolchain::ToolchainPreference;
 use uv_toolchain::ToolchainRequest;
 
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
 
 /// Display the installed packages in the current environment as a dependency tree.
+#[allow(clippy::fn_params_excessive_bools)]
 pub(crate) fn pip_tree(
     depth: u8,
+    depth_from: Option<PackageName>,
+    prune_after_depth: Option<u8>,
+    max_nodes: Option<usize>,
     prune: Vec<PackageName>,
+    exclude: Vec<PackageName>,
+    package: Vec<PackageName>,
+    installed: &[String],
     no_dedupe: bool,
+    dedupe_per_root: bool,
+    fields: &[TreeField],
+    show_summary: bool,
+    show_hashes: bool,
+    show_source: bool,
+    show_reused: bool,
+    annotate_optional: bool,
+    wrap: Option<bool>,
+    hide_version: bool,
+    color_by: Option<TreeColorBy>,
+    highlight: Vec<PackageName>,
+    show_missing: bool,
+    warn_on_missing: bool,
+    format: TreeFormat,
+    connector: TreeConnector,
+    indent: u8,
+    group_by_depth: bool,
+    flat: bool,
+    filter_by_marker: Option<&str>,
+    no_markers: bool,
+    root_markers: bool,
+    count_only: bool,
     strict: bool,
     python: Option<&str>,
     system: bool,
+    toolchain_preference: ToolchainPreference,
     _preview: PreviewMode,
     cache: &Cache,
     printer: Printer,
 ) -> anyhow::Result<ExitStatus> {
     // Detect the current Python interpreter.
-    let environment = PythonEnvironment::find(
+    let environment = PythonEnvironment::find_with_toolchain_preference(
         &python.map(ToolchainRequest::parse).unwrap_or_default(),
         EnvironmentPreference::from_system_flag(system, false),
+        toolchain_preference,
         cache,
     )?;
 
@@ -43,25 +88,225 @@ pub(crate) fn pip_tree(
     );
 
     // Build the installed index.
-    let site_packages = SitePackages::from_environment(&environment)?;
-
-    let rendered_tree = DisplayDependencyGraph::new(
-        &site_packages,
-        depth.into(),
-        prune,
-        no_dedupe,
-        environment.interpreter().markers(),
-    )
-    .render()
-    .join("\n");
-    writeln!(printer.stdout(), "{rendered_tree}").unwrap();
-    if rendered_tree.contains('*') {
-        let message = if no_dedupe {
-            "(*) Package tree is a cycle and cannot be shown".italic()
-        } else {
-            "(*) Package tree already displayed".italic()
+    let site_packages = SitePackages::from_environment_with_sys_path(&environment)?;
+
+    // Warn about any packages that are shadowed by an installation earlier on `sys.path`.
+    let mut dists_by_name: BTreeMap<&PackageName, Vec<&InstalledDist>> = BTreeMap::new();
+    for dist in site_packages.iter() {
+        dists_by_name.entry(dist.name()).or_default().push(dist);
+    }
+    for (name, dists) in dists_by_name {
+        let Some((first, rest)) = dists.split_first() else {
+            continue;
         };
-        writeln!(printer.stdout(), "{message}")?;
+        for shadowed in rest
+            .iter()
+            .filter(|dist| dist.path().parent() != first.path().parent())
+        {
+            writeln!(
+                printer.stderr(),
+                "{}{} The package `{}` is installed in multiple locations; `{}` shadows `{}`",
+                "warning".yellow().bold(),
+                ":".bold(),
+                name,
+                first.path().user_display(),
+                shadowed.path().user_display(),
+            )?;
+        }
+    }
+
+    // If requested, evaluate requirement markers against an environment synthesized from the
+    // given expression, rather than against the current interpreter. If markers are ignored
+    // entirely, every declared requirement is included.
+    let markers = if no_markers {
+        None
+    } else if let Some(filter_by_marker) = filter_by_marker {
+        let marker = MarkerTree::from_str(filter_by_marker)
+            .with_context(|| format!("Failed to parse marker expression: `{filter_by_marker}`"))?;
+        let markers = synthesize_markers(environment.interpreter().markers().clone(), &marker)
+            .with_context(|| format!("Failed to apply marker expression: `{filter_by_marker}`"))?;
+        Some(Cow::Owned(markers))
+    } else {
+        Some(Cow::Borrowed(environment.interpreter().markers()))
+    };
+
+    // If requested, validate that the given requirements are installed, rather than displaying the
+    // tree.
+    if !installed.is_empty() {
+        return validate_installed(&site_packages, installed, printer);
+    }
+
+    // If requested, report the number of installed packages and dependency edges, rather than
+    // displaying the tree.
+    if count_only {
+        let packages = site_packages.iter().count();
+        let edges = dependency_edges(&site_packages, markers.as_deref()).len();
+        let s = if packages == 1 { "" } else { "s" };
+        let es = if edges == 1 { "" } else { "s" };
+        writeln!(
+            printer.stdout(),
+            "{packages} package{s}, {edges} dependency edge{es}"
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Warn about any requested packages that aren't installed.
+    let missing = package
+        .iter()
+        .filter(|name| site_packages.get_packages(name).is_empty())
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}{} Package(s) not found for: {}",
+            "warning".yellow().bold(),
+            ":".bold(),
+            missing.iter().join(", ").bold()
+        )?;
+    }
+
+    // If requested, display the marker environment used to filter dependencies.
+    if root_markers {
+        match markers.as_deref() {
+            Some(markers) => {
+                writeln!(printer.stderr(), "Marker environment:")?;
+                for (key, value) in marker_values(markers) {
+                    writeln!(printer.stderr(), "  {key}: {}", value.cyan())?;
+                }
+            }
+            None => {
+                writeln!(printer.stderr(), "Marker environment: (ignored)")?;
+            }
+        }
+    }
+
+    match format {
+        TreeFormat::Text => {
+            let mut fields = Cow::Borrowed(fields);
+
+            // If requested, annotate each package with its summary.
+            if show_summary {
+                fields = with_field(fields, TreeField::Summary);
+            }
+
+            // If requested, annotate each package with the hash of its installed artifact.
+            if show_hashes {
+                fields = with_field(fields, TreeField::Hash);
+            }
+
+            // If requested, annotate each package with its origin.
+            if show_source {
+                fields = with_field(fields, TreeField::Source);
+            }
+
+            // If requested, annotate each package with whether it's linked from the cache.
+            if show_reused {
+                fields = with_field(fields, TreeField::Reused);
+            }
+
+            // If requested, omit the version of each package.
+            let fields = if hide_version && fields.contains(&TreeField::Version) {
+                Cow::Owned(
+                    fields
+                        .iter()
+                        .copied()
+                        .filter(|field| *field != TreeField::Version)
+                        .collect::<Vec<_>>(),
+                )
+            } else {
+                fields
+            };
+
+            let graph = DisplayDependencyGraph::new(
+                &site_packages,
+                depth.into(),
+                depth_from,
+                prune_after_depth.map(usize::from),
+                max_nodes,
+                prune,
+                exclude,
+                package,
+                no_dedupe,
+                dedupe_per_root,
+                &fields,
+                color_by,
+                highlight,
+                show_missing,
+                annotate_optional,
+                connector,
+                indent.into(),
+                markers.as_deref(),
+            );
+            let lines = if flat {
+                graph.render_flat()
+            } else if group_by_depth {
+                graph.render_by_depth()
+            } else {
+                graph.render()
+            };
+
+            // Unless wrapping was requested, truncate each line to the terminal width. When not
+            // writing to a terminal, lines are only truncated if explicitly requested.
+            let width = match wrap {
+                Some(true) => None,
+                Some(false) => terminal_width().or_else(|| {
+                    std::env::var("COLUMNS")
+                        .ok()
+                        .and_then(|columns| columns.parse::<usize>().ok())
+                }),
+                None => terminal_width(),
+            };
+            let rendered_tree = lines
+                .iter()
+                .map(|line| match width {
+                    Some(width) => truncate(line, width),
+                    None => Cow::Borrowed(line.as_str()),
+                })
+                .join("\n");
+            writeln!(printer.stdout(), "{rendered_tree}").unwrap();
+            if graph.elided.get() {
+                let message = if no_dedupe {
+                    "(*) Package tree is a cycle and cannot be shown".italic()
+                } else {
+                    "(*) Package tree already displayed".italic()
+                };
+                writeln!(printer.stdout(), "{message}")?;
+            }
+        }
+        TreeFormat::Csv => {
+            writeln!(
+                printer.stdout(),
+                "parent,child,parent_version,child_version"
+            )?;
+            for row in dependency_edges(&site_packages, markers.as_deref()) {
+                writeln!(printer.stdout(), "{row}")?;
+            }
+        }
+        TreeFormat::Json | TreeFormat::JsonFlat => {
+            let graph = JsonGraph::new(&site_packages, &package, &prune, markers.as_deref());
+            let output = if format == TreeFormat::Json {
+                serde_json::to_string(&graph.nested())?
+            } else {
+                serde_json::to_string(&graph.flat())?
+            };
+            writeln!(printer.stdout(), "{output}")?;
+        }
+    }
+
+    // If requested, report any dependencies that aren't installed.
+    let missing = if warn_on_missing {
+        missing_dependencies(&site_packages, markers.as_deref())
+    } else {
+        BTreeSet::new()
+    };
+    for name in &missing {
+        writeln!(
+            printer.stderr(),
+            "{}{} Required package is not installed: {}",
+            "warning".yellow().bold(),
+            ":".bold(),
+            name.bold()
+        )?;
     }
 
     // Validate that the environment is consistent.
@@ -76,7 +321,233 @@ pub(crate) fn pip_tree(
             )?;
         }
     }
-    Ok(ExitStatus::Success)
+
+    if missing.is_empty() {
+        Ok(ExitStatus::Success)
+    } else {
+        Ok(ExitStatus::Failure)
+    }
+}
+
+/// Validate that each of the given requirements is satisfied by an installed distribution,
+/// displaying the matching distributions.
+fn validate_installed(
+    site_packages: &SitePackages,
+    installed: &[String],
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    let mut satisfied = true;
+    for requirement in installed {
+        let requirement = pep508_rs::Requirement::<VerbatimParsedUrl>::from_str(requirement)?;
+        let specifiers = match requirement.version_or_url.as_ref() {
+            Some(VersionOrUrl::VersionSpecifier(specifiers)) => Some(specifiers),
+            Some(VersionOrUrl::Url(_)) => {
+                anyhow::bail!(
+                    "URL requirements are not supported by `--installed`: `{requirement}`"
+                );
+            }
+            None => None,
+        };
+
+        let matches = site_packages
+            .get_packages(&requirement.name)
+            .into_iter()
+            .filter(|dist| {
+                specifiers.map_or(true, |specifiers| specifiers.contains(dist.version()))
+            })
+            .collect::<Vec<_>>();
+        if matches.is_empty() {
+            satisfied = false;
+            writeln!(
+                printer.stderr(),
+                "{}{} No installed package satisfies: {}",
+                "error".red().bold(),
+                ":".bold(),
+                requirement.to_string().bold()
+            )?;
+        }
+        for dist in matches {
+            writeln!(
+                printer.stdout(),
+                "{} {}",
+                dist.name(),
+                display_version(dist.version())
+            )?;
+        }
+    }
+
+    if satisfied {
+        Ok(ExitStatus::Success)
+    } else {
+        Ok(ExitStatus::Failure)
+    }
+}
+
+/// Return the width of the terminal, if writing to a terminal.
+fn terminal_width() -> Option<usize> {
+    terminal_size::terminal_size().map(|(Width(width), _)| usize::from(width))
+}
+
+/// Truncate a line to the given display width, marking the truncation with an ellipsis.
+///
+/// ANSI escape sequences are preserved, and don't count towards the display width.
+fn truncate(line: &str, width: usize) -> Cow<'_, str> {
+    if anstream::adapter::strip_str(line).to_string().width() <= width {
+        return Cow::Borrowed(line);
+    }
+    let mut truncated = String::with_capacity(width);
+    let mut current = 0;
+    let mut styled = false;
+    let mut in_escape = false;
+    for ch in line.chars() {
+        // Copy escape sequences verbatim, up to and including the terminating letter.
+        if in_escape {
+            truncated.push(ch);
+            in_escape = !ch.is_ascii_alphabetic();
+            continue;
+        }
+        if ch == '\x1b' {
+            truncated.push(ch);
+            styled = true;
+            in_escape = true;
+            continue;
+        }
+
+        let char_width = ch.width().unwrap_or(0);
+        if current + char_width + 1 > width {
+            break;
+        }
+        current += char_width;
+        truncated.push(ch);
+    }
+    truncated.push('…');
+
+    // Reset any styling that was truncated before it was closed.
+    if styled {
+        truncated.push_str("\x1b[0m");
+    }
+    Cow::Owned(truncated)
+}
+
+/// Append the given field to the list of fields to display, if it's not already present.
+fn with_field(fields: Cow<'_, [TreeField]>, field: TreeField) -> Cow<'_, [TreeField]> {
+    if fields.contains(&field) {
+        fields
+    } else {
+        Cow::Owned(
+            fields
+                .iter()
+                .copied()
+                .chain(std::iter::once(field))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+/// Return the hash of the artifact from which the given distribution was installed, as recorded
+/// in the `archive_info` of its `direct_url.json`, formatted as `{algorithm}:{digest}`.
+///
+/// Returns `None` for distributions without a recorded hash, such as those installed from a
+/// registry, a local directory, or a VCS repository.
+fn installed_hash(installed_dist: &InstalledDist) -> Option<String> {
+    let DirectUrl::ArchiveUrl { archive_info, .. } =
+        InstalledDist::direct_url(installed_dist.path()).ok()??
+    else {
+        return None;
+    };
+
+    // Prefer the `hashes` dictionary, falling back to the legacy `hash` key (`{algorithm}={digest}`).
+    if let Some(hashes) = archive_info.hashes.filter(|hashes| !hashes.is_empty()) {
+        let (algorithm, digest) = hashes
+            .get_key_value("sha256")
+            .or_else(|| hashes.iter().min_by(|a, b| a.0.cmp(b.0)))?;
+        return Some(format!("{algorithm}:{digest}"));
+    }
+    let hash = archive_info.hash?;
+    let (algorithm, digest) = hash.split_once('=')?;
+    Some(format!("{algorithm}:{digest}"))
+}
+
+/// Return the origin of an installed distribution, as recorded in its `direct_url.json` (e.g.,
+/// `git`, `url`, or `path`).
+///
+/// Distributions without a `direct_url.json` were installed from a registry. Returns `None` if the
+/// `direct_url.json` can't be read.
+fn installed_source(installed_dist: &InstalledDist) -> Option<String> {
+    let source = match InstalledDist::direct_url(installed_dist.path()).ok()? {
+        None => "registry".to_string(),
+        Some(DirectUrl::VcsUrl { vcs_info, .. }) => vcs_info.vcs.to_string(),
+        Some(DirectUrl::ArchiveUrl { url, .. }) if url.starts_with("file:") => "path".to_string(),
+        Some(DirectUrl::ArchiveUrl { .. }) => "url".to_string(),
+        Some(DirectUrl::LocalDirectory { .. }) => "path".to_string(),
+    };
+    Some(source)
+}
+
+/// Return whether the files of an installed distribution are shared with the cache (`linked`) or
+/// were copied into the environment (`copied`), based on its `METADATA` file.
+///
+/// The installer links every file in the wheel except `RECORD`, so a `METADATA` file that's a
+/// symlink, or that has more than one hard link, indicates that the distribution was linked.
+/// Cloned files have their own inode, and so are reported as `copied`. Returns `None` if the
+/// `METADATA` file can't be inspected (e.g., for `.egg-info` installs), or on platforms that don't
+/// expose link counts.
+#[cfg(unix)]
+fn installed_reuse(installed_dist: &InstalledDist) -> Option<&'static str> {
+    use std::os::unix::fs::MetadataExt;
+
+    let metadata = fs_err::symlink_metadata(installed_dist.path().join("METADATA")).ok()?;
+    if metadata.file_type().is_symlink() || metadata.nlink() > 1 {
+        Some("linked")
+    } else {
+        Some("copied")
+    }
+}
+
+#[cfg(not(unix))]
+fn installed_reuse(_installed_dist: &InstalledDist) -> Option<&'static str> {
+    None
+}
+
+/// Render the version of an installed distribution (e.g., `v1.2.3`).
+///
+/// Some legacy and directory installs report a placeholder version (e.g., `0.0.0`) in lieu of a
+/// real one; those are rendered as `(unknown version)`.
+fn display_version(version: &Version) -> String {
+    if *version == Version::new([0]) {
+        "(unknown version)".to_string()
+    } else {
+        format!("v{version}")
+    }
+}
+
+/// Return the value of each marker in the given environment, keyed by marker name.
+fn marker_values(markers: &MarkerEnvironment) -> [(&'static str, String); 11] {
+    [
+        (
+            "implementation_name",
+            markers.implementation_name().to_string(),
+        ),
+        (
+            "implementation_version",
+            markers.implementation_version().to_string(),
+        ),
+        ("os_name", markers.os_name().to_string()),
+        ("platform_machine", markers.platform_machine().to_string()),
+        (
+            "platform_python_implementation",
+            markers.platform_python_implementation().to_string(),
+        ),
+        ("platform_release", markers.platform_release().to_string()),
+        ("platform_system", markers.platform_system().to_string()),
+        ("platform_version", markers.platform_version().to_string()),
+        (
+            "python_full_version",
+            markers.python_full_version().to_string(),
+        ),
+        ("python_version", markers.python_version().to_string()),
+        ("sys_platform", markers.sys_platform().to_string()),
+    ]
 }
 
 /// Filter out all required packages of the given distribution if they
@@ -85,11 +556,21 @@ pub(crate) fn pip_tree(
 /// For example, `requests==2.32.3` requires `charset-normalizer`, `idna`, `urllib`, and `certifi` at
 /// all times, `PySocks` on `socks` extra and `chardet` on `use_chardet_on_py3` extra.
 /// This function will return `["charset-normalizer", "idna", "urllib", "certifi"]` for `requests`.
+///
+/// If no marker environment is provided, markers are ignored entirely, and every declared
+/// requirement (including those gated on an extra) is returned, once per package name.
 fn required_with_no_extra(
     dist: &InstalledDist,
-    markers: &MarkerEnvironment,
+    markers: Option<&MarkerEnvironment>,
 ) -> Vec<pep508_rs::Requirement<VerbatimParsedUrl>> {
     let metadata = dist.metadata().unwrap();
+    let Some(markers) = markers else {
+        return metadata
+            .requires_dist
+            .into_iter()
+            .unique_by(|requirement| requirement.name.clone())
+            .collect::<Vec<_>>();
+    };
     return metadata
         .requires_dist
         .into_iter()
@@ -102,6 +583,281 @@ fn required_with_no_extra(
         .collect::<Vec<_>>();
 }
 
+/// Render each dependency edge between installed distributions as a CSV row, in the form
+/// `parent,child,parent_version,child_version`.
+///
+/// Requirements that aren't installed in the environment are omitted. Rows are sorted, for
+/// deterministic output.
+fn dependency_edges(
+    site_packages: &SitePackages,
+    markers: Option<&MarkerEnvironment>,
+) -> Vec<String> {
+    let mut rows = Vec::new();
+    for parent in site_packages.iter() {
+        for required in required_with_no_extra(parent, markers) {
+            for child in site_packages.get_packages(&required.name) {
+                rows.push(format!(
+                    "{},{},{},{}",
+                    parent.name(),
+                    child.name(),
+                    parent.version(),
+                    child.version()
+                ));
+            }
+        }
+    }
+    rows.sort_unstable();
+    rows
+}
+
+/// Apply the `key == "value"` clauses of a marker expression to the given environment.
+///
+/// Only clauses joined by `and` are supported, since each clause must pin a single value. An
+/// expression containing `or`, or a clause that doesn't pin a value (e.g.,
+/// `python_version >= "3.8"`), is rejected.
+fn synthesize_markers(
+    environment: MarkerEnvironment,
+    marker: &MarkerTree,
+) -> anyhow::Result<MarkerEnvironment> {
+    match marker {
+        MarkerTree::Expression(expression) => match expression {
+            MarkerExpression::String {
+                key,
+                operator: MarkerOperator::Equal,
+                value,
+            }
+            | MarkerExpression::StringInverted {
+                value,
+                operator: MarkerOperator::Equal,
+                key,
+            } => Ok(match key {
+                MarkerValueString::ImplementationName => {
+                    environment.with_implementation_name(value)
+                }
+                MarkerValueString::OsName | MarkerValueString::OsNameDeprecated => {
+                    environment.with_os_name(value)
+                }
+                MarkerValueString::PlatformMachine
+                | MarkerValueString::PlatformMachineDeprecated => {
+                    environment.with_platform_machine(value)
+                }
+                MarkerValueString::PlatformPythonImplementation
+                | MarkerValueString::PlatformPythonImplementationDeprecated
+                | MarkerValueString::PythonImplementationDeprecated => {
+                    environment.with_platform_python_implementation(value)
+                }
+                MarkerValueString::PlatformRelease => environment.with_platform_release(value),
+                MarkerValueString::PlatformSystem => environment.with_platform_system(value),
+                MarkerValueString::PlatformVersion
+                | MarkerValueString::PlatformVersionDeprecated => {
+                    environment.with_platform_version(value)
+                }
+                MarkerValueString::SysPlatform | MarkerValueString::SysPlatformDeprecated => {
+                    environment.with_sys_platform(value)
+                }
+            }),
+            MarkerExpression::Version { key, specifier }
+                if *specifier.operator() == Operator::Equal =>
+            {
+                let version = StringVersion::from_str(&specifier.version().to_string())
+                    .with_context(|| format!("Invalid version in marker clause: `{expression}`"))?;
+                Ok(match key {
+                    MarkerValueVersion::ImplementationVersion => {
+                        environment.with_implementation_version(version)
+                    }
+                    MarkerValueVersion::PythonFullVersion => {
+                        environment.with_python_full_version(version)
+                    }
+                    MarkerValueVersion::PythonVersion => environment.with_python_version(version),
+                })
+            }
+            _ => Err(anyhow::anyhow!(
+                "Unsupported marker clause: `{expression}` (only `key == \"value\"` clauses are supported)"
+            )),
+        },
+        MarkerTree::And(markers) => markers
+            .iter()
+            .try_fold(environment, synthesize_markers),
+        MarkerTree::Or(_) => Err(anyhow::anyhow!(
+            "Unsupported marker expression: `{marker}` (clauses can only be joined by `and`)"
+        )),
+    }
+}
+
+/// Return the names of all packages that are required by an installed distribution, but aren't
+/// installed themselves.
+fn missing_dependencies(
+    site_packages: &SitePackages,
+    markers: Option<&MarkerEnvironment>,
+) -> BTreeSet<PackageName> {
+    site_packages
+        .iter()
+        .flat_map(|dist| required_with_no_extra(dist, markers))
+        .map(|requirement| requirement.name)
+        .filter(|name| site_packages.get_packages(name).is_empty())
+        .collect()
+}
+
+/// The full definition of a package in the JSON dependency graph.
+#[derive(Debug, Serialize)]
+struct JsonPackage {
+    /// The normalized name of the package, which uniquely identifies it in the graph.
+    id: String,
+    name: String,
+    version: String,
+    /// The IDs of the package's installed dependencies.
+    dependencies: Vec<String>,
+}
+
+/// A node in the nested JSON dependency tree.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum JsonNode {
+    /// The first occurrence of a package, with its dependencies expanded.
+    Package {
+        id: String,
+        dependencies: Vec<JsonNode>,
+    },
+    /// A reference to a package that was already expanded elsewhere in the tree.
+    Ref {
+        #[serde(rename = "ref")]
+        id: String,
+    },
+}
+
+/// The nested JSON representation of the dependency tree.
+#[derive(Debug, Serialize)]
+struct JsonTree<'a> {
+    roots: Vec<JsonNode>,
+    packages: &'a BTreeMap<String, JsonPackage>,
+}
+
+/// The flattened JSON representation of the dependency graph.
+#[derive(Debug, Serialize)]
+struct JsonFlatTree<'a> {
+    roots: &'a [String],
+    packages: &'a BTreeMap<String, JsonPackage>,
+}
+
+/// The dependency graph of the installed packages, for rendering as JSON.
+#[derive(Debug)]
+struct JsonGraph {
+    /// The IDs of the packages at the roots of the graph, in sorted order.
+    roots: Vec<String>,
+    /// The full definition of each package, keyed by ID.
+    packages: BTreeMap<String, JsonPackage>,
+}
+
+impl JsonGraph {
+    /// Build the dependency graph for the set of installed distributions.
+    ///
+    /// If packages were requested, they're used as the roots of the graph. Otherwise, the roots
+    /// are the packages that aren't required by any other installed package.
+    fn new(
+        site_packages: &SitePackages,
+        requested: &[PackageName],
+        prune: &[PackageName],
+        markers: Option<&MarkerEnvironment>,
+    ) -> Self {
+        let mut packages = BTreeMap::new();
+        let mut required = HashSet::new();
+        for dist in site_packages.iter() {
+            if prune.contains(dist.name()) {
+                continue;
+            }
+            // Skip any installation that's shadowed by one earlier on `sys.path`.
+            if packages.contains_key(&dist.name().to_string()) {
+                continue;
+            }
+            let dependencies = required_with_no_extra(dist, markers)
+                .into_iter()
+                .map(|requirement| requirement.name)
+                .filter(|name| !prune.contains(name))
+                .filter(|name| !site_packages.get_packages(name).is_empty())
+                .unique()
+                .map(|name| name.to_string())
+                .collect::<Vec<_>>();
+            required.extend(dependencies.iter().cloned());
+            packages.insert(
+                dist.name().to_string(),
+                JsonPackage {
+                    id: dist.name().to_string(),
+                    name: dist.name().to_string(),
+                    version: dist.version().to_string(),
+                    dependencies,
+                },
+            );
+        }
+
+        let roots = if requested.is_empty() {
+            packages
+                .keys()
+                .filter(|id| !required.contains(*id))
+                .cloned()
+                .collect()
+        } else {
+            requested
+                .iter()
+                .map(ToString::to_string)
+                .filter(|id| packages.contains_key(id))
+                .collect()
+        };
+
+        Self { roots, packages }
+    }
+
+    /// Render the graph as a nested tree, in which each package is expanded at its first
+    /// occurrence, and referenced by ID thereafter.
+    fn nested(&self) -> JsonTree<'_> {
+        let mut visited = HashSet::new();
+        let roots = self
+            .roots
+            .iter()
+            .map(|id| self.node(id, &mut visited))
+            .collect();
+        JsonTree {
+            roots,
+            packages: &self.packages,
+        }
+    }
+
+    /// Render the graph in flattened form, in which packages are only referenced by ID.
+    fn flat(&self) -> JsonFlatTree<'_> {
+        JsonFlatTree {
+            roots: &self.roots,
+            packages: &self.packages,
+        }
+    }
+
+    /// Render the node for the given package, expanding its dependencies if it hasn't been
+    /// visited yet.
+    fn node(&self, id: &str, visited: &mut HashSet<String>) -> JsonNode {
+        // Emit a reference for any package that was already expanded, which also terminates
+        // dependency cycles.
+        if !visited.insert(id.to_string()) {
+            return JsonNode::Ref { id: id.to_string() };
+        }
+        let dependencies = self.packages[id]
+            .dependencies
+            .iter()
+            .map(|dependency| self.node(dependency, visited))
+            .collect();
+        JsonNode::Package {
+            id: id.to_string(),
+            dependencies,
+        }
+    }
+}
+
+/// The colors to cycle through when coloring packages by their depth in the dependency tree.
+const DEPTH_COLORS: [AnsiColors; 5] = [
+    AnsiColors::Cyan,
+    AnsiColors::Green,
+    AnsiColors::Yellow,
+    AnsiColors::Magenta,
+    AnsiColors::Blue,
+];
+
 #[derive(Debug)]
 struct DisplayDependencyGraph<'a> {
     site_packages: &'a SitePackages,
@@ -111,14 +867,48 @@ struct DisplayDependencyGraph<'a> {
     /// It is used to determine the starting nodes when recursing the
     /// dependency graph.
     required_packages: HashSet<PackageName>,
+    /// Set of package names that are only required by installed distributions via an extra, to be
+    /// annotated as `(optional)`.
+    optional_packages: HashSet<PackageName>,
     /// Maximum display depth of the dependency tree
     depth: usize,
+    /// The package from which the display depth is measured, if any.
+    depth_from: Option<PackageName>,
+    /// The depth past which the dependency tree should not be traversed at all.
+    prune_after_depth: Option<usize>,
+    /// The maximum number of packages to render, if any.
+    max_nodes: Option<usize>,
+    /// The number of packages rendered so far.
+    rendered: Cell<usize>,
+    /// Whether rendering was stopped upon reaching `max_nodes`.
+    truncated: Cell<bool>,
+    /// Whether any package was marked with `(*)`, as already displayed or part of a cycle.
+    elided: Cell<bool>,
     /// Prune the given package from the display of the dependency tree.
     prune: Vec<PackageName>,
+    /// Hide the given package from the display of the dependency tree, displaying its
+    /// dependencies in its place.
+    exclude: Vec<PackageName>,
+    /// The packages to display at the root of the dependency tree, if any.
+    packages: Vec<PackageName>,
     /// Whether to de-duplicate the displayed dependencies.
     no_dedupe: bool,
-    /// The marker environment for the current interpreter.
-    markers: &'a MarkerEnvironment,
+    /// Whether to reset the de-duplication state between each root of the tree.
+    dedupe_per_root: bool,
+    /// The fields to display for each package.
+    fields: &'a [TreeField],
+    /// The property by which to color each package, if any.
+    color_by: Option<TreeColorBy>,
+    /// The packages to highlight wherever they appear in the tree.
+    highlight: Vec<PackageName>,
+    /// Whether to display required dependencies that aren't installed.
+    show_missing: bool,
+    /// The characters used to connect each package to its dependents.
+    connector: TreeConnector,
+    /// The number of columns by which each level of the tree is indented.
+    indent: usize,
+    /// The marker environment for the current interpreter, or `None` to ignore markers.
+    markers: Option<&'a MarkerEnvironment>,
 }
 
 impl<'a> DisplayDependencyGraph<'a> {
@@ -126,32 +916,284 @@ impl<'a> DisplayDependencyGraph<'a> {
     fn new(
         site_packages: &'a SitePackages,
         depth: usize,
+        depth_from: Option<PackageName>,
+        prune_after_depth: Option<usize>,
+        max_nodes: Option<usize>,
         prune: Vec<PackageName>,
+        exclude: Vec<PackageName>,
+        packages: Vec<PackageName>,
         no_dedupe: bool,
-        markers: &'a MarkerEnvironment,
+        dedupe_per_root: bool,
+        fields: &'a [TreeField],
+        color_by: Option<TreeColorBy>,
+        highlight: Vec<PackageName>,
+        show_missing: bool,
+        annotate_optional: bool,
+        connector: TreeConnector,
+        indent: usize,
+        markers: Option<&'a MarkerEnvironment>,
     ) -> DisplayDependencyGraph<'a> {
         let mut dist_by_package_name = HashMap::new();
         let mut required_packages = HashSet::new();
         for site_package in site_packages.iter() {
-            dist_by_package_name.insert(site_package.name(), site_package);
+            // Prefer the first installation on `sys.path`, which shadows any later ones.
+            dist_by_package_name
+                .entry(site_package.name())
+                .or_insert(site_package);
         }
-        for site_package in site_packages.iter() {
+        for site_package in dist_by_package_name.values() {
             for required in required_with_no_extra(site_package, markers) {
                 required_packages.insert(required.name.clone());
             }
         }
 
+        // Determine the packages that are required under an extra, but never unconditionally.
+        let mut optional_packages = HashSet::new();
+        if let Some(markers) = markers.filter(|_| annotate_optional) {
+            for site_package in site_packages.iter() {
+                let Ok(metadata) = site_package.metadata() else {
+                    continue;
+                };
+                for requirement in metadata.requires_dist {
+                    if required_packages.contains(&requirement.name) {
+                        continue;
+                    }
+                    if requirement
+                        .marker
+                        .as_ref()
+                        .is_some_and(|marker| marker.evaluate(markers, &metadata.provides_extras))
+                    {
+                        optional_packages.insert(requirement.name);
+                    }
+                }
+            }
+        }
+
         Self {
             site_packages,
             dist_by_package_name,
             required_packages,
+            optional_packages,
             depth,
+            depth_from,
+            prune_after_depth,
+            max_nodes,
+            rendered: Cell::new(0),
+            truncated: Cell::new(false),
+            elided: Cell::new(false),
             prune,
+            exclude,
+            packages,
             no_dedupe,
+            dedupe_per_root,
+            fields,
+            color_by,
+            highlight,
+            show_missing,
+            connector,
+            indent,
             markers,
         }
     }
 
+    /// Record that a package is about to be rendered, returning `false` (and marking the output as
+    /// truncated) if the `--max-nodes` limit has already been reached.
+    fn reserve_node(&self) -> bool {
+        if self
+            .max_nodes
+            .is_some_and(|max_nodes| self.rendered.get() >= max_nodes)
+        {
+            self.truncated.set(true);
+            return false;
+        }
+        self.rendered.set(self.rendered.get() + 1);
+        true
+    }
+
+    /// Append a note to the given lines if rendering was stopped upon reaching `--max-nodes`.
+    fn note_truncation(&self, lines: &mut Vec<String>) {
+        if let Some(max_nodes) = self.max_nodes.filter(|_| self.truncated.get()) {
+            lines.push(format!("… (truncated at {max_nodes} nodes)"));
+        }
+    }
+
+    /// Return the prefixes for the first and subsequent lines of a dependency, based on whether
+    /// it's the last dependency of its parent.
+    ///
+    /// For example, with the default connector and indentation, the first line of the last
+    /// dependency is prefixed with `└── ` and the rest with four spaces.
+    fn prefixes(&self, is_last: bool) -> (String, String) {
+        let (branch, horizontal, vertical) = match (is_last, self.connector) {
+            (true, TreeConnector::Unicode) => ('└', '─', ' '),
+            (false, TreeConnector::Unicode) => ('├', '─', '│'),
+            (true, TreeConnector::Ascii) => ('`', '-', ' '),
+            (false, TreeConnector::Ascii) => ('|', '-', '|'),
+            (_, TreeConnector::Spaces) => (' ', ' ', ' '),
+        };
+        let prefix_top = format!(
+            "{branch}{} ",
+            horizontal.to_string().repeat(self.indent - 2)
+        );
+        let prefix_rest = format!("{vertical}{}", " ".repeat(self.indent - 1));
+        (prefix_top, prefix_rest)
+    }
+
+    /// Render the label for the given distribution, based on the requested fields.
+    ///
+    /// Fields that are absent from the distribution's metadata are omitted.
+    fn label(&self, installed_dist: &InstalledDist) -> String {
+        let descriptive_metadata = if self
+            .fields
+            .iter()
+            .any(|field| matches!(field, TreeField::License | TreeField::Summary))
+        {
+            installed_dist.descriptive_metadata().ok()
+        } else {
+            None
+        };
+
+        let mut parts = Vec::with_capacity(self.fields.len());
+        for field in self.fields {
+            match field {
+                TreeField::Name => parts.push(installed_dist.name().to_string()),
+                TreeField::Version => parts.push(display_version(installed_dist.version())),
+                TreeField::Path => {
+                    parts.push(format!("[path: {}]", installed_dist.path().user_display()));
+                }
+                TreeField::License => {
+                    if let Some(license) = descriptive_metadata
+                        .as_ref()
+                        .and_then(|metadata| metadata.license.as_deref())
+                    {
+                        parts.push(format!("[license: {license}]"));
+                    }
+                }
+                TreeField::Summary => {
+                    if let Some(summary) = descriptive_metadata
+                        .as_ref()
+                        .and_then(|metadata| metadata.summary.as_deref())
+                    {
+                        // Normalize multi-line summaries to a single line.
+                        let summary = summary.split_whitespace().join(" ");
+                        parts.push(format!("[summary: {summary}]"));
+                    }
+                }
+                TreeField::RequiresPython => {
+                    if let Some(requires_python) = installed_dist
+                        .metadata()
+                        .ok()
+                        .and_then(|metadata| metadata.requires_python)
+                    {
+                        parts.push(format!("[requires-python: {requires_python}]"));
+                    }
+                }
+                TreeField::Hash => match installed_hash(installed_dist) {
+                    Some(hash) => parts.push(format!("[hash: {hash}]")),
+                    None => parts.push("(no hash)".to_string()),
+                },
+                TreeField::Source => {
+                    if let Some(source) = installed_source(installed_dist) {
+                        parts.push(format!("({source})"));
+                    }
+                }
+                TreeField::Reused => {
+                    if let Some(reused) = installed_reuse(installed_dist) {
+                        parts.push(format!("({reused})"));
+                    }
+                }
+            }
+        }
+        if self.optional_packages.contains(installed_dist.name()) {
+            parts.push("(optional)".to_string());
+        }
+        parts.join(" ")
+    }
+
+    /// Highlight the given label if the distribution was requested via `--highlight`.
+    fn highlight(&self, installed_dist: &InstalledDist, label: String) -> String {
+        if self.highlight.contains(installed_dist.name()) {
+            label.on_yellow().to_string()
+        } else {
+            label
+        }
+    }
+
+    /// Return the depth of the current path, measured from the `--depth-from` package, if any.
+    ///
+    /// Paths that don't pass through the `--depth-from` package have a depth of zero, such that
+    /// they're never truncated.
+    fn relative_depth(&self, path: &[String]) -> usize {
+        match self.depth_from.as_ref() {
+            Some(depth_from) => path
+                .iter()
+                .position(|name| name == depth_from.as_ref())
+                .map_or(0, |index| path.len() - index),
+            None => path.len(),
+        }
+    }
+
+    /// Replace any excluded packages in the given requirements with their own (transitive)
+    /// requirements, such that they're displayed in place of the excluded package.
+    ///
+    /// Excluded packages that aren't installed are omitted.
+    fn flatten_excluded(
+        &self,
+        requirements: Vec<pep508_rs::Requirement<VerbatimParsedUrl>>,
+        seen: &mut HashSet<PackageName>,
+    ) -> Vec<pep508_rs::Requirement<VerbatimParsedUrl>> {
+        let mut flattened = Vec::with_capacity(requirements.len());
+        for requirement in requirements {
+            if !self.exclude.contains(&requirement.name) {
+                flattened.push(requirement);
+                continue;
+            }
+            // Avoid infinite recursion in the event of a cycle between excluded packages.
+            if !seen.insert(requirement.name.clone()) {
+                continue;
+            }
+            if let Some(excluded_dist) = self.dist_by_package_name.get(&requirement.name) {
+                flattened.extend(
+                    self.flatten_excluded(
+                        required_with_no_extra(excluded_dist, self.markers),
+                        seen,
+                    ),
+                );
+            }
+        }
+
+        // If a package is reachable through multiple excluded packages, display it once.
+        let mut names = HashSet::new();
+        flattened.retain(|requirement| names.insert(requirement.name.clone()));
+        flattened
+    }
+
+    /// Visit the given root of the dependency tree, or, if the root is excluded, each of its
+    /// dependencies in turn.
+    fn visit_root(
+        &self,
+        installed_dist: &InstalledDist,
+        visited: &mut HashSet<String>,
+    ) -> Vec<String> {
+        if !self.exclude.contains(installed_dist.name()) {
+            return self.visit(installed_dist, visited, &mut Vec::new());
+        }
+
+        let mut seen = HashSet::from([installed_dist.name().clone()]);
+        let mut lines = Vec::new();
+        for requirement in self.flatten_excluded(
+            required_with_no_extra(installed_dist, self.markers),
+            &mut seen,
+        ) {
+            if self.prune.contains(&requirement.name) {
+                continue;
+            }
+            if let Some(required_dist) = self.dist_by_package_name.get(&requirement.name) {
+                lines.extend(self.visit(required_dist, visited, &mut Vec::new()));
+            }
+        }
+        lines
+    }
+
     /// Perform a depth-first traversal of the given distribution and its dependencies.
     fn visit(
         &self,
@@ -160,18 +1202,41 @@ impl<'a> DisplayDependencyGraph<'a> {
         path: &mut Vec<String>,
     ) -> Vec<String> {
         // Short-circuit if the current path is longer than the provided depth.
-        if path.len() > self.depth {
+        if self.relative_depth(path) > self.depth {
             return Vec::new();
         }
 
+        // Stop rendering once the `--max-nodes` limit has been reached.
+        if !self.reserve_node() {
+            return Vec::new();
+        }
+
+        let line = match self.color_by {
+            Some(TreeColorBy::Depth) => {
+                let color = DEPTH_COLORS[path.len() % DEPTH_COLORS.len()];
+                self.label(installed_dist).color(color).to_string()
+            }
+            None => self.label(installed_dist),
+        };
+        let line = self.highlight(installed_dist, line);
+
+        // Halt the traversal if the current path has reached the pruning depth, without recording
+        // the package for de-duplication or cycle detection.
+        if self
+            .prune_after_depth
+            .is_some_and(|prune_after_depth| path.len() >= prune_after_depth)
+        {
+            return vec![line];
+        }
+
         let package_name = installed_dist.name().to_string();
         let is_visited = visited.contains(&package_name);
-        let line = format!("{} v{}", package_name, installed_dist.version());
 
         // Skip the traversal if
         // 1. the package is in the current traversal path (i.e. a dependency cycle)
         // 2. if the package has been visited and de-duplication is enabled (default)
         if path.contains(&package_name) || (is_visited && !self.no_dedupe) {
+            self.elided.set(true);
             return vec![format!("{} (*)", line)];
         }
 
@@ -179,19 +1244,15 @@ impl<'a> DisplayDependencyGraph<'a> {
 
         path.push(package_name.clone());
         visited.insert(package_name.clone());
-        let required_packages = required_with_no_extra(installed_dist, self.markers)
+        let required_packages = self
+            .flatten_excluded(
+                required_with_no_extra(installed_dist, self.markers),
+                &mut HashSet::new(),
+            )
             .into_iter()
             .filter(|p| !self.prune.contains(&p.name))
             .collect::<Vec<_>>();
         for (index, required_package) in required_packages.iter().enumerate() {
-            // Skip if the current package is not one of the installed distributions.
-            if !self
-                .dist_by_package_name
-                .contains_key(&required_package.name)
-            {
-                continue;
-            }
-
             // For sub-visited packages, add the prefix to make the tree display user-friendly.
             // The key observation here is you can group the tree as follows when you're at the
             // root of the tree:
@@ -211,28 +1272,31 @@ impl<'a> DisplayDependencyGraph<'a> {
             // those in Group 3 have `└── ` at the top and `    ` at the rest.
             // This observation is true recursively even when looking at the subtree rooted
             // at `level_1_0`.
-            let (prefix_top, prefix_rest) = if required_packages.len() - 1 == index {
-                ("└── ", "    ")
-            } else {
-                ("├── ", "│   ")
+            let is_last = required_packages.len() - 1 == index;
+            let (prefix_top, prefix_rest) = self.prefixes(is_last);
+
+            // If the current package is not one of the installed distributions, skip it, or
+            // display it as missing.
+            let Some(required_dist) = self.dist_by_package_name.get(&required_package.name) else {
+                if self.show_missing {
+                    lines.push(format!(
+                        "{prefix_top}{} (not installed)",
+                        required_package.name
+                    ));
+                }
+                continue;
             };
 
             let mut prefixed_lines = Vec::new();
-            for (visited_index, visited_line) in self
-                .visit(
-                    self.dist_by_package_name[&required_package.name],
-                    visited,
-                    path,
-                )
-                .iter()
-                .enumerate()
+            for (visited_index, visited_line) in
+                self.visit(required_dist, visited, path).iter().enumerate()
             {
                 prefixed_lines.push(format!(
                     "{}{}",
                     if visited_index == 0 {
-                        prefix_top
+                        &prefix_top
                     } else {
-                        prefix_rest
+                        &prefix_rest
                     },
                     visited_line
                 ));
@@ -243,18 +1307,180 @@ impl<'a> DisplayDependencyGraph<'a> {
         lines
     }
 
+    /// Iterate over the installed distributions, skipping any that are shadowed by an installation
+    /// of the same package earlier on `sys.path`.
+    fn dists(&self) -> impl Iterator<Item = &'a InstalledDist> + '_ {
+        self.site_packages.iter().filter(|dist| {
+            self.dist_by_package_name
+                .get(dist.name())
+                .is_some_and(|first| first.path() == dist.path())
+        })
+    }
+
     // Depth-first traverse the nodes to render the tree.
-    // The starting nodes are the ones without incoming edges.
+    // The starting nodes are the requested packages, if any, or else the ones without incoming
+    // edges.
     fn render(&self) -> Vec<String> {
         let mut visited: HashSet<String> = HashSet::new();
         let mut lines: Vec<String> = Vec::new();
-        for site_package in self.site_packages.iter() {
-            // If the current package is not required by any other package, start the traversal
-            // with the current package as the root.
-            if !self.required_packages.contains(site_package.name()) {
-                lines.extend(self.visit(site_package, &mut visited, &mut Vec::new()));
+
+        // If packages were requested, anchor the tree at each of them, in the order in which they
+        // were given, regardless of their position in the dependency graph.
+        if !self.packages.is_empty() {
+            for package in &self.packages {
+                if let Some(installed_dist) = self.dist_by_package_name.get(package) {
+                    if self.dedupe_per_root {
+                        visited.clear();
+                    }
+                    lines.extend(self.visit_root(installed_dist, &mut visited));
+                }
+            }
+            self.note_truncation(&mut lines);
+            return lines;
+        }
+
+        // Otherwise, start the traversal from each package that isn't required by any other
+        // package, sorted by name for deterministic output.
+        for site_package in self
+            .dists()
+            .filter(|site_package| !self.required_packages.contains(site_package.name()))
+            .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
+        {
+            if self.dedupe_per_root {
+                visited.clear();
+            }
+            lines.extend(self.visit_root(site_package, &mut visited));
+        }
+        self.note_truncation(&mut lines);
+        lines
+    }
+
+    /// Render one line per installed package, listing the packages that directly require it
+    /// (e.g., `idna v3.6 <- requests`), rather than a nested tree.
+    fn render_flat(&self) -> Vec<String> {
+        // Invert the dependency graph, mapping each package to the packages that require it.
+        let mut requesters: HashMap<&PackageName, BTreeSet<&PackageName>> = HashMap::new();
+        for site_package in self.dists() {
+            for required in required_with_no_extra(site_package, self.markers) {
+                if let Some(required_dist) = self.dist_by_package_name.get(&required.name) {
+                    requesters
+                        .entry(required_dist.name())
+                        .or_default()
+                        .insert(site_package.name());
+                }
+            }
+        }
+
+        let mut lines = Vec::new();
+        for installed_dist in self
+            .dists()
+            .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
+        {
+            if !self.reserve_node() {
+                break;
+            }
+            let parents = requesters.get(installed_dist.name()).map_or_else(
+                || "(top-level)".to_string(),
+                |parents| parents.iter().join(", "),
+            );
+            lines.push(format!(
+                "{} <- {parents}",
+                self.highlight(installed_dist, self.label(installed_dist))
+            ));
+        }
+        self.note_truncation(&mut lines);
+        lines
+    }
+
+    /// Render the packages grouped by their minimum depth in the dependency tree, rather than as a
+    /// nested tree.
+    ///
+    /// Performs a breadth-first traversal from the same starting nodes as [`Self::render`], such
+    /// that each package is listed once, at the shallowest level at which it's reachable.
+    fn render_by_depth(&self) -> Vec<String> {
+        let roots = if self.packages.is_empty() {
+            self.dists()
+                .filter(|site_package| !self.required_packages.contains(site_package.name()))
+                .sorted_unstable_by(|a, b| {
+                    a.name().cmp(b.name()).then(a.version().cmp(b.version()))
+                })
+                .collect::<Vec<_>>()
+        } else {
+            self.packages
+                .iter()
+                .filter_map(|package| self.dist_by_package_name.get(package).copied())
+                .collect::<Vec<_>>()
+        };
+
+        // Expand any excluded roots into their (non-excluded) dependencies.
+        let mut queue = VecDeque::new();
+        for root in roots {
+            if self.exclude.contains(root.name()) {
+                let mut seen = HashSet::from([root.name().clone()]);
+                for requirement in
+                    self.flatten_excluded(required_with_no_extra(root, self.markers), &mut seen)
+                {
+                    if self.prune.contains(&requirement.name) {
+                        continue;
+                    }
+                    if let Some(required_dist) = self.dist_by_package_name.get(&requirement.name) {
+                        queue.push_back((*required_dist, 0));
+                    }
+                }
+            } else {
+                queue.push_back((root, 0));
+            }
+        }
+
+        let mut levels: BTreeMap<usize, Vec<&InstalledDist>> = BTreeMap::new();
+        let mut visited = HashSet::new();
+        while let Some((installed_dist, depth)) = queue.pop_front() {
+            if depth > self.depth || !visited.insert(installed_dist.name()) {
+                continue;
+            }
+            if !self.reserve_node() {
+                break;
+            }
+            levels.entry(depth).or_default().push(installed_dist);
+
+            if self
+                .prune_after_depth
+                .is_some_and(|prune_after_depth| depth >= prune_after_depth)
+            {
+                continue;
+            }
+            for requirement in self.flatten_excluded(
+                required_with_no_extra(installed_dist, self.markers),
+                &mut HashSet::new(),
+            ) {
+                if self.prune.contains(&requirement.name) {
+                    continue;
+                }
+                if let Some(required_dist) = self.dist_by_package_name.get(&requirement.name) {
+                    queue.push_back((*required_dist, depth + 1));
+                }
+            }
+        }
+
+        let mut lines = Vec::new();
+        for (depth, dists) in levels {
+            lines.push(format!("Level {depth}:"));
+            for installed_dist in dists
+                .into_iter()
+                .sorted_unstable_by(|a, b| a.name().cmp(b.name()))
+            {
+                let label = match self.color_by {
+                    Some(TreeColorBy::Depth) => {
+                        let color = DEPTH_COLORS[depth % DEPTH_COLORS.len()];
+                        self.label(installed_dist).color(color).to_string()
+                    }
+                    None => self.label(installed_dist),
+                };
+                let label = self.highlight(installed_dist, label);
+                lines.push(format!("  {label}"));
             }
         }
+        self.note_truncation(&mut lines);
         lines
     }
 }
@@ -15,6 +15,7 @@ use uv_configuration::{KeyringProviderType, PreviewMode};
 use uv_fs::Simplified;
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_toolchain::EnvironmentPreference;
+use uv_toolchain::ToolchainPreference;
 use uv_toolchain::ToolchainRequest;
 use uv_toolchain::{Prefix, PythonEnvironment, Target};
 
@@ -26,12 +27,14 @@ pub(crate) async fn pip_uninstall(
     sources: &[RequirementsSource],
     python: Option<String>,
     system: bool,
+    toolchain_preference: ToolchainPreference,
     break_system_packages: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
     cache: Cache,
     connectivity: Connectivity,
     native_tls: bool,
+    retries: u32,
     _preview: PreviewMode,
     keyring_provider: KeyringProviderType,
     printer: Printer,
@@ -40,18 +43,20 @@ pub(crate) async fn pip_uninstall(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .retries(retries)
         .keyring(keyring_provider);
 
     // Read all requirements from the provided sources.
     let spec = RequirementsSpecification::from_simple_sources(sources, &client_builder).await?;
 
     // Detect the current Python interpreter.
-    let environment = PythonEnvironment::find(
+    let environment = PythonEnvironment::find_with_toolchain_preference(
         &python
             .as_deref()
             .map(ToolchainRequest::parse)
             .unwrap_or_default(),
         EnvironmentPreference::from_system_flag(system, true),
+        toolchain_preference,
         &cache,
     )?;
 
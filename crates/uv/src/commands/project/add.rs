@@ -141,7 +141,7 @@ pub(crate) async fn add(
         requirements,
         &hasher,
         &index,
-        DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads, preview),
+        DistributionDatabase::new(&client, &build_dispatch, concurrency.requests, preview),
     )
     .with_reporter(ResolverReporter::from(printer))
     .resolve()
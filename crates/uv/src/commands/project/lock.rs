@@ -74,7 +74,7 @@ pub(crate) async fn lock(
             let report = miette::Report::msg(format!("{err}"))
                 .context("No solution found when resolving dependencies:");
             eprint!("{report:?}");
-            Ok(ExitStatus::Failure)
+            Ok(ExitStatus::NoSolution)
         }
         Err(err) => Err(err.into()),
     }
@@ -99,6 +99,7 @@ pub(super) async fn do_lock(
         keyring_provider,
         resolution,
         prerelease,
+        prerelease_package,
         config_setting,
         exclude_newer,
         link_mode,
@@ -218,6 +219,7 @@ pub(super) async fn do_lock(
         &hasher,
         &Reinstall::default(),
         upgrade,
+        prerelease_package,
         None,
         None,
         python_requirement,
@@ -296,6 +296,7 @@ pub(crate) async fn update_environment(
         keyring_provider,
         resolution,
         prerelease,
+        prerelease_package,
         config_setting,
         exclude_newer,
         link_mode,
@@ -421,6 +422,7 @@ pub(crate) async fn update_environment(
         &hasher,
         reinstall,
         upgrade,
+        prerelease_package,
         Some(tags),
         Some(markers),
         python_requirement,
@@ -477,6 +479,7 @@ pub(crate) async fn update_environment(
         build_options,
         *link_mode,
         *compile_bytecode,
+        "uv",
         index_locations,
         &hasher,
         tags,
@@ -487,6 +490,7 @@ pub(crate) async fn update_environment(
         cache,
         &venv,
         dry_run,
+        false,
         printer,
         preview,
     )
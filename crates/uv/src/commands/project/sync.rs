@@ -194,6 +194,7 @@ pub(super) async fn do_sync(
         build_options,
         link_mode,
         compile_bytecode,
+        "uv",
         index_locations,
         &hasher,
         tags,
@@ -204,6 +205,7 @@ pub(super) async fn do_sync(
         cache,
         venv,
         dry_run,
+        false,
         printer,
         preview,
     )
@@ -1,4 +1,5 @@
 use std::env;
+use std::fmt::Write;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
@@ -30,6 +31,8 @@ enum ProgressMode {
         multi_progress: MultiProgress,
         state: Arc<Mutex<BarState>>,
     },
+    // Reports the start and end of each download/build/checkout process as a line of text.
+    Plain,
 }
 
 #[derive(Default, Debug)]
@@ -54,7 +57,9 @@ impl BarState {
 
 impl ProgressReporter {
     fn new(root: ProgressBar, multi_progress: MultiProgress, printer: Printer) -> ProgressReporter {
-        let mode = if env::var("JPY_SESSION_NAME").is_ok() {
+        let mode = if printer == Printer::Plain {
+            ProgressMode::Plain
+        } else if env::var("JPY_SESSION_NAME").is_ok() {
             // Disable concurrent progress bars when running inside a Jupyter notebook
             // because the Jupyter terminal does not support clearing previous lines.
             // See: https://github.com/astral-sh/uv/issues/3887.
@@ -73,6 +78,16 @@ impl ProgressReporter {
         }
     }
     fn on_build_start(&self, source: &BuildableSource) -> usize {
+        if let ProgressMode::Plain = self.mode {
+            let _ = writeln!(
+                self.printer.stderr(),
+                "{} {}",
+                "Building".bold().cyan(),
+                source.to_color_string()
+            );
+            return 0;
+        }
+
         let ProgressMode::Multi {
             multi_progress,
             state,
@@ -102,6 +117,16 @@ impl ProgressReporter {
     }
 
     fn on_build_complete(&self, source: &BuildableSource, id: usize) {
+        if let ProgressMode::Plain = self.mode {
+            let _ = writeln!(
+                self.printer.stderr(),
+                "   {} {}",
+                "Built".bold().green(),
+                source.to_color_string()
+            );
+            return;
+        }
+
         let ProgressMode::Multi { state, .. } = &self.mode else {
             return;
         };
@@ -120,6 +145,16 @@ impl ProgressReporter {
     }
 
     fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize {
+        if let ProgressMode::Plain = self.mode {
+            let _ = writeln!(
+                self.printer.stderr(),
+                "{} {}",
+                "Downloading".bold().cyan(),
+                name
+            );
+            return 0;
+        }
+
         let ProgressMode::Multi {
             multi_progress,
             state,
@@ -168,7 +203,17 @@ impl ProgressReporter {
         state.lock().unwrap().bars[&id].inc(bytes);
     }
 
-    fn on_download_complete(&self, id: usize) {
+    fn on_download_complete(&self, name: &PackageName, id: usize) {
+        if let ProgressMode::Plain = self.mode {
+            let _ = writeln!(
+                self.printer.stderr(),
+                " {} {}",
+                "Downloaded".bold().green(),
+                name
+            );
+            return;
+        }
+
         let ProgressMode::Multi { state, .. } = &self.mode else {
             return;
         };
@@ -178,6 +223,17 @@ impl ProgressReporter {
     }
 
     fn on_checkout_start(&self, url: &Url, rev: &str) -> usize {
+        if let ProgressMode::Plain = self.mode {
+            let _ = writeln!(
+                self.printer.stderr(),
+                "{} {} ({})",
+                "Updating".bold().cyan(),
+                url,
+                rev.dimmed()
+            );
+            return 0;
+        }
+
         let ProgressMode::Multi {
             multi_progress,
             state,
@@ -209,6 +265,17 @@ impl ProgressReporter {
     }
 
     fn on_checkout_complete(&self, url: &Url, rev: &str, id: usize) {
+        if let ProgressMode::Plain = self.mode {
+            let _ = writeln!(
+                self.printer.stderr(),
+                " {} {} ({})",
+                "Updated".bold().green(),
+                url,
+                rev.dimmed()
+            );
+            return;
+        }
+
         let ProgressMode::Multi { state, .. } = &self.mode else {
             return;
         };
@@ -286,8 +353,8 @@ impl uv_installer::PrepareReporter for PrepareReporter {
         self.reporter.on_download_progress(id, bytes);
     }
 
-    fn on_download_complete(&self, _name: &PackageName, id: usize) {
-        self.reporter.on_download_complete(id);
+    fn on_download_complete(&self, name: &PackageName, id: usize) {
+        self.reporter.on_download_complete(name, id);
     }
 
     fn on_checkout_start(&self, url: &Url, rev: &str) -> usize {
@@ -370,8 +437,8 @@ impl uv_resolver::ResolverReporter for ResolverReporter {
         self.reporter.on_download_progress(id, bytes);
     }
 
-    fn on_download_complete(&self, _name: &PackageName, id: usize) {
-        self.reporter.on_download_complete(id);
+    fn on_download_complete(&self, name: &PackageName, id: usize) {
+        self.reporter.on_download_complete(name, id);
     }
 }
 
@@ -392,8 +459,8 @@ impl uv_distribution::Reporter for ResolverReporter {
         self.reporter.on_download_progress(id, bytes);
     }
 
-    fn on_download_complete(&self, _name: &PackageName, id: usize) {
-        self.reporter.on_download_complete(id);
+    fn on_download_complete(&self, name: &PackageName, id: usize) {
+        self.reporter.on_download_complete(name, id);
     }
 
     fn on_checkout_start(&self, url: &Url, rev: &str) -> usize {
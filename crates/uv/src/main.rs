@@ -28,7 +28,8 @@ use uv_settings::Combine;
 use crate::commands::ExitStatus;
 use crate::settings::{
     CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipFreezeSettings,
-    PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings, PipUninstallSettings,
+    PipInspectSettings, PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings,
+    PipUninstallSettings,
 };
 
 #[cfg(target_os = "windows")]
@@ -113,7 +114,15 @@ async fn run() -> Result<ExitStatus> {
                     _ => {}
                 }
             }
-            err.exit()
+
+            // Print the error (or the requested help or version output), and exit with a
+            // dedicated code for usage errors.
+            err.print()?;
+            return if err.use_stderr() {
+                Ok(ExitStatus::Usage)
+            } else {
+                Ok(ExitStatus::Success)
+            };
         }
     };
 
@@ -243,6 +252,16 @@ async fn run() -> Result<ExitStatus> {
                 .into_iter()
                 .map(RequirementsSource::from_constraints_txt)
                 .collect::<Vec<_>>();
+            let build_constraints = args
+                .build_constraint
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
+            let constraints_from_lock = args
+                .constraint_from_lock
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
             let overrides = args
                 .r#override
                 .into_iter()
@@ -252,16 +271,26 @@ async fn run() -> Result<ExitStatus> {
             commands::pip_compile(
                 &requirements,
                 &constraints,
+                &build_constraints,
+                &constraints_from_lock,
                 &overrides,
                 args.overrides_from_workspace,
                 args.settings.extras,
                 args.settings.output_file.as_deref(),
+                args.verify,
+                args.backup,
+                args.check_input_hash,
+                args.local_version_policy,
+                args.strip_trailing_zeros,
+                args.prune,
                 args.settings.resolution,
                 args.settings.prerelease,
+                args.settings.prerelease_package,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
                 args.settings.generate_hashes,
                 args.settings.no_emit_package,
+                args.settings.unsafe_package,
                 args.settings.no_strip_extras,
                 args.settings.no_strip_markers,
                 !args.settings.no_annotate,
@@ -272,16 +301,25 @@ async fn run() -> Result<ExitStatus> {
                 args.settings.emit_build_options,
                 args.settings.emit_marker_expression,
                 args.settings.emit_index_annotation,
+                args.settings.keep_yanked_reason,
+                args.settings.emit_build_isolation_note,
+                args.settings.emit_input_hash,
+                args.settings.emit_python_version,
+                args.settings.emit_timestamp,
+                args.settings.emit_workspace_members,
                 args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.keyring_provider,
                 args.settings.setup_py,
                 args.settings.config_setting,
+                args.settings.config_settings_package,
                 globals.connectivity,
                 args.settings.no_build_isolation,
+                args.settings.sources,
                 args.settings.build_options,
                 args.settings.python_version,
                 args.settings.python_platform,
+                args.marker,
                 args.settings.universal,
                 args.settings.exclude_newer,
                 args.settings.annotation_style,
@@ -291,6 +329,7 @@ async fn run() -> Result<ExitStatus> {
                 globals.toolchain_preference,
                 args.settings.concurrency,
                 globals.native_tls,
+                globals.retries,
                 globals.quiet,
                 globals.preview,
                 cache,
@@ -347,11 +386,13 @@ async fn run() -> Result<ExitStatus> {
                 args.settings.exclude_newer,
                 args.settings.python,
                 args.settings.system,
+                globals.toolchain_preference,
                 args.settings.break_system_packages,
                 args.settings.target,
                 args.settings.prefix,
                 args.settings.concurrency,
                 globals.native_tls,
+                globals.retries,
                 globals.preview,
                 cache,
                 args.dry_run,
@@ -395,6 +436,11 @@ async fn run() -> Result<ExitStatus> {
                 .r#override
                 .into_iter()
                 .map(RequirementsSource::from_overrides_txt)
+                .chain(
+                    args.dependency_overrides
+                        .into_iter()
+                        .map(RequirementsSource::from_package),
+                )
                 .collect::<Vec<_>>();
 
             commands::pip_install(
@@ -405,8 +451,10 @@ async fn run() -> Result<ExitStatus> {
                 &args.settings.extras,
                 args.settings.resolution,
                 args.settings.prerelease,
+                args.settings.prerelease_package,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
+                args.settings.upgrade_strategy,
                 args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.keyring_provider,
@@ -425,15 +473,26 @@ async fn run() -> Result<ExitStatus> {
                 args.settings.exclude_newer,
                 args.settings.python,
                 args.settings.system,
+                globals.toolchain_preference,
                 args.settings.break_system_packages,
                 args.settings.target,
                 args.settings.prefix,
                 args.settings.concurrency,
                 globals.native_tls,
+                globals.retries,
                 globals.preview,
                 cache,
                 args.dry_run,
-                printer,
+                args.system_site_packages,
+                args.no_build_isolation_package,
+                args.verify_hashes,
+                &args.installer_name,
+                args.no_warn_conflicts,
+                args.no_rollback,
+                args.only,
+                args.prefer_find_links,
+                args.exclude_newer_package,
+                printer.with_progress(args.progress),
             )
             .await
         }
@@ -461,12 +520,14 @@ async fn run() -> Result<ExitStatus> {
                 &sources,
                 args.settings.python,
                 args.settings.system,
+                globals.toolchain_preference,
                 args.settings.break_system_packages,
                 args.settings.target,
                 args.settings.prefix,
                 cache,
                 globals.connectivity,
                 globals.native_tls,
+                globals.retries,
                 globals.preview,
                 args.settings.keyring_provider,
                 printer,
@@ -488,6 +549,7 @@ async fn run() -> Result<ExitStatus> {
                 args.settings.strict,
                 args.settings.python.as_deref(),
                 args.settings.system,
+                globals.toolchain_preference,
                 globals.preview,
                 &cache,
                 printer,
@@ -513,6 +575,7 @@ async fn run() -> Result<ExitStatus> {
                 args.settings.strict,
                 args.settings.python.as_deref(),
                 args.settings.system,
+                globals.toolchain_preference,
                 globals.preview,
                 &cache,
                 printer,
@@ -533,6 +596,7 @@ async fn run() -> Result<ExitStatus> {
                 args.settings.strict,
                 args.settings.python.as_deref(),
                 args.settings.system,
+                globals.toolchain_preference,
                 globals.preview,
                 &cache,
                 printer,
@@ -549,11 +613,40 @@ async fn run() -> Result<ExitStatus> {
 
             commands::pip_tree(
                 args.depth,
+                args.depth_from,
+                args.prune_after_depth,
+                args.max_nodes,
                 args.prune,
+                args.exclude,
+                args.package,
+                &args.installed,
                 args.no_dedupe,
+                args.dedupe_per_root,
+                &args.fields,
+                args.show_summary,
+                args.show_hashes,
+                args.show_source,
+                args.show_reused,
+                args.annotate_optional,
+                args.wrap,
+                args.hide_version,
+                args.color_by,
+                args.highlight,
+                args.show_missing,
+                args.warn_on_missing,
+                args.format,
+                args.connector,
+                args.indent,
+                args.group_by_depth,
+                args.flat,
+                args.filter_by_marker.as_deref(),
+                args.no_markers,
+                args.root_markers,
+                args.count_only,
                 args.shared.strict,
                 args.shared.python.as_deref(),
                 args.shared.system,
+                globals.toolchain_preference,
                 globals.preview,
                 &cache,
                 printer,
@@ -572,6 +665,26 @@ async fn run() -> Result<ExitStatus> {
             commands::pip_check(
                 args.settings.python.as_deref(),
                 args.settings.system,
+                globals.toolchain_preference,
+                globals.preview,
+                &cache,
+                printer,
+            )
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Inspect(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipInspectSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::pip_inspect(
+                args.settings.python.as_deref(),
+                args.settings.system,
+                globals.toolchain_preference,
                 globals.preview,
                 &cache,
                 printer,
@@ -947,7 +1060,7 @@ fn main() -> ExitCode {
             for err in causes {
                 eprintln!("  {}: {}", "Caused by".red().bold(), err);
             }
-            ExitStatus::Error.into()
+            ExitStatus::from_error(&err).into()
         }
     }
 }
@@ -1,6 +1,8 @@
 use anstream::{eprint, print};
 use indicatif::ProgressDrawTarget;
 
+use uv_cli::ProgressDisplay;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum Printer {
     /// A printer that prints to standard streams (e.g., stdout).
@@ -9,6 +11,14 @@ pub(crate) enum Printer {
     Quiet,
     /// A printer that prints all output, including debug messages.
     Verbose,
+    /// A printer that prints to standard streams, but hides all progress output.
+    NoProgress,
+    /// A printer that prints to standard streams, reporting progress as plain lines of text rather
+    /// than progress bars.
+    Plain,
+    /// A printer that prints to standard streams, drawing progress bars even if standard error
+    /// isn't a terminal.
+    ForceProgress,
 }
 
 impl Printer {
@@ -20,6 +30,25 @@ impl Printer {
             // Confusingly, hide the progress bar when in verbose mode.
             // Otherwise, it gets interleaved with debug messages.
             Self::Verbose => ProgressDrawTarget::hidden(),
+            Self::NoProgress => ProgressDrawTarget::hidden(),
+            Self::Plain => ProgressDrawTarget::hidden(),
+            // Draw via a `TermLike` target, which (unlike `ProgressDrawTarget::stderr`) doesn't
+            // check whether standard error is a terminal.
+            Self::ForceProgress => {
+                ProgressDrawTarget::term_like(Box::new(console::Term::buffered_stderr()))
+            }
+        }
+    }
+
+    /// Return the printer to use for the given [`ProgressDisplay`].
+    ///
+    /// Quiet and verbose printers are left unchanged, as they never display progress.
+    pub(crate) fn with_progress(self, progress: ProgressDisplay) -> Self {
+        match (self, progress) {
+            (Self::Default, ProgressDisplay::Bar) => Self::ForceProgress,
+            (Self::Default, ProgressDisplay::Plain) => Self::Plain,
+            (Self::Default, ProgressDisplay::None) => Self::NoProgress,
+            _ => self,
         }
     }
 
@@ -29,6 +58,9 @@ impl Printer {
             Self::Default => Stdout::Enabled,
             Self::Quiet => Stdout::Disabled,
             Self::Verbose => Stdout::Enabled,
+            Self::NoProgress => Stdout::Enabled,
+            Self::Plain => Stdout::Enabled,
+            Self::ForceProgress => Stdout::Enabled,
         }
     }
 
@@ -38,6 +70,9 @@ impl Printer {
             Self::Default => Stderr::Enabled,
             Self::Quiet => Stderr::Disabled,
             Self::Verbose => Stderr::Enabled,
+            Self::NoProgress => Stderr::Enabled,
+            Self::Plain => Stderr::Enabled,
+            Self::ForceProgress => Stderr::Enabled,
         }
     }
 }
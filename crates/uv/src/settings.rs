@@ -8,25 +8,30 @@ use distribution_types::IndexLocations;
 use install_wheel_rs::linker::LinkMode;
 use pep508_rs::{ExtraName, RequirementOrigin};
 use pypi_types::Requirement;
+use rustc_hash::FxHashMap;
 use uv_cache::{CacheArgs, Refresh};
 use uv_cli::options::{flag, installer_options, resolver_installer_options, resolver_options};
 use uv_cli::{
-    AddArgs, ColorChoice, Commands, ExternalCommand, GlobalArgs, ListFormat, LockArgs, Maybe,
-    PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, RemoveArgs, RunArgs, SyncArgs, ToolInstallArgs,
-    ToolListArgs, ToolRunArgs, ToolUninstallArgs, ToolchainFindArgs, ToolchainInstallArgs,
-    ToolchainListArgs, VenvArgs,
+    AddArgs, ColorChoice, Commands, CompileFormat, ExternalCommand, GlobalArgs, ListFormat,
+    LockArgs, Maybe, PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInspectArgs, PipInstallArgs,
+    PipListArgs, PipShowArgs, PipSyncArgs, PipTreeArgs, PipUninstallArgs, ProgressDisplay,
+    RemoveArgs, RunArgs, SyncArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs,
+    ToolchainFindArgs, ToolchainInstallArgs, ToolchainListArgs, TreeColorBy, TreeConnector,
+    TreeField, TreeFormat, VenvArgs,
 };
 use uv_client::Connectivity;
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, ExtrasSpecification, IndexStrategy,
-    KeyringProviderType, NoBinary, NoBuild, PreviewMode, Reinstall, SetupPyStrategy, TargetTriple,
-    Upgrade,
+    KeyringProviderType, MarkerOverride, NoBinary, NoBuild, PackageConfigSettings, PreviewMode,
+    Reinstall, SetupPyStrategy, SourceStrategy, TargetTriple, Upgrade, UpgradeStrategy,
 };
 use uv_distribution::pyproject::DependencyType;
 use uv_normalize::PackageName;
 use uv_requirements::RequirementsSource;
-use uv_resolver::{AnnotationStyle, DependencyMode, ExcludeNewer, PreReleaseMode, ResolutionMode};
+use uv_resolver::{
+    AnnotationStyle, DependencyMode, ExcludeNewer, LocalVersionPolicy, PreReleaseMode,
+    ResolutionMode,
+};
 use uv_settings::{
     Combine, FilesystemOptions, InstallerOptions, Options, PipOptions, ResolverInstallerOptions,
     ResolverOptions,
@@ -44,6 +49,7 @@ pub(crate) struct GlobalSettings {
     pub(crate) color: ColorChoice,
     pub(crate) native_tls: bool,
     pub(crate) connectivity: Connectivity,
+    pub(crate) retries: u32,
     pub(crate) isolated: bool,
     pub(crate) show_settings: bool,
     pub(crate) preview: PreviewMode,
@@ -107,6 +113,7 @@ impl GlobalSettings {
             } else {
                 Connectivity::Online
             },
+            retries: args.retries.unwrap_or(3),
             isolated: args.isolated,
             show_settings: args.show_settings,
             preview,
@@ -580,8 +587,17 @@ impl RemoveSettings {
 pub(crate) struct PipCompileSettings {
     pub(crate) src_file: Vec<PathBuf>,
     pub(crate) constraint: Vec<PathBuf>,
+    pub(crate) build_constraint: Vec<PathBuf>,
+    pub(crate) constraint_from_lock: Vec<PathBuf>,
     pub(crate) r#override: Vec<PathBuf>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
+    pub(crate) verify: bool,
+    pub(crate) backup: bool,
+    pub(crate) check_input_hash: bool,
+    pub(crate) local_version_policy: LocalVersionPolicy,
+    pub(crate) strip_trailing_zeros: bool,
+    pub(crate) prune: Vec<PackageName>,
+    pub(crate) marker: Vec<MarkerOverride>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -592,6 +608,8 @@ impl PipCompileSettings {
         let PipCompileArgs {
             src_file,
             constraint,
+            build_constraint,
+            constraint_from_lock,
             r#override,
             extra,
             all_extras,
@@ -600,6 +618,13 @@ impl PipCompileSettings {
             no_deps,
             deps,
             output_file,
+            verify,
+            backup,
+            check_input_hash,
+            local_version_policy,
+            strip_trailing_zeros,
+            prune,
+            format,
             no_strip_extras,
             strip_extras,
             no_strip_markers,
@@ -620,15 +645,22 @@ impl PipCompileSettings {
             no_legacy_setup_py,
             no_build_isolation,
             build_isolation,
+            config_setting_package,
+            no_sources,
+            sources,
             no_build,
             build,
             no_binary,
             only_binary,
             python_version,
             python_platform,
+            marker,
             universal,
             no_universal,
             no_emit_package,
+            unsafe_package,
+            allow_unsafe,
+            no_allow_unsafe,
             emit_index_url,
             no_emit_index_url,
             emit_find_links,
@@ -639,6 +671,20 @@ impl PipCompileSettings {
             no_emit_marker_expression,
             emit_index_annotation,
             no_emit_index_annotation,
+            keep_yanked_reason,
+            no_keep_yanked_reason,
+            emit_build_isolation_note,
+            no_emit_build_isolation_note,
+            emit_input_hash,
+            no_emit_input_hash,
+            emit_python_version,
+            no_emit_python_version,
+            emit_timestamp,
+            no_emit_timestamp,
+            emit_workspace_members,
+            no_emit_workspace_members,
+            concurrent_downloads,
+            concurrent_requests,
             compat_args: _,
         } = args;
 
@@ -662,8 +708,20 @@ impl PipCompileSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            build_constraint: build_constraint
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            constraint_from_lock,
             r#override,
             overrides_from_workspace,
+            verify,
+            backup,
+            check_input_hash,
+            local_version_policy,
+            strip_trailing_zeros,
+            prune,
+            marker,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -673,29 +731,50 @@ impl PipCompileSettings {
                     no_binary,
                     only_binary,
                     no_build_isolation: flag(no_build_isolation, build_isolation),
+                    no_sources: flag(no_sources, sources),
                     extra,
                     all_extras: flag(all_extras, no_all_extras),
                     no_deps: flag(no_deps, deps),
                     output_file,
-                    no_strip_extras: flag(no_strip_extras, strip_extras),
+                    no_strip_extras: match format {
+                        CompileFormat::RequirementsTxt => flag(no_strip_extras, strip_extras),
+                        CompileFormat::RequirementsWithExtras => Some(true),
+                    },
                     no_strip_markers: flag(no_strip_markers, strip_markers),
                     no_annotate: flag(no_annotate, annotate),
                     no_header: flag(no_header, header),
                     custom_compile_command,
                     generate_hashes: flag(generate_hashes, no_generate_hashes),
                     legacy_setup_py: flag(legacy_setup_py, no_legacy_setup_py),
+                    config_settings_package: config_setting_package.map(|config_settings| {
+                        config_settings
+                            .into_iter()
+                            .collect::<PackageConfigSettings>()
+                    }),
                     python_version,
                     python_platform,
                     universal: flag(universal, no_universal),
                     no_emit_package,
+                    allow_unsafe: flag(allow_unsafe, no_allow_unsafe),
+                    unsafe_package,
                     emit_index_url: flag(emit_index_url, no_emit_index_url),
                     emit_find_links: flag(emit_find_links, no_emit_find_links),
                     emit_build_options: flag(emit_build_options, no_emit_build_options),
                     emit_marker_expression: flag(emit_marker_expression, no_emit_marker_expression),
                     emit_index_annotation: flag(emit_index_annotation, no_emit_index_annotation),
+                    keep_yanked_reason: flag(keep_yanked_reason, no_keep_yanked_reason),
+                    emit_build_isolation_note: flag(
+                        emit_build_isolation_note,
+                        no_emit_build_isolation_note,
+                    ),
+                    emit_input_hash: flag(emit_input_hash, no_emit_input_hash),
+                    emit_python_version: flag(emit_python_version, no_emit_python_version),
+                    emit_timestamp: flag(emit_timestamp, no_emit_timestamp),
+                    emit_workspace_members: flag(emit_workspace_members, no_emit_workspace_members),
                     annotation_style,
                     concurrent_builds: env(env::CONCURRENT_BUILDS),
-                    concurrent_downloads: env(env::CONCURRENT_DOWNLOADS),
+                    concurrent_downloads: concurrent_downloads.map(concurrency_limit),
+                    concurrent_requests: concurrent_requests.map(concurrency_limit),
                     concurrent_installs: env(env::CONCURRENT_INSTALLS),
                     ..PipOptions::from(resolver)
                 },
@@ -747,6 +826,8 @@ impl PipSyncSettings {
             strict,
             no_strict,
             dry_run,
+            concurrent_downloads,
+            concurrent_requests,
             compat_args: _,
         } = args;
 
@@ -776,7 +857,8 @@ impl PipSyncSettings {
                     python_platform,
                     require_hashes: flag(require_hashes, no_require_hashes),
                     concurrent_builds: env(env::CONCURRENT_BUILDS),
-                    concurrent_downloads: env(env::CONCURRENT_DOWNLOADS),
+                    concurrent_downloads: concurrent_downloads.map(concurrency_limit),
+                    concurrent_requests: concurrent_requests.map(concurrency_limit),
                     concurrent_installs: env(env::CONCURRENT_INSTALLS),
                     ..PipOptions::from(installer)
                 },
@@ -793,9 +875,20 @@ pub(crate) struct PipInstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirement: Vec<PathBuf>,
     pub(crate) editable: Vec<String>,
+    pub(crate) only: Vec<PackageName>,
     pub(crate) constraint: Vec<PathBuf>,
     pub(crate) r#override: Vec<PathBuf>,
+    pub(crate) dependency_overrides: Vec<String>,
     pub(crate) dry_run: bool,
+    pub(crate) system_site_packages: bool,
+    pub(crate) no_build_isolation_package: Vec<PackageName>,
+    pub(crate) verify_hashes: bool,
+    pub(crate) installer_name: String,
+    pub(crate) no_warn_conflicts: bool,
+    pub(crate) no_rollback: bool,
+    pub(crate) prefer_find_links: bool,
+    pub(crate) exclude_newer_package: FxHashMap<PackageName, ExcludeNewer>,
+    pub(crate) progress: ProgressDisplay,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
@@ -808,12 +901,15 @@ impl PipInstallSettings {
             package,
             requirement,
             editable,
+            only,
             constraint,
             r#override,
+            dependency_overrides,
             extra,
             all_extras,
             no_all_extras,
             refresh,
+            upgrade_strategy,
             no_deps,
             deps,
             require_hashes,
@@ -830,6 +926,16 @@ impl PipInstallSettings {
             no_legacy_setup_py,
             no_build_isolation,
             build_isolation,
+            system_site_packages,
+            no_build_isolation_package,
+            verify_hashes,
+            no_verify_hashes,
+            installer_name,
+            no_warn_conflicts,
+            no_rollback,
+            prefer_find_links,
+            exclude_newer_package,
+            progress,
             no_build,
             build,
             no_binary,
@@ -839,6 +945,8 @@ impl PipInstallSettings {
             strict,
             no_strict,
             dry_run,
+            concurrent_downloads,
+            concurrent_requests,
             compat_args: _,
         } = args;
 
@@ -860,12 +968,33 @@ impl PipInstallSettings {
             package,
             requirement,
             editable,
+            only,
             constraint: constraint
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
             r#override,
+            dependency_overrides,
             dry_run,
+            system_site_packages,
+            no_build_isolation_package,
+            verify_hashes: flag(verify_hashes, no_verify_hashes).unwrap_or(true),
+            installer_name,
+            no_warn_conflicts,
+            no_rollback,
+            prefer_find_links,
+            exclude_newer_package: exclude_newer_package
+                .into_iter()
+                .map(|entry| (entry.package, entry.timestamp))
+                .collect(),
+            // `UV_NO_PROGRESS` disables progress, unless a mode is explicitly requested.
+            progress: progress.unwrap_or_else(|| {
+                if std::env::var_os("UV_NO_PROGRESS").is_some() {
+                    ProgressDisplay::None
+                } else {
+                    ProgressDisplay::default()
+                }
+            }),
             overrides_from_workspace,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
@@ -882,13 +1011,15 @@ impl PipInstallSettings {
                     strict: flag(strict, no_strict),
                     extra,
                     all_extras: flag(all_extras, no_all_extras),
+                    upgrade_strategy,
                     no_deps: flag(no_deps, deps),
                     legacy_setup_py: flag(legacy_setup_py, no_legacy_setup_py),
                     python_version,
                     python_platform,
                     require_hashes: flag(require_hashes, no_require_hashes),
                     concurrent_builds: env(env::CONCURRENT_BUILDS),
-                    concurrent_downloads: env(env::CONCURRENT_DOWNLOADS),
+                    concurrent_downloads: concurrent_downloads.map(concurrency_limit),
+                    concurrent_requests: concurrent_requests.map(concurrency_limit),
                     concurrent_installs: env(env::CONCURRENT_INSTALLS),
                     ..PipOptions::from(installer)
                 },
@@ -1062,8 +1193,36 @@ impl PipShowSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct PipTreeSettings {
     pub(crate) depth: u8,
+    pub(crate) depth_from: Option<PackageName>,
+    pub(crate) prune_after_depth: Option<u8>,
+    pub(crate) max_nodes: Option<usize>,
     pub(crate) prune: Vec<PackageName>,
+    pub(crate) exclude: Vec<PackageName>,
+    pub(crate) package: Vec<PackageName>,
+    pub(crate) installed: Vec<String>,
     pub(crate) no_dedupe: bool,
+    pub(crate) dedupe_per_root: bool,
+    pub(crate) fields: Vec<TreeField>,
+    pub(crate) show_summary: bool,
+    pub(crate) show_hashes: bool,
+    pub(crate) show_source: bool,
+    pub(crate) show_reused: bool,
+    pub(crate) annotate_optional: bool,
+    pub(crate) wrap: Option<bool>,
+    pub(crate) hide_version: bool,
+    pub(crate) color_by: Option<TreeColorBy>,
+    pub(crate) highlight: Vec<PackageName>,
+    pub(crate) show_missing: bool,
+    pub(crate) warn_on_missing: bool,
+    pub(crate) format: TreeFormat,
+    pub(crate) connector: TreeConnector,
+    pub(crate) indent: u8,
+    pub(crate) group_by_depth: bool,
+    pub(crate) flat: bool,
+    pub(crate) filter_by_marker: Option<String>,
+    pub(crate) no_markers: bool,
+    pub(crate) root_markers: bool,
+    pub(crate) count_only: bool,
     // CLI-only settings.
     pub(crate) shared: PipSettings,
 }
@@ -1073,8 +1232,39 @@ impl PipTreeSettings {
     pub(crate) fn resolve(args: PipTreeArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let PipTreeArgs {
             depth,
+            depth_from,
+            prune_after_depth,
+            max_nodes,
             prune,
+            exclude,
+            package,
+            installed,
             no_dedupe,
+            dedupe_per_root,
+            dedupe_across_roots,
+            fields,
+            show_summary,
+            show_hashes,
+            show_source,
+            show_reused,
+            annotate_optional,
+            wrap,
+            no_wrap,
+            hide_version,
+            color_by,
+            highlight,
+            show_missing,
+            warn_on_missing,
+            format,
+            ascii,
+            connector,
+            indent,
+            group_by_depth,
+            flat,
+            filter_by_marker,
+            no_markers,
+            root_markers,
+            count_only,
             strict,
             no_strict,
             python,
@@ -1084,8 +1274,40 @@ impl PipTreeSettings {
 
         Self {
             depth,
+            depth_from,
+            prune_after_depth,
+            max_nodes,
             prune,
+            exclude,
+            package,
+            installed,
             no_dedupe,
+            dedupe_per_root: flag(dedupe_per_root, dedupe_across_roots).unwrap_or(false),
+            fields,
+            show_summary,
+            show_hashes,
+            show_source,
+            show_reused,
+            annotate_optional,
+            wrap: flag(wrap, no_wrap),
+            hide_version,
+            color_by,
+            highlight,
+            show_missing,
+            warn_on_missing,
+            format,
+            connector: connector.unwrap_or(if ascii {
+                TreeConnector::Ascii
+            } else {
+                TreeConnector::Unicode
+            }),
+            indent,
+            group_by_depth,
+            flat,
+            filter_by_marker,
+            no_markers,
+            root_markers,
+            count_only,
             // Shared settings.
             shared: PipSettings::combine(
                 PipOptions {
@@ -1129,6 +1351,35 @@ impl PipCheckSettings {
     }
 }
 
+/// The resolved settings to use for a `pip inspect` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct PipInspectSettings {
+    pub(crate) settings: PipSettings,
+}
+
+impl PipInspectSettings {
+    /// Resolve the [`PipInspectSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: PipInspectArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PipInspectArgs {
+            python,
+            system,
+            no_system,
+        } = args;
+
+        Self {
+            settings: PipSettings::combine(
+                PipOptions {
+                    python,
+                    system: flag(system, no_system),
+                    ..PipOptions::default()
+                },
+                filesystem,
+            ),
+        }
+    }
+}
+
 /// The resolved settings to use for a `pip check` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -1224,6 +1475,7 @@ impl InstallerSettings {
             keyring_provider,
             resolution: _,
             prerelease: _,
+            prerelease_package: _,
             config_settings,
             exclude_newer: _,
             link_mode,
@@ -1316,6 +1568,7 @@ pub(crate) struct ResolverSettings {
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PreReleaseMode,
+    pub(crate) prerelease_package: Vec<PackageName>,
     pub(crate) config_setting: ConfigSettings,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
     pub(crate) link_mode: LinkMode,
@@ -1330,6 +1583,7 @@ pub(crate) struct ResolverSettingsRef<'a> {
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PreReleaseMode,
+    pub(crate) prerelease_package: &'a [PackageName],
     pub(crate) config_setting: &'a ConfigSettings,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
     pub(crate) link_mode: LinkMode,
@@ -1349,6 +1603,7 @@ impl ResolverSettings {
             keyring_provider,
             resolution,
             prerelease,
+            prerelease_package,
             config_settings,
             exclude_newer,
             link_mode,
@@ -1377,6 +1632,10 @@ impl ResolverSettings {
             ),
             resolution: args.resolution.combine(resolution).unwrap_or_default(),
             prerelease: args.prerelease.combine(prerelease).unwrap_or_default(),
+            prerelease_package: args
+                .prerelease_package
+                .combine(prerelease_package)
+                .unwrap_or_default(),
             index_strategy: args
                 .index_strategy
                 .combine(index_strategy)
@@ -1421,6 +1680,7 @@ impl ResolverSettings {
             keyring_provider: self.keyring_provider,
             resolution: self.resolution,
             prerelease: self.prerelease,
+            prerelease_package: &self.prerelease_package,
             config_setting: &self.config_setting,
             exclude_newer: self.exclude_newer,
             link_mode: self.link_mode,
@@ -1443,6 +1703,7 @@ pub(crate) struct ResolverInstallerSettings {
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PreReleaseMode,
+    pub(crate) prerelease_package: Vec<PackageName>,
     pub(crate) config_setting: ConfigSettings,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
     pub(crate) link_mode: LinkMode,
@@ -1459,6 +1720,7 @@ pub(crate) struct ResolverInstallerSettingsRef<'a> {
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PreReleaseMode,
+    pub(crate) prerelease_package: &'a [PackageName],
     pub(crate) config_setting: &'a ConfigSettings,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
     pub(crate) link_mode: LinkMode,
@@ -1483,6 +1745,7 @@ impl ResolverInstallerSettings {
             keyring_provider,
             resolution,
             prerelease,
+            prerelease_package,
             config_settings,
             exclude_newer,
             link_mode,
@@ -1511,6 +1774,10 @@ impl ResolverInstallerSettings {
             ),
             resolution: args.resolution.combine(resolution).unwrap_or_default(),
             prerelease: args.prerelease.combine(prerelease).unwrap_or_default(),
+            prerelease_package: args
+                .prerelease_package
+                .combine(prerelease_package)
+                .unwrap_or_default(),
             index_strategy: args
                 .index_strategy
                 .combine(index_strategy)
@@ -1565,6 +1832,7 @@ impl ResolverInstallerSettings {
             keyring_provider: self.keyring_provider,
             resolution: self.resolution,
             prerelease: self.prerelease,
+            prerelease_package: &self.prerelease_package,
             config_setting: &self.config_setting,
             exclude_newer: self.exclude_newer,
             link_mode: self.link_mode,
@@ -1576,6 +1844,12 @@ impl ResolverInstallerSettings {
     }
 }
 
+/// The packages that are considered unsafe to pin in a requirements file, and so are omitted
+/// from `pip compile` output unless `--allow-unsafe` is provided. Matches pip-compile.
+///
+/// Additional packages can be marked as unsafe via `--unsafe-package`.
+const DEFAULT_UNSAFE_PACKAGES: &[&str] = &["distribute", "pip", "setuptools"];
+
 /// The resolved settings to use for an invocation of the `pip` CLI.
 ///
 /// Represents the shared settings that are used across all `pip` commands. Analogous to the
@@ -1593,11 +1867,13 @@ pub(crate) struct PipSettings {
     pub(crate) index_strategy: IndexStrategy,
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) no_build_isolation: bool,
+    pub(crate) sources: SourceStrategy,
     pub(crate) build_options: BuildOptions,
     pub(crate) strict: bool,
     pub(crate) dependency_mode: DependencyMode,
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PreReleaseMode,
+    pub(crate) prerelease_package: Vec<PackageName>,
     pub(crate) output_file: Option<PathBuf>,
     pub(crate) no_strip_extras: bool,
     pub(crate) no_strip_markers: bool,
@@ -1607,21 +1883,30 @@ pub(crate) struct PipSettings {
     pub(crate) generate_hashes: bool,
     pub(crate) setup_py: SetupPyStrategy,
     pub(crate) config_setting: ConfigSettings,
+    pub(crate) config_settings_package: PackageConfigSettings,
     pub(crate) python_version: Option<PythonVersion>,
     pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) universal: bool,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
     pub(crate) no_emit_package: Vec<PackageName>,
+    pub(crate) unsafe_package: Vec<PackageName>,
     pub(crate) emit_index_url: bool,
     pub(crate) emit_find_links: bool,
     pub(crate) emit_build_options: bool,
     pub(crate) emit_marker_expression: bool,
     pub(crate) emit_index_annotation: bool,
+    pub(crate) keep_yanked_reason: bool,
+    pub(crate) emit_build_isolation_note: bool,
+    pub(crate) emit_input_hash: bool,
+    pub(crate) emit_python_version: bool,
+    pub(crate) emit_timestamp: bool,
+    pub(crate) emit_workspace_members: bool,
     pub(crate) annotation_style: AnnotationStyle,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
     pub(crate) require_hashes: bool,
     pub(crate) upgrade: Upgrade,
+    pub(crate) upgrade_strategy: UpgradeStrategy,
     pub(crate) reinstall: Reinstall,
     pub(crate) concurrency: Concurrency,
 }
@@ -1649,12 +1934,14 @@ impl PipSettings {
             no_binary,
             only_binary,
             no_build_isolation,
+            no_sources,
             strict,
             extra,
             all_extras,
             no_deps,
             resolution,
             prerelease,
+            prerelease_package,
             output_file,
             no_strip_extras,
             no_strip_markers,
@@ -1664,26 +1951,37 @@ impl PipSettings {
             generate_hashes,
             legacy_setup_py,
             config_settings,
+            config_settings_package,
             python_version,
             python_platform,
             universal,
             exclude_newer,
             no_emit_package,
+            allow_unsafe,
+            unsafe_package,
             emit_index_url,
             emit_find_links,
             emit_build_options,
             emit_marker_expression,
             emit_index_annotation,
+            keep_yanked_reason,
+            emit_build_isolation_note,
+            emit_input_hash,
+            emit_python_version,
+            emit_timestamp,
+            emit_workspace_members,
             annotation_style,
             link_mode,
             compile_bytecode,
             require_hashes,
             upgrade,
             upgrade_package,
+            upgrade_strategy,
             reinstall,
             reinstall_package,
             concurrent_builds,
             concurrent_downloads,
+            concurrent_requests,
             concurrent_installs,
         } = pip.unwrap_or_default();
 
@@ -1696,6 +1994,7 @@ impl PipSettings {
             keyring_provider: top_level_keyring_provider,
             resolution: top_level_resolution,
             prerelease: top_level_prerelease,
+            prerelease_package: top_level_prerelease_package,
             config_settings: top_level_config_settings,
             exclude_newer: top_level_exclude_newer,
             link_mode: top_level_link_mode,
@@ -1722,6 +2021,7 @@ impl PipSettings {
         let keyring_provider = keyring_provider.combine(top_level_keyring_provider);
         let resolution = resolution.combine(top_level_resolution);
         let prerelease = prerelease.combine(top_level_prerelease);
+        let prerelease_package = prerelease_package.combine(top_level_prerelease_package);
         let config_settings = config_settings.combine(top_level_config_settings);
         let exclude_newer = exclude_newer.combine(top_level_exclude_newer);
         let link_mode = link_mode.combine(top_level_link_mode);
@@ -1751,6 +2051,10 @@ impl PipSettings {
             },
             resolution: args.resolution.combine(resolution).unwrap_or_default(),
             prerelease: args.prerelease.combine(prerelease).unwrap_or_default(),
+            prerelease_package: args
+                .prerelease_package
+                .combine(prerelease_package)
+                .unwrap_or_default(),
             output_file: args.output_file.combine(output_file),
             no_strip_extras: args
                 .no_strip_extras
@@ -1792,10 +2096,17 @@ impl PipSettings {
                 .no_build_isolation
                 .combine(no_build_isolation)
                 .unwrap_or_default(),
+            sources: SourceStrategy::from_args(
+                args.no_sources.combine(no_sources).unwrap_or_default(),
+            ),
             config_setting: args
                 .config_settings
                 .combine(config_settings)
                 .unwrap_or_default(),
+            config_settings_package: args
+                .config_settings_package
+                .combine(config_settings_package)
+                .unwrap_or_default(),
             python_version: args.python_version.combine(python_version),
             python_platform: args.python_platform.combine(python_platform),
             universal: args.universal.combine(universal).unwrap_or_default(),
@@ -1804,6 +2115,28 @@ impl PipSettings {
                 .no_emit_package
                 .combine(no_emit_package)
                 .unwrap_or_default(),
+            unsafe_package: {
+                // Unless `--allow-unsafe` is set, omit the built-in unsafe packages, along with
+                // any that were explicitly marked as unsafe.
+                let mut packages = if args.allow_unsafe.combine(allow_unsafe).unwrap_or_default() {
+                    Vec::new()
+                } else {
+                    DEFAULT_UNSAFE_PACKAGES
+                        .iter()
+                        .map(|name| PackageName::from_str(name).unwrap())
+                        .collect()
+                };
+                for package in args
+                    .unsafe_package
+                    .combine(unsafe_package)
+                    .unwrap_or_default()
+                {
+                    if !packages.contains(&package) {
+                        packages.push(package);
+                    }
+                }
+                packages
+            },
             emit_index_url: args
                 .emit_index_url
                 .combine(emit_index_url)
@@ -1824,6 +2157,30 @@ impl PipSettings {
                 .emit_index_annotation
                 .combine(emit_index_annotation)
                 .unwrap_or_default(),
+            keep_yanked_reason: args
+                .keep_yanked_reason
+                .combine(keep_yanked_reason)
+                .unwrap_or_default(),
+            emit_build_isolation_note: args
+                .emit_build_isolation_note
+                .combine(emit_build_isolation_note)
+                .unwrap_or_default(),
+            emit_input_hash: args
+                .emit_input_hash
+                .combine(emit_input_hash)
+                .unwrap_or_default(),
+            emit_python_version: args
+                .emit_python_version
+                .combine(emit_python_version)
+                .unwrap_or_default(),
+            emit_timestamp: args
+                .emit_timestamp
+                .combine(emit_timestamp)
+                .unwrap_or_default(),
+            emit_workspace_members: args
+                .emit_workspace_members
+                .combine(emit_workspace_members)
+                .unwrap_or_default(),
             link_mode: args.link_mode.combine(link_mode).unwrap_or_default(),
             require_hashes: args
                 .require_hashes
@@ -1848,6 +2205,10 @@ impl PipSettings {
                     .combine(upgrade_package)
                     .unwrap_or_default(),
             ),
+            upgrade_strategy: args
+                .upgrade_strategy
+                .combine(upgrade_strategy)
+                .unwrap_or_default(),
             reinstall: Reinstall::from_args(
                 args.reinstall.combine(reinstall),
                 args.reinstall_package
@@ -1860,6 +2221,11 @@ impl PipSettings {
                     .combine(concurrent_downloads)
                     .map(NonZeroUsize::get)
                     .unwrap_or(Concurrency::DEFAULT_DOWNLOADS),
+                requests: args
+                    .concurrent_requests
+                    .combine(concurrent_requests)
+                    .map(NonZeroUsize::get)
+                    .unwrap_or(Concurrency::DEFAULT_REQUESTS),
                 builds: args
                     .concurrent_builds
                     .combine(concurrent_builds)
@@ -1898,6 +2264,7 @@ impl<'a> From<ResolverInstallerSettingsRef<'a>> for ResolverSettingsRef<'a> {
             keyring_provider: settings.keyring_provider,
             resolution: settings.resolution,
             prerelease: settings.prerelease,
+            prerelease_package: settings.prerelease_package,
             config_setting: settings.config_setting,
             exclude_newer: settings.exclude_newer,
             link_mode: settings.link_mode,
@@ -1922,11 +2289,13 @@ impl<'a> From<ResolverInstallerSettingsRef<'a>> for InstallerSettingsRef<'a> {
     }
 }
 
+/// Clamp a concurrency limit provided on the command line to a minimum of one.
+fn concurrency_limit(limit: usize) -> NonZeroUsize {
+    NonZeroUsize::new(limit).unwrap_or(NonZeroUsize::MIN)
+}
+
 // Environment variables that are not exposed as CLI arguments.
 mod env {
-    pub(super) const CONCURRENT_DOWNLOADS: (&str, &str) =
-        ("UV_CONCURRENT_DOWNLOADS", "a non-zero integer");
-
     pub(super) const CONCURRENT_BUILDS: (&str, &str) =
         ("UV_CONCURRENT_BUILDS", "a non-zero integer");
 
@@ -86,7 +86,7 @@ fn branching_urls_overlapping() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock().arg("--preview").current_dir(&context.temp_dir), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -152,7 +152,7 @@ fn root_package_splits_but_transitive_conflict() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock().arg("--preview").current_dir(&context.temp_dir), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -439,7 +439,7 @@ fn branching_urls_of_different_sources_conflict() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock().arg("--preview").current_dir(&context.temp_dir), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -167,7 +167,7 @@ fn add_git() -> Result<()> {
     // Adding with an ambiguous Git reference will fail.
     uv_snapshot!(context.filters(), context.add(&["uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@0.0.1"]).arg("--preview"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -639,7 +639,7 @@ fn add_remove_dev() -> Result<()> {
     // This should fail without --dev.
     uv_snapshot!(context.filters(), context.remove(&["anyio"]), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -799,7 +799,7 @@ fn add_remove_optional() -> Result<()> {
     // This should fail without --optional.
     uv_snapshot!(context.filters(), context.remove(&["anyio"]), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -915,7 +915,7 @@ fn add_remove_workspace() -> Result<()> {
 
     uv_snapshot!(context.filters(), add_cmd, @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
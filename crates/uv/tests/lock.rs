@@ -1630,7 +1630,7 @@ fn lock_requires_python() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2042,7 +2042,7 @@ fn lock_requires_python() -> Result<()> {
     // Note we need `--offline` otherwise we'll just fetch a 3.12 interpreter!
     uv_snapshot!(filters, context38.sync().arg("--offline"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
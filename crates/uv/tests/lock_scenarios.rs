@@ -357,7 +357,7 @@ fn fork_conflict_unsatisfiable() -> Result<()> {
         .arg("https://astral-sh.github.io/packse/0.3.29/simple-html/");
     uv_snapshot!(filters, cmd, @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -705,7 +705,7 @@ fn fork_marker_disjoint() -> Result<()> {
         .arg("https://astral-sh.github.io/packse/0.3.29/simple-html/");
     uv_snapshot!(filters, cmd, @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2071,7 +2071,7 @@ fn fork_non_local_fork_marker_direct() -> Result<()> {
         .arg("https://astral-sh.github.io/packse/0.3.29/simple-html/");
     uv_snapshot!(filters, cmd, @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2146,7 +2146,7 @@ fn fork_non_local_fork_marker_transitive() -> Result<()> {
         .arg("https://astral-sh.github.io/packse/0.3.29/simple-html/");
     uv_snapshot!(filters, cmd, @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -6,6 +6,7 @@ use std::fs;
 use std::path::PathBuf;
 
 use anyhow::{bail, Context, Result};
+use assert_cmd::prelude::*;
 use assert_fs::prelude::*;
 use indoc::indoc;
 use url::Url;
@@ -71,6 +72,48 @@ fn compile_requirements_in_annotation_line() -> Result<()> {
     Ok(())
 }
 
+/// Resolve a package with several requesters with an explicit `--annotation-style=split` flag, such
+/// that each requester is rendered on its own line.
+#[test]
+fn compile_requirements_in_annotation_split() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask==3.0.0\nmarkupsafe")?;
+
+    uv_snapshot!(context
+        .pip_compile()
+        .arg("--annotation-style=split")
+        .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --annotation-style=split requirements.in
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    flask==3.0.0
+        # via -r requirements.in
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   -r requirements.in
+        #   jinja2
+        #   werkzeug
+    werkzeug==3.0.1
+        # via flask
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
 /// Resolve a specific version of `anyio` from a `requirements.in` file on stdin
 /// when passed a path of `-`.
 #[test]
@@ -109,7 +152,7 @@ fn missing_requirements_in() {
     uv_snapshot!(context.pip_compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -264,6 +307,45 @@ dependencies = [
     Ok(())
 }
 
+/// Omit a package from the output with `--no-emit-package`, while retaining its dependencies.
+#[test]
+fn compile_no_emit_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask==3.0.2")?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-emit-package")
+            .arg("flask"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-emit-package flask
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    werkzeug==3.0.1
+        # via flask
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a package from a `requirements.in` file, with a `constraints.txt` file.
 #[test]
 fn compile_constraints_txt() -> Result<()> {
@@ -300,6 +382,109 @@ fn compile_constraints_txt() -> Result<()> {
     Ok(())
 }
 
+/// Annotate a package whose version was pinned by a `constraints.txt` file with the constraint
+/// file that forced it.
+#[test]
+fn compile_constraints_pinned_annotation() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("requests==2.31.0")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("urllib3==1.26.18")?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--annotation-style=line")
+            .arg("--constraint")
+            .arg("constraints.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --annotation-style=line --constraint constraints.txt
+    certifi==2024.2.2         # via requests
+    charset-normalizer==3.3.2  # via requests
+    idna==3.6                 # via requests
+    requests==2.31.0          # via -r requirements.in
+    urllib3==1.26.18          # via requests, -c constraints.txt
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve a local source tree with a `--build-constraint` file, which applies to the build
+/// requirements of the source tree, but not to the requirements themselves.
+#[test]
+fn compile_build_constraint() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a package without static metadata, such that it needs to be built.
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [build-system]
+        requires = ["setuptools>=40.8.0"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+    project.child("setup.py").write_str(indoc! {r#"
+        from setuptools import setup
+
+        setup(name="project", version="0.1.0", install_requires=["setuptools"])
+    "#})?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("./project")?;
+
+    // The build constraint conflicts with the build requirements, so the build fails.
+    let build_constraints_txt = context.temp_dir.child("build-constraints.txt");
+    build_constraints_txt.write_str("setuptools<40")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--build-constraint")
+            .arg("build-constraints.txt"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to build: `file://[TEMP_DIR]/project`
+      Caused by: Failed to install requirements from build-system.requires (resolve)
+      Caused by: No solution found when resolving: setuptools>=40.8.0
+      Caused by: Because you require setuptools>=40.8.0 and setuptools<40, we can conclude that your requirements are unsatisfiable.
+    "###
+    );
+
+    // The build constraint doesn't apply to the requirements themselves.
+    build_constraints_txt.write_str("setuptools==69.2.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--build-constraint")
+            .arg("build-constraints.txt")
+            .arg("--allow-unsafe"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --build-constraint build-constraints.txt --allow-unsafe
+    ./project
+        # via -r requirements.in
+    setuptools==69.2.0
+        # via project
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a package from a `requirements.in` file, with an inline constraint.
 #[test]
 fn compile_constraints_inline() -> Result<()> {
@@ -526,7 +711,7 @@ optional-dependencies.foo = [
             .arg("--extra")
             .arg("bar"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -682,7 +867,7 @@ build-backend = "poetry.core.masonry.api"
     uv_snapshot!(context.pip_compile()
             .arg("pyproject.toml"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -890,7 +1075,7 @@ dependencies = [
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("pyproject.toml"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -935,7 +1120,7 @@ optional-dependencies.foo = [
             .arg("--extra")
             .arg("foobar"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -958,7 +1143,7 @@ fn compile_requirements_file_extra() -> Result<()> {
             .arg("--all-extras"),
             @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -992,7 +1177,7 @@ optional-dependencies.foo = [
             .arg("--extra")
             .arg("invalid name!"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -1126,7 +1311,7 @@ fn compile_python_37() -> Result<()> {
             .arg("--python-version")
             .arg("3.7"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1171,6 +1356,133 @@ fn compile_sdist_resolution_lowest() -> Result<()> {
     Ok(())
 }
 
+/// Resolve with `--resolution=lowest`, to ensure that both direct and transitive dependencies are
+/// resolved to their lowest compatible versions.
+#[test]
+fn compile_resolution_lowest() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("requests>=2.31.0")?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--resolution=lowest"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --resolution=lowest
+    certifi==2017.4.17
+        # via requests
+    charset-normalizer==2.0.0
+        # via requests
+    idna==2.5
+        # via requests
+    requests==2.31.0
+        # via -r requirements.in
+    urllib3==1.21.1
+        # via requests
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve with `--resolution=lowest-direct`, to ensure that direct dependencies are resolved to
+/// their lowest compatible versions, while transitive dependencies are resolved to their highest
+/// compatible versions.
+#[test]
+fn compile_resolution_lowest_direct() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("requests>=2.31.0")?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--resolution=lowest-direct"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --resolution=lowest-direct
+    certifi==2024.2.2
+        # via requests
+    charset-normalizer==3.3.2
+        # via requests
+    idna==3.6
+        # via requests
+    requests==2.31.0
+        # via -r requirements.in
+    urllib3==2.2.1
+        # via requests
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve a direct URL requirement alongside ranged requirements with `--resolution=lowest` and
+/// `--resolution=lowest-direct`. The URL fixes the version of `anyio`, so it should be selected
+/// over the lowest version that satisfies the range.
+#[test]
+fn compile_resolution_lowest_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio @ https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl\nanyio>=4.0.0\nidna>=3.0")?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--resolution=lowest"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --resolution=lowest
+    anyio @ https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl
+        # via -r requirements.in
+    idna==3.0
+        # via
+        #   -r requirements.in
+        #   anyio
+    sniffio==1.1.0
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--resolution=lowest-direct"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --resolution=lowest-direct
+    anyio @ https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl
+        # via -r requirements.in
+    idna==3.0
+        # via
+        #   -r requirements.in
+        #   anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a specific version of Black against an invalid Python version.
 #[test]
 fn compile_python_invalid_version() -> Result<()> {
@@ -1183,7 +1495,7 @@ fn compile_python_invalid_version() -> Result<()> {
             .arg("--python-version")
             .arg("3.7.x"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -1208,7 +1520,7 @@ fn compile_python_dev_version() -> Result<()> {
             .arg("--python-version")
             .arg("3.7-dev"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -1662,7 +1974,7 @@ fn compile_git_mismatched_name() -> Result<()> {
     uv_snapshot!(context.pip_compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1754,12 +2066,14 @@ fn conflicting_direct_url_dependency() -> Result<()> {
     uv_snapshot!(context.pip_compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
       × No solution found when resolving dependencies:
       ╰─▶ Because there is no version of werkzeug==3.0.0 and you require werkzeug==3.0.0, we can conclude that the requirements are unsatisfiable.
+
+          hint: werkzeug was requested with a direct URL, but the version at that URL (2.0.0) does not satisfy werkzeug==3.0.0
     "###
     );
 
@@ -1802,7 +2116,7 @@ fn conflicting_repeated_url_dependency_version_mismatch() -> Result<()> {
     uv_snapshot!(context.pip_compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1857,7 +2171,7 @@ fn conflicting_repeated_url_dependency_version_match() -> Result<()> {
     uv_snapshot!(context.pip_compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1880,7 +2194,7 @@ fn conflicting_transitive_url_dependency() -> Result<()> {
     uv_snapshot!(context.pip_compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1935,7 +2249,7 @@ fn conflicting_repeated_url_dependency() -> Result<()> {
     uv_snapshot!(context.pip_compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2056,7 +2370,7 @@ fn incompatible_narrowed_url_dependency() -> Result<()> {
     uv_snapshot!(context.pip_compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2227,12 +2541,14 @@ fn requirement_constraint_override_url() -> Result<()> {
         .arg("--override")
         .arg("overrides.txt"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
       × No solution found when resolving dependencies:
       ╰─▶ Because there is no version of anyio==3.7.0 and you require anyio==3.7.0, we can conclude that the requirements are unsatisfiable.
+
+          hint: anyio was requested with a direct URL, but the version at that URL (4.3.0) does not satisfy anyio==3.7.0
     "###
     );
 
@@ -2415,7 +2731,7 @@ optional-dependencies.bar = [
             .arg("foo"),
             @r###"
     success: false
-    exit_code: 2
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -2449,7 +2765,7 @@ dependencies = ["anyio==3.7.0", "anyio==4.0.0"]
     uv_snapshot!(context.pip_compile()
             .arg("pyproject.toml"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2481,7 +2797,7 @@ dependencies = ["anyio==300.1.4"]
     uv_snapshot!(context.pip_compile()
             .arg("pyproject.toml"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2550,7 +2866,7 @@ fn compile_exclude_newer() -> Result<()> {
         .arg("--exclude-newer")
         .arg("2022-04-04+02:00"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -2877,7 +3193,7 @@ fn compile_wheel_path_dependency_missing() -> Result<()> {
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2913,6 +3229,67 @@ fn compile_yanked_version_direct() -> Result<()> {
     Ok(())
 }
 
+/// Resolve a yanked version of `attrs` by specifying the version directly, annotating the output
+/// with the reason it was yanked.
+#[test]
+fn compile_yanked_version_keep_yanked_reason() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("attrs==21.1.0")?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--keep-yanked-reason"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --keep-yanked-reason
+    attrs==21.1.0
+        # via -r requirements.in
+        # YANKED: Installable but not importable on Python 3.4.
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: `attrs==21.1.0` is yanked (reason: "Installable but not importable on Python 3.4.").
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve a source distribution alongside its wheel-only dependencies, annotating the output
+/// with a note for the package that was built from source.
+#[test]
+fn compile_emit_build_isolation_note() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio @ https://files.pythonhosted.org/packages/2d/b8/7333d87d5f03247215d86a86362fd3e324111788c6cdd8d2e6196a6ba833/anyio-4.2.0.tar.gz")?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--emit-build-isolation-note"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --emit-build-isolation-note
+    anyio @ https://files.pythonhosted.org/packages/2d/b8/7333d87d5f03247215d86a86362fd3e324111788c6cdd8d2e6196a6ba833/anyio-4.2.0.tar.gz
+        # via -r requirements.in
+        # built from source
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Fail to resolve `attrs` due to the indirect use of a yanked version (`21.1.0`).
 #[test]
 fn compile_yanked_version_indirect() -> Result<()> {
@@ -2923,7 +3300,7 @@ fn compile_yanked_version_indirect() -> Result<()> {
     uv_snapshot!(context.pip_compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -3179,7 +3556,7 @@ fn override_dependency_from_workspace_invalid_syntax() -> Result<()> {
             .current_dir(&context.temp_dir)
             , @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3545,7 +3922,7 @@ fn error_missing_unnamed_env_var() -> Result<()> {
     uv_snapshot!(context.pip_compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4729,7 +5106,7 @@ fn missing_path_requirement() -> Result<()> {
     uv_snapshot!(filters, context.pip_compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4749,7 +5126,7 @@ fn missing_editable_file() -> Result<()> {
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4770,7 +5147,7 @@ fn missing_editable_directory() -> Result<()> {
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4948,12 +5325,32 @@ fn custom_compile_command() -> Result<()> {
     Ok(())
 }
 
-/// Emit warnings when users pass redundant options from `pip-compile`.
+/// Omit "unsafe" packages from the output by default, and include them with `--allow-unsafe`.
 #[test]
 fn allow_unsafe() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("werkzeug==3.0.1")?;
+    requirements_in.write_str("setuptools-scm==8.0.1")?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    packaging==24.0
+        # via setuptools-scm
+    setuptools-scm==8.0.1
+        # via -r requirements.in
+
+    # The following packages are considered to be unsafe in a requirements file:
+    # setuptools
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
 
     uv_snapshot!(context.pip_compile()
             .arg("requirements.in")
@@ -4963,14 +5360,104 @@ fn allow_unsafe() -> Result<()> {
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --allow-unsafe
-    markupsafe==2.1.5
-        # via werkzeug
-    werkzeug==3.0.1
+    packaging==24.0
+        # via setuptools-scm
+    setuptools==69.2.0
+        # via setuptools-scm
+    setuptools-scm==8.0.1
         # via -r requirements.in
 
     ----- stderr -----
-    warning: pip-compile's `--allow-unsafe` has no effect (uv can safely pin `pip` and other packages).
-    Resolved 2 packages in [TIME]
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Respect additional "unsafe" packages from the configuration file.
+#[test]
+fn unsafe_package_configuration() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("setuptools-scm==8.0.1")?;
+
+    let config = context.temp_dir.child("uv.toml");
+    config.write_str(indoc! {r#"
+        [pip]
+        unsafe-package = ["packaging"]
+    "#})?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    setuptools-scm==8.0.1
+        # via -r requirements.in
+
+    # The following packages are considered to be unsafe in a requirements file:
+    # setuptools
+    # packaging
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Omit packages passed via `--unsafe-package`, even when `--allow-unsafe` is set.
+#[test]
+fn unsafe_package_allow_unsafe() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("setuptools-scm==8.0.1")?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--unsafe-package")
+            .arg("packaging"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --unsafe-package packaging
+    setuptools-scm==8.0.1
+        # via -r requirements.in
+
+    # The following packages are considered to be unsafe in a requirements file:
+    # setuptools
+    # packaging
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--unsafe-package")
+            .arg("packaging")
+            .arg("--allow-unsafe"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --unsafe-package packaging --allow-unsafe
+    setuptools==69.2.0
+        # via setuptools-scm
+    setuptools-scm==8.0.1
+        # via -r requirements.in
+
+    # The following packages are considered to be unsafe in a requirements file:
+    # packaging
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
     "###
     );
 
@@ -4988,7 +5475,7 @@ fn resolver_legacy() -> Result<()> {
             .arg("requirements.in")
             .arg("--resolver=legacy"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5043,6 +5530,38 @@ fn emit_index_urls() -> Result<()> {
     Ok(())
 }
 
+/// Emit `--no-index` alongside the `--find-links` locations, such that the output can be installed
+/// without any additional flags.
+#[test]
+fn emit_no_index() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("-f https://download.pytorch.org/whl/torch_stable.html\ntqdm")?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-index")
+            .arg("--emit-index-url")
+            .arg("--emit-find-links"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-index --emit-index-url --emit-find-links
+    --no-index
+    --find-links https://download.pytorch.org/whl/torch_stable.html
+
+    tqdm==4.64.1
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Emit the `--find-links` locations.
 #[test]
 fn emit_find_links() -> Result<()> {
@@ -5136,7 +5655,7 @@ fn no_index_requirements_txt() -> Result<()> {
     uv_snapshot!(context.pip_compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -5194,7 +5713,7 @@ fn conflicting_index_urls_requirements_txt() -> Result<()> {
             .arg("--constraint")
             .arg("constraints.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5245,7 +5764,7 @@ fn offline_registry() -> Result<()> {
             .arg("requirements.in")
             .arg("--offline"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -5376,7 +5895,7 @@ fn offline_find_links() -> Result<()> {
             .arg("https://download.pytorch.org/whl/torch_stable.html")
             .arg("--offline"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -5395,7 +5914,7 @@ fn offline_find_links() -> Result<()> {
             .arg("--no-index")
             .arg("--offline"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -5421,7 +5940,7 @@ fn offline_direct_url() -> Result<()> {
             .arg("requirements.in")
             .arg("--offline"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -5466,41 +5985,161 @@ fn offline_direct_url() -> Result<()> {
     Ok(())
 }
 
-/// Resolve a package with invalid metadata, by way of an invalid `Requires-Python` field in the
-/// `METADATA` file.
+/// Re-fetch index metadata via the `--refresh` flag, ignoring any cached responses.
 #[test]
-fn invalid_metadata_requires_python() -> Result<()> {
+fn refresh() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("validation==2.0.0")?;
+    requirements_in.write_str("iniconfig==2.0.0\ntomli==2.0.1")?;
 
-    // `2.0.0` has invalid metadata.
+    // Populate the cache.
     uv_snapshot!(context.pip_compile()
-            .arg("requirements.in")
-            .arg("--no-index")
-            .arg("--find-links")
-            .arg(context.workspace_root.join("scripts").join("links")), @r###"
-    success: false
-    exit_code: 1
+            .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    iniconfig==2.0.0
+        # via -r requirements.in
+    tomli==2.0.1
+        # via -r requirements.in
 
     ----- stderr -----
-      × No solution found when resolving dependencies:
-      ╰─▶ Because validation==2.0.0 has invalid metadata and you require validation==2.0.0, we can conclude that the requirements are unsatisfiable.
-
-          hint: Metadata for validation==2.0.0 could not be parsed:
-            Failed to parse version: Unexpected end of version specifier, expected operator:
-            12
-            ^^
-
+    Resolved 2 packages in [TIME]
     "###
     );
 
-    Ok(())
-}
+    // Overwrite the cached index response for `tomli` with that of `iniconfig`.
+    let simple = context.cache_dir.child("simple-v9").child("pypi");
+    fs::copy(simple.child("iniconfig.rkyv"), simple.child("tomli.rkyv"))?;
 
-/// Resolve a package with multiple `.dist-info` directories.
-#[test]
+    // Without `--refresh`, the (still fresh) cached response is used, and `tomli==2.0.1` can't be
+    // found.
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .assert()
+        .failure();
+
+    // With `--refresh`, the index is queried again.
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--refresh"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --refresh
+    iniconfig==2.0.0
+        # via -r requirements.in
+    tomli==2.0.1
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Re-fetch index metadata for a single package via the `--refresh-package` flag.
+#[test]
+fn refresh_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==2.0.0\ntomli==2.0.1")?;
+
+    // Populate the cache.
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    iniconfig==2.0.0
+        # via -r requirements.in
+    tomli==2.0.1
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    // Overwrite the cached index response for `tomli` with that of `iniconfig`.
+    let simple = context.cache_dir.child("simple-v9").child("pypi");
+    fs::copy(simple.child("iniconfig.rkyv"), simple.child("tomli.rkyv"))?;
+
+    // Refreshing an unrelated package continues to use the cached response for `tomli`.
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--refresh-package")
+        .arg("iniconfig")
+        .assert()
+        .failure();
+
+    // Refreshing `tomli` queries the index again.
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--refresh-package")
+            .arg("tomli"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --refresh-package tomli
+    iniconfig==2.0.0
+        # via -r requirements.in
+    tomli==2.0.1
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve a package with invalid metadata, by way of an invalid `Requires-Python` field in the
+/// `METADATA` file.
+#[test]
+fn invalid_metadata_requires_python() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("validation==2.0.0")?;
+
+    // `2.0.0` has invalid metadata.
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("scripts").join("links")), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because validation==2.0.0 has invalid metadata and you require validation==2.0.0, we can conclude that the requirements are unsatisfiable.
+
+          hint: Metadata for validation==2.0.0 could not be parsed:
+            Failed to parse version: Unexpected end of version specifier, expected operator:
+            12
+            ^^
+
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve a package with multiple `.dist-info` directories.
+#[test]
 fn invalid_metadata_multiple_dist_info() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
@@ -5513,7 +6152,7 @@ fn invalid_metadata_multiple_dist_info() -> Result<()> {
             .arg("--find-links")
             .arg(context.workspace_root.join("scripts").join("links")), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -5766,6 +6405,36 @@ fn compile_constraints_compatible_url_version() -> Result<()> {
     Ok(())
 }
 
+/// Resolve a direct URL package from a `requirements.in` file, with a `constraints.txt` file
+/// that excludes the version at that URL.
+#[test]
+fn compile_constraints_incompatible_url_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio @ https://files.pythonhosted.org/packages/bf/cd/d6d9bb1dadf73e7af02d18225cbd2c93f8552e13130484f1c8dcfece292b/anyio-4.2.0-py3-none-any.whl")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("anyio<4")?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.txt"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because there is no version of anyio<4 and you require anyio<4, we can conclude that the requirements are unsatisfiable.
+
+          hint: anyio was requested with a direct URL, but the version at that URL (4.2.0) does not satisfy anyio<4
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a package from a `requirements.in` file, with a `constraints.txt` file pinning it to
 /// a specific URL with an incompatible version.
 #[test]
@@ -5782,7 +6451,7 @@ fn compile_constraints_incompatible_url() -> Result<()> {
             .arg("--constraint")
             .arg("constraints.txt"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -5805,7 +6474,7 @@ fn index_url_in_requirements() -> Result<()> {
     uv_snapshot!(context.pip_compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -5860,7 +6529,7 @@ fn unsupported_scheme() -> Result<()> {
     uv_snapshot!(context.pip_compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -6023,6 +6692,43 @@ fn editable_invalid_extra() -> Result<()> {
     Ok(())
 }
 
+/// Resolve a package with `--format requirements-with-extras`, which retains extras on each pin.
+#[test]
+fn format_requirements_with_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("requests[socks]==2.31.0")?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--format")
+            .arg("requirements-with-extras"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --format requirements-with-extras
+    certifi==2024.2.2
+        # via requests
+    charset-normalizer==3.3.2
+        # via requests
+    idna==3.6
+        # via requests
+    pysocks==1.7.1
+        # via requests
+    requests[socks]==2.31.0
+        # via -r requirements.in
+    urllib3==2.2.1
+        # via requests
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a package with `--no-strip-extras`.
 #[test]
 fn no_strip_extra() -> Result<()> {
@@ -6203,7 +6909,7 @@ fn no_strip_markers() -> Result<()> {
 fn no_strip_markers_multiple_markers() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         trio ; python_version > '3.11'
         trio ; sys_platform == 'win32'
     "})?;
@@ -6295,7 +7001,7 @@ fn no_strip_markers_transitive_marker() -> Result<()> {
 fn universal() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         trio ; python_version > '3.11'
         trio ; sys_platform == 'win32'
     "})?;
@@ -6340,7 +7046,7 @@ fn universal() -> Result<()> {
 fn universal_conflicting() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         trio==0.25.0 ; sys_platform == 'darwin'
         trio==0.10.0 ; sys_platform == 'win32'
     "})?;
@@ -6389,7 +7095,7 @@ fn universal_conflicting() -> Result<()> {
 fn universal_cycles() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         testtools==2.3.0
         fixtures==3.0.0
     "})?;
@@ -6447,7 +7153,7 @@ fn universal_cycles() -> Result<()> {
 fn universal_constraint() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         anyio ; sys_platform == 'win32'
     "})?;
 
@@ -6488,7 +7194,7 @@ fn universal_constraint() -> Result<()> {
 fn universal_constraint_marker() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         anyio ; sys_platform == 'win32'
     "})?;
 
@@ -6534,7 +7240,7 @@ fn universal_constraint_marker() -> Result<()> {
 fn universal_multi_version() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         iniconfig
         iniconfig==2.0.0 ; python_version > '3.12'
         iniconfig==1.0.0 ; python_version == '3.12'
@@ -6551,7 +7257,7 @@ fn universal_multi_version() -> Result<()> {
             .arg("constraints.txt")
             .arg("--universal"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -6619,7 +7325,7 @@ fn compile_constraints_incompatible_version() -> Result<()> {
             .arg("--constraint")
             .arg("constraints.txt"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -6631,6 +7337,72 @@ fn compile_constraints_incompatible_version() -> Result<()> {
     Ok(())
 }
 
+/// Resolve a `requirements.in` file against the pins of another compiled lockfile, such that any
+/// shared packages agree across the two.
+#[test]
+fn compile_constraint_from_lock() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let other_lock = context.temp_dir.child("other.txt");
+    other_lock.write_str(indoc! {r"
+        anyio==3.7.0
+            # via -r other.in
+        idna==3.4
+            # via anyio
+        sniffio==1.3.0
+            # via anyio
+    "})?;
+
+    // `idna` is shared with the other lockfile, and so should be pinned to the same version; the
+    // remaining pins are not included in the output.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("requests==2.31.0")?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint-from-lock")
+            .arg("other.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint-from-lock other.txt
+    certifi==2024.2.2
+        # via requests
+    charset-normalizer==3.3.2
+        # via requests
+    idna==3.4
+        # via requests
+    requests==2.31.0
+        # via -r requirements.in
+    urllib3==2.2.1
+        # via requests
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###
+    );
+
+    // A requirement that disagrees with the other lockfile should be reported as a conflict.
+    requirements_in.write_str("idna==3.6")?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint-from-lock")
+            .arg("other.txt"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because you require idna==3.6 and idna==3.4, we can conclude that the requirements are unsatisfiable.
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a package from a `requirements.in` file, with a `constraints.txt` file pinning one of
 /// its direct dependencies to an incompatible version.
 #[test]
@@ -6647,7 +7419,7 @@ fn conflicting_url_markers() -> Result<()> {
             .arg("--constraint")
             .arg("constraints.txt"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -6801,7 +7573,7 @@ fn override_with_incompatible_constraint() -> Result<()> {
             .arg("--override")
             .arg("overrides.txt"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -6846,7 +7618,7 @@ fn unsafe_package() -> Result<()> {
     werkzeug==3.0.1
         # via flask
 
-    # The following packages were excluded from the output:
+    # The following packages are considered to be unsafe in a requirements file:
     # jinja2
 
     ----- stderr -----
@@ -6969,31 +7741,25 @@ fn pre_alias() -> Result<()> {
     Ok(())
 }
 
-/// Allow a pre-release for a version specifier in a constraint file.
+/// Allow pre-releases for a single package via `--pre=<package>`.
 #[test]
-fn pre_release_constraint() -> Result<()> {
+fn pre_package() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("flask")?;
-
-    let constraints_txt = context.temp_dir.child("constraints.txt");
-    constraints_txt.write_str("flask<=2.0.0rc2")?;
+    requirements_in.write_str("flask<2.0.0rc4")?;
 
     uv_snapshot!(context.pip_compile()
             .arg("requirements.in")
-            .arg("--constraint")
-            .arg("constraints.txt"), @r###"
+            .arg("--pre=flask"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint constraints.txt
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --pre=flask
     click==8.1.7
         # via flask
     flask==2.0.0rc2
-        # via
-        #   -c constraints.txt
-        #   -r requirements.in
+        # via -r requirements.in
     itsdangerous==2.1.2
         # via flask
     jinja2==3.1.3
@@ -7013,27 +7779,107 @@ fn pre_release_constraint() -> Result<()> {
     Ok(())
 }
 
-/// Resolve from a `pyproject.toml` file with a mutually recursive extra.
+/// `--pre=<package>` should not allow pre-releases for any other package.
 #[test]
-fn compile_pyproject_toml_mutually_recursive_extra() -> Result<()> {
+fn pre_package_other() -> Result<()> {
     let context = TestContext::new("3.12");
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
-[project]
-name = "project"
-version = "0.0.1"
-dependencies = [
-    "anyio"
-]
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask<2.0.0rc4")?;
 
-[project.optional-dependencies]
-test = [
-    "iniconfig",
-    "project[dev]"
-]
-dev = [
-    "project[test]",
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--pre=werkzeug"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --pre=werkzeug
+    click==7.1.2
+        # via flask
+    flask==1.1.4
+        # via -r requirements.in
+    itsdangerous==1.1.0
+        # via flask
+    jinja2==2.11.3
+        # via flask
+    markupsafe==2.1.5
+        # via jinja2
+    werkzeug==1.0.1
+        # via flask
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Allow a pre-release for a version specifier in a constraint file.
+#[test]
+fn pre_release_constraint() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("flask<=2.0.0rc2")?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint constraints.txt
+    click==8.1.7
+        # via flask
+    flask==2.0.0rc2
+        # via
+        #   -c constraints.txt
+        #   -r requirements.in
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    werkzeug==3.0.1
+        # via flask
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve from a `pyproject.toml` file with a mutually recursive extra.
+#[test]
+fn compile_pyproject_toml_mutually_recursive_extra() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+[project]
+name = "project"
+version = "0.0.1"
+dependencies = [
+    "anyio"
+]
+
+[project.optional-dependencies]
+test = [
+    "iniconfig",
+    "project[dev]"
+]
+dev = [
+    "project[test]",
 ]
 "#,
     )?;
@@ -7328,7 +8174,7 @@ requires-python = "<=3.8"
     uv_snapshot!(context.filters(), context.pip_compile()
         .arg("requirements.in"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -7380,7 +8226,7 @@ requires-python = "<=3.8"
         .arg("requirements.in")
         .arg("--python-version=3.11"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -7478,11 +8324,12 @@ dev = ["setuptools"]
         # via -r requirements.in
     packaging==24.0
         # via setuptools-scm
-    setuptools==69.2.0
-        # via setuptools-scm
     setuptools-scm==8.0.1
         # via example
 
+    # The following packages are considered to be unsafe in a requirements file:
+    # setuptools
+
     ----- stderr -----
     Resolved 4 packages in [TIME]
     "###
@@ -7569,7 +8416,7 @@ fn not_found_direct_url() -> Result<()> {
     uv_snapshot!(context.pip_compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -7608,7 +8455,7 @@ requires-python = "<=3.8"
     uv_snapshot!(context.filters(), context.pip_compile()
         .arg("requirements.in"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -7696,7 +8543,7 @@ fn requirement_wheel_name_mismatch() -> Result<()> {
     uv_snapshot!(context.pip_compile()
         .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -8370,6 +9217,55 @@ fn emit_marker_expression_pypy() -> Result<()> {
     Ok(())
 }
 
+/// In universal mode, the emitted marker expression reflects the minimum Python version.
+#[test]
+fn emit_marker_expression_universal() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    uv_snapshot!(context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--universal")
+        .arg("--python-version")
+        .arg("3.8")
+        .arg("--emit-marker-expression"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --universal --python-version 3.8 --emit-marker-expression
+    # Pinned dependencies known to be valid for:
+    #    python_version >= '3.8'
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###);
+
+    // Without a minimum Python version, no marker expression is emitted.
+    uv_snapshot!(context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--universal")
+        .arg("--emit-marker-expression"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --universal --emit-marker-expression
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###);
+
+    Ok(())
+}
+
 /// A local version of a package shadowing a remote package is installed.
 #[test]
 fn local_version_of_remote_package() -> Result<()> {
@@ -8743,7 +9639,7 @@ requires-python = ">3.8"
         .arg("--override")
         .arg("overrides.txt"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -8916,7 +9812,7 @@ fn compile_index_url_first_match() -> Result<()> {
         .arg("requirements.in")
         .arg("--no-deps"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -8928,6 +9824,43 @@ fn compile_index_url_first_match() -> Result<()> {
     Ok(())
 }
 
+/// Install a package via `--extra-index-url`, with an explicit `--index-strategy first-match`.
+///
+/// In this case, anyio 3.5.0 is hosted on the "extra" index, and newer versions are available on
+/// the "primary" index. Since the "extra" index is searched first, only its versions are
+/// considered, unlike with `unsafe-best-match` (see `compile_index_url_unsafe_highest`).
+#[test]
+fn compile_index_url_first_match_explicit() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio")?;
+
+    uv_snapshot!(context.pip_compile()
+        .arg("--index-strategy")
+        .arg("first-match")
+        .arg("--index-url")
+        .arg("https://pypi.org/simple")
+        .arg("--extra-index-url")
+        .arg("https://test.pypi.org/simple")
+        .arg("requirements.in")
+        .arg("--no-deps"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --index-strategy first-match requirements.in --no-deps
+    anyio==3.5.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Install a package via `--extra-index-url`.
 ///
 /// If the package exists exist on the "extra" index, but at an incompatible version, the
@@ -9038,6 +9971,7 @@ fn compile_index_url_unsafe_highest() -> Result<()> {
         # via -r requirements.in
 
     ----- stderr -----
+    warning: `--index-strategy unsafe-best-match` selects the best version of each package across all indexes. A package published to any index with a matching name may take precedence over the version from your intended index, exposing the resolution to dependency confusion attacks.
     Resolved 1 package in [TIME]
     "###
     );
@@ -9080,6 +10014,7 @@ fn compile_index_url_unsafe_lowest() -> Result<()> {
         # via -r requirements.in
 
     ----- stderr -----
+    warning: `--index-strategy unsafe-best-match` selects the best version of each package across all indexes. A package published to any index with a matching name may take precedence over the version from your intended index, exposing the resolution to dependency confusion attacks.
     Resolved 1 package in [TIME]
     "###
     );
@@ -9291,7 +10226,7 @@ fn no_version_for_direct_dependency() -> Result<()> {
         // Must error before we make any network requests
         .arg("--offline"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -9374,6 +10309,53 @@ fn python_platform() -> Result<()> {
     Ok(())
 }
 
+/// Compile against a marker environment with overridden fields, e.g., to target PyPy from CPython.
+#[test]
+fn marker_override() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        iniconfig==2.0.0 ; implementation_name == 'pypy'
+        idna==3.6 ; implementation_name == 'cpython'
+    "})?;
+
+    uv_snapshot!(context.pip_compile()
+        .arg("requirements.in")
+        .arg("--marker")
+        .arg("implementation_name=pypy"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --marker implementation_name=pypy
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // Unknown keys are rejected, listing the accepted markers.
+    uv_snapshot!(context.pip_compile()
+        .arg("requirements.in")
+        .arg("--marker")
+        .arg("implementation=pypy"), @r###"
+    success: false
+    exit_code: 4
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'implementation=pypy' for '--marker <KEY=VALUE>': Unknown marker: `implementation` (expected one of: implementation_name, implementation_version, os_name, platform_machine, platform_python_implementation, platform_release, platform_system, platform_version, python_full_version, python_version, sys_platform)
+
+    For more information, try '--help'.
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a specific source distribution via a Git HTTPS dependency.
 #[test]
 #[cfg(feature = "git")]
@@ -9626,7 +10608,7 @@ fn git_source_missing_tag() -> Result<()> {
             .arg("--preview")
             .arg("pyproject.toml"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -9686,6 +10668,47 @@ fn warn_missing_constraint() -> Result<()> {
     Ok(())
 }
 
+/// Ignore `tool.uv.sources` with `--no-sources`, resolving all requirements from the index.
+#[test]
+fn no_sources() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "foo"
+        version = "0.0.0"
+        dependencies = [
+          "anyio==4.3.0",
+        ]
+
+        [tool.uv.sources]
+        anyio = { url = "https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl" }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("--preview")
+            .arg("pyproject.toml")
+            .arg("--no-sources"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --preview pyproject.toml --no-sources
+    anyio==4.3.0
+        # via foo (pyproject.toml)
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
 /// Ensure that this behavior is constraint to preview mode.
 #[test]
 fn dont_warn_missing_constraint_without_sources() -> Result<()> {
@@ -9878,7 +10901,7 @@ fn no_binary_only_binary() -> Result<()> {
         .arg("--only-binary")
         .arg(":all:"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -9946,3 +10969,836 @@ fn ignore_invalid_constraint() -> Result<()> {
 
     Ok(())
 }
+
+/// Verify that an existing output file is up-to-date via `--verify`, without overwriting it.
+#[test]
+fn verify() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    uv_snapshot!(context.pip_compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
+    anyio==3.7.0
+        # via -r requirements.in
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    // The output file is up-to-date.
+    uv_snapshot!(context.pip_compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .arg("--verify"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    // Change the input requirements, such that the output file is out-of-date.
+    requirements_in.write_str("anyio==4.0.0")?;
+
+    uv_snapshot!(context.pip_compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .arg("--verify"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    error: The output file at `requirements.txt` is not up-to-date with the input requirements:
+    -anyio==3.7.0
+    +anyio==4.0.0
+    "###
+    );
+
+    // The output file should be unchanged.
+    let requirements_txt = fs_err::read_to_string(context.temp_dir.child("requirements.txt"))?;
+    assert!(requirements_txt.contains("anyio==3.7.0"));
+
+    Ok(())
+}
+
+/// Overwrite an existing output file, keeping a backup of its previous contents.
+#[test]
+fn backup() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==3.0.0\n")?;
+
+    uv_snapshot!(context.pip_compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .arg("--backup"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
+    anyio==3.7.0
+        # via -r requirements.in
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    // The previous contents should be retained in the backup file.
+    context
+        .temp_dir
+        .child("requirements.txt.bak")
+        .assert("anyio==3.0.0\n");
+    assert!(fs_err::read_to_string(requirements_txt.path())?.contains("anyio==3.7.0"));
+
+    // The output file is replaced atomically, so no temporary files should be left behind.
+    let leftovers = fs_err::read_dir(context.temp_dir.path())?
+        .map(|entry| entry.map(|entry| entry.file_name()))
+        .collect::<std::io::Result<Vec<_>>>()?
+        .into_iter()
+        .filter(|name| name.to_string_lossy().starts_with(".tmp"))
+        .collect::<Vec<_>>();
+    assert!(leftovers.is_empty(), "{leftovers:?}");
+
+    Ok(())
+}
+
+/// Record a hash of the inputs in the header, and validate it with `--check-input-hash`.
+#[test]
+fn input_hash() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    let filters = [(r"sha256:[0-9a-f]{64}", "sha256:[HASH]")]
+        .into_iter()
+        .chain(context.filters())
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.pip_compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .arg("--emit-input-hash"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt --emit-input-hash
+    # input-hash: sha256:[HASH]
+    anyio==3.7.0
+        # via -r requirements.in
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    // The inputs are unchanged, so the check passes without resolving.
+    uv_snapshot!(context.pip_compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .arg("--check-input-hash"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###
+    );
+
+    // Targeting a different Python version changes the inputs.
+    uv_snapshot!(context.pip_compile()
+        .arg("requirements.in")
+        .arg("--python-version")
+        .arg("3.11")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .arg("--check-input-hash"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The input requirements have changed since `requirements.txt` was generated
+    "###
+    );
+
+    // Adding a constraint changes the inputs.
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("idna<3.4")?;
+
+    uv_snapshot!(context.pip_compile()
+        .arg("requirements.in")
+        .arg("--constraint")
+        .arg("constraints.txt")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .arg("--check-input-hash"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The input requirements have changed since `requirements.txt` was generated
+    "###
+    );
+
+    // An output file without an input hash can't be validated.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==3.7.0\n")?;
+
+    uv_snapshot!(context.pip_compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .arg("--check-input-hash"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The output file at `requirements.txt` does not include an input hash (generate it with `--emit-input-hash`)
+    "###
+    );
+
+    Ok(())
+}
+
+/// Editing the dependencies of a `pyproject.toml` input should change the input hash.
+#[test]
+fn input_hash_source_tree() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        dependencies = ["anyio==3.7.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    let filters = [(r"sha256:[0-9a-f]{64}", "sha256:[HASH]")]
+        .into_iter()
+        .chain(context.filters())
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.pip_compile()
+        .arg("pyproject.toml")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .arg("--emit-input-hash"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --output-file requirements.txt --emit-input-hash
+    # input-hash: sha256:[HASH]
+    anyio==3.7.0
+        # via project (pyproject.toml)
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    // The `pyproject.toml` is unchanged, so the check passes.
+    uv_snapshot!(context.pip_compile()
+        .arg("pyproject.toml")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .arg("--check-input-hash"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###
+    );
+
+    // Changing a dependency in the `pyproject.toml` changes the inputs.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        dependencies = ["anyio==4.0.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    uv_snapshot!(context.pip_compile()
+        .arg("pyproject.toml")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .arg("--check-input-hash"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The input requirements have changed since `requirements.txt` was generated
+    "###
+    );
+
+    Ok(())
+}
+
+/// Warn about, rather than reject, unsupported `pip-compile` options with `--ignore-unknown-flags`.
+#[test]
+fn ignore_unknown_flags() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    uv_snapshot!(context.pip_compile()
+        .arg("requirements.in")
+        .arg("--pip-args=--no-cache-dir")
+        .arg("--max-rounds")
+        .arg("10"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: pip-compile's `--max-rounds` is unsupported (uv always resolves until convergence).
+    "###
+    );
+
+    uv_snapshot!(context.pip_compile()
+        .arg("requirements.in")
+        .arg("--pip-args=--no-cache-dir")
+        .arg("--max-rounds")
+        .arg("10")
+        .arg("--ignore-unknown-flags"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --pip-args=--no-cache-dir --max-rounds 10 --ignore-unknown-flags
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    warning: pip-compile's `--max-rounds` is unsupported (uv always resolves until convergence). Ignoring due to `--ignore-unknown-flags`.
+    warning: pip-compile's `--pip-args` is unsupported (try passing arguments to uv directly). Ignoring due to `--ignore-unknown-flags`.
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // Options that affect how uv connects to the index are always rejected.
+    uv_snapshot!(context.pip_compile()
+        .arg("requirements.in")
+        .arg("--trusted-host")
+        .arg("example.com")
+        .arg("--ignore-unknown-flags"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: pip-compile's `--trusted-host` is unsupported (uv always requires HTTPS).
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include the targeted Python version in the header with `--emit-python-version`.
+#[test]
+fn emit_python_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    // The version is taken from the interpreter.
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--emit-python-version"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --emit-python-version
+    # python-version: 3.12
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // The version is taken from `--python-version`.
+    let filters: Vec<_> = [
+        // 3.11 may not be installed
+        (
+            "warning: The requested Python version 3.11 is not available; .* will be used to build dependencies instead.\n",
+            "",
+        ),
+    ]
+    .into_iter()
+    .chain(context.filters())
+    .collect();
+
+    uv_snapshot!(filters, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--emit-python-version")
+            .arg("--python-version")
+            .arg("3.11"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --emit-python-version --python-version 3.11
+    # python-version: 3.11
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve with `--emit-timestamp`, to include the generation time in the header. By default, the
+/// output should not include a timestamp.
+#[test]
+fn emit_timestamp() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    let filters: Vec<_> = [(
+        r"# generated-at: \d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}Z",
+        "# generated-at: [TIMESTAMP]",
+    )]
+    .into_iter()
+    .chain(context.filters())
+    .collect();
+
+    uv_snapshot!(filters, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--emit-timestamp"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --emit-timestamp
+    # generated-at: [TIMESTAMP]
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // The timestamp is ignored when verifying the output file.
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .arg("--emit-timestamp")
+        .assert()
+        .success();
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    let contents = fs_err::read_to_string(&requirements_txt)?
+        .lines()
+        .map(|line| {
+            if line.starts_with("# generated-at: ") {
+                "# generated-at: 2024-01-01T00:00:00Z"
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    requirements_txt.write_str(&format!("{contents}\n"))?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--emit-timestamp")
+            .arg("--verify"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve the members of a workspace with `--emit-workspace-members`, to list the members that
+/// contributed requirements in the header.
+#[test]
+fn emit_workspace_members() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [tool.uv.workspace]
+        members = ["packages/*"]
+    "#})?;
+    context
+        .temp_dir
+        .child("packages/foo/pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+    context
+        .temp_dir
+        .child("packages/bar/pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "bar"
+        version = "0.1.0"
+        dependencies = ["anyio==3.7.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("packages/foo/pyproject.toml")
+            .arg("packages/bar/pyproject.toml")
+            .arg("--emit-workspace-members"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] packages/foo/pyproject.toml packages/bar/pyproject.toml --emit-workspace-members
+    # workspace-members:
+    #    bar (packages/bar)
+    #    foo (packages/foo)
+    anyio==3.7.0
+        # via bar (packages/bar/pyproject.toml)
+    idna==3.6
+        # via anyio
+    iniconfig==2.0.0
+        # via foo (packages/foo/pyproject.toml)
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// With `--emit-workspace-members`, omit the header outside of a workspace, but surface any errors
+/// encountered while discovering the workspace.
+#[test]
+fn emit_workspace_members_discovery() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==2.0.0")?;
+
+    // Outside of a workspace, no members are listed.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--emit-workspace-members"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --emit-workspace-members
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // A malformed `pyproject.toml` is reported, rather than ignored.
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "!project"
+        version = "0.1.0"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--emit-workspace-members"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `pyproject.toml`
+      Caused by: TOML parse error at line 2, column 8
+      |
+    2 | name = "!project"
+      |        ^^^^^^^^^^
+    Not a valid package or extra name: "!project". Names must start and end with a letter or digit and may only contain -, _, ., and alphanumeric characters.
+
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve with `--strip-trailing-zeros`, to write each pinned version in its canonical form.
+#[test]
+fn strip_trailing_zeros() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==2.0.0\nidna==3.6")?;
+
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--strip-trailing-zeros"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --strip-trailing-zeros
+    idna==3.6
+        # via -r requirements.in
+    iniconfig==2
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Prune a package from the output, along with any dependencies that aren't required by another
+/// package.
+#[test]
+fn prune() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask==3.0.0\njinja2")?;
+
+    // `jinja2` is requested directly, and `markupsafe` is required by `jinja2`, so both should be
+    // retained; the remaining dependencies of `flask` should be omitted.
+    uv_snapshot!(context.pip_compile()
+            .arg("requirements.in")
+            .arg("--prune")
+            .arg("flask"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --prune flask
+    jinja2==3.1.3
+        # via -r requirements.in
+    markupsafe==2.1.5
+        # via jinja2
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Request two extras of the same package, from separate requirements, whose dependencies
+/// conflict. The error should name both extras.
+#[test]
+fn compile_conflicting_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let mut filters = context.filters();
+    filters.push((r"extra-incompatible-with-extra-", "package-"));
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        extra-incompatible-with-extra-a[extra_b]
+        extra-incompatible-with-extra-a[extra_c]
+    "})?;
+
+    uv_snapshot!(filters, context.pip_compile()
+            .env_remove("UV_EXCLUDE_NEWER")
+            .arg("requirements.in")
+            .arg("--index-url")
+            .arg("https://astral-sh.github.io/packse/0.3.29/simple-html/"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only package-a[extra-c]==1.0.0 is available and package-a[extra-c]==1.0.0 depends on package-b==2.0.0, we can conclude that all versions of package-a[extra-c] depend on package-b==2.0.0.
+          And because package-a[extra-b]==1.0.0 depends on package-b==1.0.0 and only package-a[extra-b]==1.0.0 is available, we can conclude that all versions of package-a[extra-b] and all versions of package-a[extra-c] are incompatible.
+          And because you require package-a[extra-b] and package-a[extra-c], we can conclude that the requirements are unsatisfiable.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve a package whose only release has a local version (`1.2.3+foo`) under each
+/// `--local-version-policy`.
+#[test]
+fn local_version_policy() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let mut filters = context.filters();
+    filters.push((r"local-greater-than-or-equal-", "package-"));
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("local-greater-than-or-equal-a>=1.2.3")?;
+
+    let command = || {
+        let mut command = context.pip_compile();
+        command
+            .env_remove("UV_EXCLUDE_NEWER")
+            .arg("requirements.in")
+            .arg("--index-url")
+            .arg("https://astral-sh.github.io/packse/0.3.29/simple-html/");
+        command
+    };
+
+    // By default, the local version is selected and written as-is.
+    uv_snapshot!(filters, command()
+        .arg("--local-version-policy")
+        .arg("keep"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --local-version-policy keep
+    package-a==1.2.3+foo
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // With `strip`, the local version is selected, but the local segment is omitted.
+    uv_snapshot!(filters, command()
+        .arg("--local-version-policy")
+        .arg("strip"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --local-version-policy strip
+    package-a==1.2.3
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // With `forbid`, the local version is excluded from resolution.
+    uv_snapshot!(filters, command()
+        .arg("--local-version-policy")
+        .arg("forbid"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because there are no versions of package-a>=1.2.3 and you require package-a>=1.2.3, we can conclude that the requirements are unsatisfiable.
+    "###
+    );
+
+    // `strip` can't be combined with `--generate-hashes`, since the hashes would belong to the
+    // local version.
+    uv_snapshot!(filters, command()
+        .arg("--local-version-policy")
+        .arg("strip")
+        .arg("--generate-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--local-version-policy strip` can't be used with `--generate-hashes`, since the hashes would belong to the local versions that were stripped
+    "###
+    );
+
+    Ok(())
+}
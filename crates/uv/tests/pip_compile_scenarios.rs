@@ -118,7 +118,7 @@ fn compatible_python_incompatible_override() -> Result<()> {
         .arg("--python-version=3.9")
         , @r###"
                  success: false
-                 exit_code: 1
+                 exit_code: 2
                  ----- stdout -----
 
                  ----- stderr -----
@@ -172,7 +172,7 @@ fn incompatible_python_compatible_override_unavailable_no_wheels() -> Result<()>
         .arg("--python-version=3.11")
         , @r###"
                  success: false
-                 exit_code: 1
+                 exit_code: 2
                  ----- stdout -----
 
                  ----- stderr -----
@@ -284,7 +284,7 @@ fn incompatible_python_compatible_override_no_compatible_wheels() -> Result<()>
         .arg("--python-version=3.11")
         , @r###"
                  success: false
-                 exit_code: 1
+                 exit_code: 2
                  ----- stdout -----
 
                  ----- stderr -----
@@ -342,7 +342,7 @@ fn incompatible_python_compatible_override_other_wheel() -> Result<()> {
         .arg("--python-version=3.11")
         , @r###"
                  success: false
-                 exit_code: 1
+                 exit_code: 2
                  ----- stdout -----
 
                  ----- stderr -----
@@ -398,7 +398,7 @@ fn python_patch_override_no_patch() -> Result<()> {
         .arg("--python-version=3.8")
         , @r###"
                  success: false
-                 exit_code: 1
+                 exit_code: 2
                  ----- stdout -----
 
                  ----- stderr -----
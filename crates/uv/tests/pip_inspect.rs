@@ -0,0 +1,110 @@
+use std::process::Command;
+
+use anyhow::Result;
+use assert_fs::fixture::FileWriteStr;
+use assert_fs::fixture::PathChild;
+
+use common::uv_snapshot;
+
+use crate::common::{get_bin, TestContext};
+
+mod common;
+
+/// Create a `pip inspect` command with options shared across scenarios.
+fn inspect_command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command.arg("pip").arg("inspect");
+    context.add_shared_args(&mut command);
+    command
+}
+
+/// Filter out the uv version and the interpreter markers, which vary across releases and
+/// platforms.
+fn inspect_filters(context: &TestContext) -> Vec<(&str, &str)> {
+    context
+        .filters()
+        .into_iter()
+        .chain([
+            (r#""uv_version": "[^"]+""#, r#""uv_version": "[VERSION]""#),
+            (
+                r#"(?s)"environment": \{.*?\}"#,
+                r#""environment": [ENVIRONMENT]"#,
+            ),
+        ])
+        .collect()
+}
+
+#[test]
+fn inspect_empty() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(inspect_filters(&context), inspect_command(&context), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "version": "1",
+      "uv_version": "[VERSION]",
+      "installed": [],
+      "environment": [ENVIRONMENT]
+    }
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn inspect_installed() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + markupsafe==2.1.3
+    "###
+    );
+
+    uv_snapshot!(inspect_filters(&context), inspect_command(&context), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "version": "1",
+      "uv_version": "[VERSION]",
+      "installed": [
+        {
+          "metadata": {
+            "name": "markupsafe",
+            "version": "2.1.3",
+            "summary": "Safely add untrusted strings to HTML/XML markup.",
+            "license": "BSD-3-Clause",
+            "requires_python": ">=3.7"
+          },
+          "metadata_location": "[SITE_PACKAGES]/MarkupSafe-2.1.3.dist-info",
+          "requested": true,
+          "installer": "uv"
+        }
+      ],
+      "environment": [ENVIRONMENT]
+    }
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
@@ -76,7 +76,7 @@ fn requires_package_does_not_exist() {
         .arg("requires-package-does-not-exist-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -115,7 +115,7 @@ fn requires_exact_version_does_not_exist() {
         .arg("requires-exact-version-does-not-exist-a==2.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -156,7 +156,7 @@ fn requires_greater_version_does_not_exist() {
         .arg("requires-greater-version-does-not-exist-a>1.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -198,7 +198,7 @@ fn requires_less_version_does_not_exist() {
         .arg("requires-less-version-does-not-exist-a<2.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -239,7 +239,7 @@ fn transitive_requires_package_does_not_exist() {
         .arg("transitive-requires-package-does-not-exist-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -280,7 +280,7 @@ fn excluded_only_version() {
         .arg("excluded-only-version-a!=1.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -336,7 +336,7 @@ fn excluded_only_compatible_version() {
                 .arg("excluded-only-compatible-version-b<3.0.0,>=2.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -442,7 +442,7 @@ fn dependency_excludes_range_of_compatible_versions() {
                 .arg("dependency-excludes-range-of-compatible-versions-c")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -567,7 +567,7 @@ fn dependency_excludes_non_contiguous_range_of_compatible_versions() {
                 .arg("dependency-excludes-non-contiguous-range-of-compatible-versions-c")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -892,7 +892,7 @@ fn extra_incompatible_with_extra() {
         .arg("extra-incompatible-with-extra-a[extra_b,extra_c]")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1008,7 +1008,7 @@ fn extra_incompatible_with_root() {
                 .arg("extra-incompatible-with-root-b==2.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1115,7 +1115,7 @@ fn direct_incompatible_versions() {
                 .arg("direct-incompatible-versions-a==2.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1168,7 +1168,7 @@ fn transitive_incompatible_with_root_version() {
                 .arg("transitive-incompatible-with-root-version-b==1.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1226,7 +1226,7 @@ fn transitive_incompatible_with_transitive() {
                 .arg("transitive-incompatible-with-transitive-b")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1277,7 +1277,7 @@ fn transitive_incompatible_versions() {
         .arg("transitive-incompatible-versions-a==1.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1318,7 +1318,7 @@ fn local_simple() {
         .arg("local-simple-a==1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1404,7 +1404,7 @@ fn local_used_without_sdist() {
         .arg("local-used-without-sdist-a==1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1557,7 +1557,7 @@ fn local_transitive_greater_than() {
                 .arg("local-transitive-greater-than-b==2.0.0+foo")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1668,7 +1668,7 @@ fn local_transitive_less_than() {
                 .arg("local-transitive-less-than-b==2.0.0+foo")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1778,7 +1778,7 @@ fn local_transitive_confounding() {
         .arg("local-transitive-confounding-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1827,7 +1827,7 @@ fn local_transitive_conflicting() {
                 .arg("local-transitive-conflicting-b==2.0.0+foo")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1936,7 +1936,7 @@ fn local_greater_than() {
         .arg("local-greater-than-a>1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2014,7 +2014,7 @@ fn local_less_than() {
         .arg("local-less-than-a<1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2049,7 +2049,7 @@ fn local_less_than_or_equal() {
         .arg("local-less-than-or-equal-a<=1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2089,7 +2089,7 @@ fn post_simple() {
         .arg("post-simple-a==1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2167,7 +2167,7 @@ fn post_greater_than() {
         .arg("post-greater-than-a>1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2293,7 +2293,7 @@ fn post_less_than_or_equal() {
         .arg("post-less-than-or-equal-a<=1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2332,7 +2332,7 @@ fn post_less_than() {
         .arg("post-less-than-a<1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2369,7 +2369,7 @@ fn post_local_greater_than() {
         .arg("post-local-greater-than-a>1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2410,7 +2410,7 @@ fn post_local_greater_than_post() {
         .arg("post-local-greater-than-post-a>1.2.3.post1")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2451,7 +2451,7 @@ fn post_equal_not_available() {
         .arg("post-equal-not-available-a==1.2.3.post0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2538,7 +2538,7 @@ fn post_greater_than_post_not_available() {
         .arg("post-greater-than-post-not-available-a>1.2.3.post2")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2624,14 +2624,14 @@ fn package_only_prereleases_in_range() {
         .arg("package-only-prereleases-in-range-a>0.1.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
       × No solution found when resolving dependencies:
       ╰─▶ Because only package-a<=0.1.0 is available and you require package-a>0.1.0, we can conclude that the requirements are unsatisfiable.
 
-          hint: Pre-releases are available for package-a in the requested range (e.g., 1.0.0a1), but pre-releases weren't enabled (try: `--prerelease=allow`)
+          hint: Pre-releases are available for package-a in the requested range (e.g., 1.0.0a1), but pre-releases weren't enabled (try: `--pre=package-a` or `--prerelease=allow`)
     "###);
 
     // Since there are stable versions of `a` available, prerelease versions should not
@@ -3072,7 +3072,7 @@ fn transitive_package_only_prereleases_in_range() {
         .arg("transitive-package-only-prereleases-in-range-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -3080,7 +3080,7 @@ fn transitive_package_only_prereleases_in_range() {
       ╰─▶ Because only package-b<=0.1 is available and package-a==0.1.0 depends on package-b>0.1, we can conclude that package-a==0.1.0 cannot be used.
           And because only package-a==0.1.0 is available and you require package-a, we can conclude that the requirements are unsatisfiable.
 
-          hint: Pre-releases are available for package-b in the requested range (e.g., 1.0.0a1), but pre-releases weren't enabled (try: `--prerelease=allow`)
+          hint: Pre-releases are available for package-b in the requested range (e.g., 1.0.0a1), but pre-releases weren't enabled (try: `--pre=package-b` or `--prerelease=allow`)
     "###);
 
     // Since there are stable versions of `b` available, the prerelease version should
@@ -3194,7 +3194,7 @@ fn transitive_prerelease_and_stable_dependency() {
                 .arg("transitive-prerelease-and-stable-dependency-b")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -3202,7 +3202,7 @@ fn transitive_prerelease_and_stable_dependency() {
       ╰─▶ Because there is no version of package-c==2.0.0b1 and package-a==1.0.0 depends on package-c==2.0.0b1, we can conclude that package-a==1.0.0 cannot be used.
           And because only package-a==1.0.0 is available and you require package-a, we can conclude that the requirements are unsatisfiable.
 
-          hint: package-c was requested with a pre-release marker (e.g., package-c==2.0.0b1), but pre-releases weren't enabled (try: `--prerelease=allow`)
+          hint: package-c was requested with a pre-release marker (e.g., package-c==2.0.0b1), but pre-releases weren't enabled (try: `--pre=package-c` or `--prerelease=allow`)
     "###);
 
     // Since the user did not explicitly opt-in to a prerelease, it cannot be selected.
@@ -3361,7 +3361,7 @@ fn transitive_prerelease_and_stable_dependency_many_versions() {
                 .arg("transitive-prerelease-and-stable-dependency-many-versions-b")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -3371,7 +3371,7 @@ fn transitive_prerelease_and_stable_dependency_many_versions() {
           And because package-b==1.0.0 depends on package-c and only package-b==1.0.0 is available, we can conclude that all versions of package-a and all versions of package-b are incompatible.
           And because you require package-a and package-b, we can conclude that the requirements are unsatisfiable.
 
-          hint: package-c was requested with a pre-release marker (e.g., package-c>=2.0.0b1), but pre-releases weren't enabled (try: `--prerelease=allow`)
+          hint: package-c was requested with a pre-release marker (e.g., package-c>=2.0.0b1), but pre-releases weren't enabled (try: `--pre=package-c` or `--prerelease=allow`)
     "###);
 
     // Since the user did not explicitly opt-in to a prerelease, it cannot be selected.
@@ -3445,7 +3445,7 @@ fn transitive_prerelease_and_stable_dependency_many_versions_holes() {
                 .arg("transitive-prerelease-and-stable-dependency-many-versions-holes-b")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -3466,7 +3466,7 @@ fn transitive_prerelease_and_stable_dependency_many_versions_holes() {
               package-c>1.0.0,<2.0.0a5
               package-c>2.0.0a7,<2.0.0b1
               package-c>2.0.0b1,<2.0.0b5
-          ), but pre-releases weren't enabled (try: `--prerelease=allow`)
+          ), but pre-releases weren't enabled (try: `--pre=package-c` or `--prerelease=allow`)
     "###);
 
     // Since the user did not explicitly opt-in to a prerelease, it cannot be selected.
@@ -3701,7 +3701,7 @@ fn python_version_does_not_exist() {
         .arg("python-version-does-not-exist-a==1.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -3743,7 +3743,7 @@ fn python_less_than_current() {
         .arg("python-less-than-current-a==1.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -3785,7 +3785,7 @@ fn python_greater_than_current() {
         .arg("python-greater-than-current-a==1.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -3828,7 +3828,7 @@ fn python_greater_than_current_patch() {
         .arg("python-greater-than-current-patch-a==1.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -3892,7 +3892,7 @@ fn python_greater_than_current_many() {
         .arg("python-greater-than-current-many-a==1.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -3992,7 +3992,7 @@ fn python_greater_than_current_excluded() {
         .arg("python-greater-than-current-excluded-a>=2.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -4189,7 +4189,7 @@ fn no_sdist_no_wheels_with_matching_platform() {
         .arg("no-sdist-no-wheels-with-matching-platform-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -4230,7 +4230,7 @@ fn no_sdist_no_wheels_with_matching_python() {
         .arg("no-sdist-no-wheels-with-matching-python-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -4271,7 +4271,7 @@ fn no_sdist_no_wheels_with_matching_abi() {
         .arg("no-sdist-no-wheels-with-matching-abi-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -4314,7 +4314,7 @@ fn no_wheels_no_build() {
         .arg("no-wheels-no-build-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -4353,7 +4353,7 @@ fn only_wheels_no_binary() {
         .arg("only-wheels-no-binary-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -4470,7 +4470,7 @@ fn package_only_yanked() {
         .arg("package-only-yanked-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -4509,7 +4509,7 @@ fn package_only_yanked_in_range() {
         .arg("package-only-yanked-in-range-a>0.1.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -4652,7 +4652,7 @@ fn transitive_package_only_yanked() {
         .arg("transitive-package-only-yanked-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -4701,7 +4701,7 @@ fn transitive_package_only_yanked_in_range() {
         .arg("transitive-package-only-yanked-in-range-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -4825,7 +4825,7 @@ fn transitive_yanked_and_unyanked_dependency() {
                 .arg("transitive-yanked-and-unyanked-dependency-b")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -547,7 +547,7 @@ Version: 0.1-bulbasaur
     uv_snapshot!(filters, list_command(&context)
         .arg("--editable"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
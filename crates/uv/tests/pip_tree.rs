@@ -1,5 +1,7 @@
 use std::process::Command;
 
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::ChildPath;
 use assert_fs::fixture::FileWriteStr;
 use assert_fs::fixture::PathChild;
 
@@ -1070,3 +1072,1730 @@ fn with_editable() {
     "###
     );
 }
+
+#[test]
+fn fields() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--fields").arg("name,requires-python"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    requests [requires-python: >=3.7]
+    ├── charset-normalizer [requires-python: >=3.7.0]
+    ├── idna [requires-python: >=3.5]
+    ├── urllib3 [requires-python: >=3.8]
+    └── certifi [requires-python: >=3.6]
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn ascii() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--ascii"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    requests v2.31.0
+    |-- charset-normalizer v3.3.2
+    |-- idna v3.6
+    |-- urllib3 v2.2.1
+    `-- certifi v2024.2.2
+
+    ----- stderr -----
+    "###
+    );
+
+    let output = tree_command(&context).arg("--ascii").output().unwrap();
+    assert!(output.stdout.is_ascii());
+}
+
+#[test]
+fn connector() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("scikit-learn==1.4.1.post1")
+        .unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + joblib==1.3.2
+     + numpy==1.26.4
+     + scikit-learn==1.4.1.post1
+     + scipy==1.12.0
+     + threadpoolctl==3.4.0
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--connector").arg("unicode").arg("--indent").arg("2"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    scikit-learn v1.4.1.post1
+    ├ numpy v1.26.4
+    ├ scipy v1.12.0
+    │ └ numpy v1.26.4 (*)
+    ├ joblib v1.3.2
+    └ threadpoolctl v3.4.0
+    (*) Package tree already displayed
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--connector").arg("ascii").arg("--indent").arg("6"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    scikit-learn v1.4.1.post1
+    |---- numpy v1.26.4
+    |---- scipy v1.12.0
+    |     `---- numpy v1.26.4 (*)
+    |---- joblib v1.3.2
+    `---- threadpoolctl v3.4.0
+    (*) Package tree already displayed
+
+    ----- stderr -----
+    "###
+    );
+
+    // With `spaces`, each level is indented without any connectors.
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--connector").arg("spaces"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    scikit-learn v1.4.1.post1
+        numpy v1.26.4
+        scipy v1.12.0
+            numpy v1.26.4 (*)
+        joblib v1.3.2
+        threadpoolctl v3.4.0
+    (*) Package tree already displayed
+
+    ----- stderr -----
+    "###
+    );
+
+    // The indentation must leave room for a connector.
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--indent").arg("1"), @r###"
+    success: false
+    exit_code: 4
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value '1' for '--indent <INDENT>': 1 is not in 2..=255
+
+    For more information, try '--help'.
+    "###
+    );
+}
+
+#[test]
+fn package() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("scikit-learn==1.4.1.post1")
+        .unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + joblib==1.3.2
+     + numpy==1.26.4
+     + scikit-learn==1.4.1.post1
+     + scipy==1.12.0
+     + threadpoolctl==3.4.0
+    "###
+    );
+
+    // `scipy` is a dependency of `scikit-learn`, but should be rendered at the root.
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--package").arg("scipy"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    scipy v1.12.0
+    └── numpy v1.26.4
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--package").arg("scipy").arg("--package").arg("joblib").arg("--package").arg("flask"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    scipy v1.12.0
+    └── numpy v1.26.4
+    joblib v1.3.2
+
+    ----- stderr -----
+    warning: Package(s) not found for: flask
+    "###
+    );
+
+    // Roots are displayed in the order in which they were given, via the `--root` alias.
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--root").arg("joblib").arg("--root").arg("scipy"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    joblib v1.3.2
+    scipy v1.12.0
+    └── numpy v1.26.4
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn prune_after_depth() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("scikit-learn==1.4.1.post1")
+        .unwrap();
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + joblib==1.3.2
+     + numpy==1.26.4
+     + scikit-learn==1.4.1.post1
+     + scipy==1.12.0
+     + threadpoolctl==3.4.0
+    "###
+    );
+
+    // The traversal halts at the pruning depth, regardless of the display depth.
+    uv_snapshot!(context.filters(), tree_command(&context)
+        .arg("--depth")
+        .arg("2")
+        .arg("--prune-after-depth")
+        .arg("1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    scikit-learn v1.4.1.post1
+    ├── numpy v1.26.4
+    ├── scipy v1.12.0
+    ├── joblib v1.3.2
+    └── threadpoolctl v3.4.0
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn show_summary() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    // Output is not truncated when not writing to a terminal.
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--show-summary"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    requests v2.31.0 [summary: Python HTTP for Humans.]
+    ├── charset-normalizer v3.3.2 [summary: The Real First Universal Charset Detector. Open, modern and actively maintained alternative to Chardet.]
+    ├── idna v3.6 [summary: Internationalized Domain Names in Applications (IDNA)]
+    ├── urllib3 v2.2.1 [summary: HTTP library with thread-safe connection pooling, file post, and more.]
+    └── certifi v2024.2.2 [summary: Python package for providing Mozilla's CA Bundle.]
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn no_wrap() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    // When not writing to a terminal, `--no-wrap` truncates each line to `COLUMNS`.
+    uv_snapshot!(context.filters(), tree_command(&context)
+        .arg("--show-summary")
+        .arg("--no-wrap")
+        .env("COLUMNS", "40"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    requests v2.31.0 [summary: Python HTTP …
+    ├── charset-normalizer v3.3.2 [summary:…
+    ├── idna v3.6 [summary: Internationaliz…
+    ├── urllib3 v2.2.1 [summary: HTTP libra…
+    └── certifi v2024.2.2 [summary: Python …
+
+    ----- stderr -----
+    "###
+    );
+
+    // `--wrap` overrides `--no-wrap`, displaying each line in full.
+    uv_snapshot!(context.filters(), tree_command(&context)
+        .arg("--show-summary")
+        .arg("--no-wrap")
+        .arg("--wrap")
+        .env("COLUMNS", "40"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    requests v2.31.0 [summary: Python HTTP for Humans.]
+    ├── charset-normalizer v3.3.2 [summary: The Real First Universal Charset Detector. Open, modern and actively maintained alternative to Chardet.]
+    ├── idna v3.6 [summary: Internationalized Domain Names in Applications (IDNA)]
+    ├── urllib3 v2.2.1 [summary: HTTP library with thread-safe connection pooling, file post, and more.]
+    └── certifi v2024.2.2 [summary: Python package for providing Mozilla's CA Bundle.]
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn show_hashes() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("idna==3.6\niniconfig==2.0.0")
+        .unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + idna==3.6
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // Simulate a package installed from a direct URL with a recorded hash (as written by `pip`).
+    ChildPath::new(context.site_packages())
+        .child("iniconfig-2.0.0.dist-info")
+        .child("direct_url.json")
+        .write_str(r#"{"archive_info": {"hash": "sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", "hashes": {"sha256": "b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"}}, "url": "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl"}"#)
+        .unwrap();
+
+    // Packages installed from a registry don't have a recorded hash.
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--show-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    idna v3.6 (no hash)
+    iniconfig v2.0.0 [hash: sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374]
+
+    ----- stderr -----
+    "###
+    );
+}
+
+/// A `*` in a package's summary shouldn't be mistaken for the `(*)` de-duplication marker.
+#[test]
+fn show_summary_asterisk() {
+    let context = TestContext::new("3.12");
+
+    ChildPath::new(context.site_packages())
+        .child("fancy-1.0.0.dist-info")
+        .child("METADATA")
+        .write_str(
+            "Metadata-Version: 2.1\nName: fancy\nVersion: 1.0.0\nSummary: A *very* fancy package\n",
+        )
+        .unwrap();
+
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--show-summary"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    fancy v1.0.0 [summary: A *very* fancy package]
+
+    ----- stderr -----
+    "###
+    );
+}
+
+/// Repeated (but non-cyclic) dependencies are fully expanded with `--repeat`.
+#[test]
+#[cfg(not(windows))]
+fn repeat_diamond() {
+    let context = TestContext::new("3.12");
+
+    // `pendulum` depends on `python-dateutil` both directly and via `time-machine`.
+    uv_snapshot!(context
+        .pip_install()
+        .arg("pendulum==3.0.0")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + pendulum==3.0.0
+     + python-dateutil==2.9.0.post0
+     + six==1.16.0
+     + time-machine==2.14.1
+     + tzdata==2024.1
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context)
+        .arg("--repeat"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pendulum v3.0.0
+    ├── python-dateutil v2.9.0.post0
+    │   └── six v1.16.0
+    ├── tzdata v2024.1
+    └── time-machine v2.14.1
+        └── python-dateutil v2.9.0.post0
+            └── six v1.16.0
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn show_missing() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context
+        .pip_uninstall()
+        .arg("idna"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - idna==3.6
+    "###
+    );
+
+    // By default, the missing dependency is omitted.
+    uv_snapshot!(context.filters(), tree_command(&context), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    requests v2.31.0
+    ├── charset-normalizer v3.3.2
+    ├── urllib3 v2.2.1
+    └── certifi v2024.2.2
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context)
+        .arg("--show-missing"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    requests v2.31.0
+    ├── charset-normalizer v3.3.2
+    ├── idna (not installed)
+    ├── urllib3 v2.2.1
+    └── certifi v2024.2.2
+
+    ----- stderr -----
+    "###
+    );
+
+    // With `--warn-on-missing`, the missing dependency is reported, and the command fails.
+    uv_snapshot!(context.filters(), tree_command(&context)
+        .arg("--show-missing")
+        .arg("--warn-on-missing"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    requests v2.31.0
+    ├── charset-normalizer v3.3.2
+    ├── idna (not installed)
+    ├── urllib3 v2.2.1
+    └── certifi v2024.2.2
+
+    ----- stderr -----
+    warning: Required package is not installed: idna
+    "###
+    );
+}
+
+#[test]
+fn hide_version() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context)
+        .arg("--hide-version"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    requests
+    ├── charset-normalizer
+    ├── idna
+    ├── urllib3
+    └── certifi
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn color_by_depth() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    // When color is disabled, the output is unchanged.
+    uv_snapshot!(context.filters(), tree_command(&context)
+        .arg("--color-by")
+        .arg("depth")
+        .arg("--color")
+        .arg("never"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    requests v2.31.0
+    ├── charset-normalizer v3.3.2
+    ├── idna v3.6
+    ├── urllib3 v2.2.1
+    └── certifi v2024.2.2
+
+    ----- stderr -----
+    "###
+    );
+
+    // Otherwise, each level of the tree is colored differently.
+    let output = tree_command(&context)
+        .arg("--color-by")
+        .arg("depth")
+        .arg("--color")
+        .arg("always")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("\u{1b}[36mrequests v2.31.0\u{1b}[39m"));
+    assert!(stdout.contains("\u{1b}[32midna v3.6\u{1b}[39m"));
+}
+
+#[test]
+fn highlight() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    // When color is disabled, the tree is neither filtered nor highlighted.
+    uv_snapshot!(context.filters(), tree_command(&context)
+        .arg("--highlight")
+        .arg("urllib3")
+        .arg("--highlight")
+        .arg("idna")
+        .arg("--color")
+        .arg("never"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    requests v2.31.0
+    ├── charset-normalizer v3.3.2
+    ├── idna v3.6
+    ├── urllib3 v2.2.1
+    └── certifi v2024.2.2
+
+    ----- stderr -----
+    "###
+    );
+
+    // Otherwise, only the requested packages are highlighted.
+    let output = tree_command(&context)
+        .arg("--highlight")
+        .arg("urllib3")
+        .arg("--highlight")
+        .arg("idna")
+        .arg("--color")
+        .arg("always")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("\u{1b}[43murllib3 v2.2.1\u{1b}[49m"));
+    assert!(stdout.contains("\u{1b}[43midna v3.6\u{1b}[49m"));
+    assert!(stdout.starts_with("requests v2.31.0\n"));
+}
+
+#[test]
+fn format_csv() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--format").arg("csv"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    parent,child,parent_version,child_version
+    requests,certifi,2.31.0,2024.2.2
+    requests,charset-normalizer,2.31.0,3.3.2
+    requests,idna,2.31.0,3.6
+    requests,urllib3,2.31.0,2.2.1
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn depth_from() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("scikit-learn==1.4.1.post1")
+        .unwrap();
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + joblib==1.3.2
+     + numpy==1.26.4
+     + scikit-learn==1.4.1.post1
+     + scipy==1.12.0
+     + threadpoolctl==3.4.0
+    "###
+    );
+
+    // The depth is measured from `scipy`, such that its dependencies are omitted, while the rest
+    // of the tree is displayed in full.
+    uv_snapshot!(context.filters(), tree_command(&context)
+        .arg("--depth")
+        .arg("0")
+        .arg("--depth-from")
+        .arg("scipy"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    scikit-learn v1.4.1.post1
+    ├── numpy v1.26.4
+    ├── scipy v1.12.0
+    ├── joblib v1.3.2
+    └── threadpoolctl v3.4.0
+
+    ----- stderr -----
+    "###
+    );
+
+    // Combined with `--package`, only the immediate dependencies of the anchor are displayed.
+    uv_snapshot!(context.filters(), tree_command(&context)
+        .arg("--package")
+        .arg("scipy")
+        .arg("--depth")
+        .arg("1")
+        .arg("--depth-from")
+        .arg("scipy"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    scipy v1.12.0
+    └── numpy v1.26.4
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn installed() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context)
+        .arg("--installed")
+        .arg("requests==2.31.0")
+        .arg("--installed")
+        .arg("idna>=3"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    requests v2.31.0
+    idna v3.6
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context)
+        .arg("--installed")
+        .arg("requests==2.31.0")
+        .arg("--installed")
+        .arg("urllib3<2"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    requests v2.31.0
+
+    ----- stderr -----
+    error: No installed package satisfies: urllib3<2
+    "###
+    );
+}
+
+#[test]
+fn exclude() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    // Excluding the root displays its dependencies at the top level.
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--exclude").arg("requests"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    charset-normalizer v3.3.2
+    idna v3.6
+    urllib3 v2.2.1
+    certifi v2024.2.2
+
+    ----- stderr -----
+    "###
+    );
+
+    // Unlike `--prune`, excluding the root and pruning a dependency still displays the rest.
+    uv_snapshot!(context.filters(), tree_command(&context)
+        .arg("--exclude")
+        .arg("requests")
+        .arg("--prune")
+        .arg("certifi"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    charset-normalizer v3.3.2
+    idna v3.6
+    urllib3 v2.2.1
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn format_json() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"roots":[{"id":"requests","dependencies":[{"id":"charset-normalizer","dependencies":[]},{"id":"idna","dependencies":[]},{"id":"urllib3","dependencies":[]},{"id":"certifi","dependencies":[]}]}],"packages":{"certifi":{"id":"certifi","name":"certifi","version":"2024.2.2","dependencies":[]},"charset-normalizer":{"id":"charset-normalizer","name":"charset-normalizer","version":"3.3.2","dependencies":[]},"idna":{"id":"idna","name":"idna","version":"3.6","dependencies":[]},"requests":{"id":"requests","name":"requests","version":"2.31.0","dependencies":["charset-normalizer","idna","urllib3","certifi"]},"urllib3":{"id":"urllib3","name":"urllib3","version":"2.2.1","dependencies":[]}}}
+
+    ----- stderr -----
+    "###
+    );
+
+    // Packages that were already expanded are emitted by reference.
+    uv_snapshot!(context.filters(), tree_command(&context)
+        .arg("--format")
+        .arg("json")
+        .arg("--package")
+        .arg("requests")
+        .arg("--package")
+        .arg("idna"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"roots":[{"id":"requests","dependencies":[{"id":"charset-normalizer","dependencies":[]},{"id":"idna","dependencies":[]},{"id":"urllib3","dependencies":[]},{"id":"certifi","dependencies":[]}]},{"ref":"idna"}],"packages":{"certifi":{"id":"certifi","name":"certifi","version":"2024.2.2","dependencies":[]},"charset-normalizer":{"id":"charset-normalizer","name":"charset-normalizer","version":"3.3.2","dependencies":[]},"idna":{"id":"idna","name":"idna","version":"3.6","dependencies":[]},"requests":{"id":"requests","name":"requests","version":"2.31.0","dependencies":["charset-normalizer","idna","urllib3","certifi"]},"urllib3":{"id":"urllib3","name":"urllib3","version":"2.2.1","dependencies":[]}}}
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--format").arg("json-flat"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"roots":["requests"],"packages":{"certifi":{"id":"certifi","name":"certifi","version":"2024.2.2","dependencies":[]},"charset-normalizer":{"id":"charset-normalizer","name":"charset-normalizer","version":"3.3.2","dependencies":[]},"idna":{"id":"idna","name":"idna","version":"3.6","dependencies":[]},"requests":{"id":"requests","name":"requests","version":"2.31.0","dependencies":["charset-normalizer","idna","urllib3","certifi"]},"urllib3":{"id":"urllib3","name":"urllib3","version":"2.2.1","dependencies":[]}}}
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn group_by_depth() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--group-by-depth"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Level 0:
+      requests v2.31.0
+    Level 1:
+      certifi v2024.2.2
+      charset-normalizer v3.3.2
+      idna v3.6
+      urllib3 v2.2.1
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn filter_by_marker() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("click==8.1.7\ncolorama==0.4.6")
+        .unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + click==8.1.7
+     + colorama==0.4.6
+    "###
+    );
+
+    // `colorama` is only required by `click` on Windows.
+    uv_snapshot!(context.filters(), tree_command(&context), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    click v8.1.7
+    colorama v0.4.6
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--filter-by-marker").arg("platform_system == 'Windows'"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    click v8.1.7
+    └── colorama v0.4.6
+
+    ----- stderr -----
+    "###
+    );
+
+    // An invalid expression is rejected.
+    let output = tree_command(&context)
+        .arg("--filter-by-marker")
+        .arg("platform_system ==")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Failed to parse marker expression: `platform_system ==`"));
+
+    // An expression with alternatives can't be applied to a single environment.
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--filter-by-marker").arg("platform_system == 'Windows' or platform_system == 'Linux'"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to apply marker expression: `platform_system == 'Windows' or platform_system == 'Linux'`
+      Caused by: Unsupported marker expression: `platform_system == 'Windows' or platform_system == 'Linux'` (clauses can only be joined by `and`)
+    "###
+    );
+
+    // Neither can a clause that doesn't pin a single value.
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--filter-by-marker").arg("platform_system == 'Windows' and python_version >= '3.8'"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to apply marker expression: `platform_system == 'Windows' and python_version >= '3.8'`
+      Caused by: Unsupported marker clause: `python_version >= '3.8'` (only `key == "value"` clauses are supported)
+    "###
+    );
+}
+
+#[test]
+fn no_markers() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("click==8.1.7\ncolorama==0.4.6")
+        .unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + click==8.1.7
+     + colorama==0.4.6
+    "###
+    );
+
+    // `colorama` is only required by `click` on Windows, but markers are ignored.
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--no-markers"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    click v8.1.7
+    └── colorama v0.4.6
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn unknown_version() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install().arg("iniconfig==2.0.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // Simulate a legacy install that reports a placeholder version.
+    ChildPath::new(context.site_packages())
+        .child("legacy-0.0.0.dist-info")
+        .child("METADATA")
+        .write_str("Metadata-Version: 2.1\nName: legacy\nVersion: 0.0.0\n")
+        .unwrap();
+
+    uv_snapshot!(context.filters(), tree_command(&context), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig v2.0.0
+    legacy (unknown version)
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn count_only() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--count-only"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    5 packages, 4 dependency edges
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn shadowed_across_site_dirs() {
+    let context = TestContext::new("3.12");
+
+    // Add a second site directory to `sys.path` via a `.pth` file. This must happen before the
+    // environment's interpreter is first queried, since `sys.path` is cached.
+    let extra = context.temp_dir.child("extra");
+    extra.create_dir_all().unwrap();
+    ChildPath::new(context.site_packages())
+        .child("extra.pth")
+        .write_str(&format!("{}\n", extra.path().display()))
+        .unwrap();
+
+    uv_snapshot!(context.pip_install().arg("iniconfig==2.0.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // Install an older copy of `iniconfig`, shadowed by the one in `site-packages`, alongside a
+    // package that only exists in the second directory.
+    extra
+        .child("iniconfig-1.1.1.dist-info")
+        .child("METADATA")
+        .write_str("Metadata-Version: 2.1\nName: iniconfig\nVersion: 1.1.1\n")
+        .unwrap();
+    extra
+        .child("standalone-1.0.0.dist-info")
+        .child("METADATA")
+        .write_str("Metadata-Version: 2.1\nName: standalone\nVersion: 1.0.0\n")
+        .unwrap();
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain(vec![(r"(\[TEMP_DIR\]/)?extra[\\/]", "[EXTRA]/")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, tree_command(&context), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig v2.0.0
+    standalone v1.0.0
+
+    ----- stderr -----
+    warning: The package `iniconfig` is installed in multiple locations; `[SITE_PACKAGES]/iniconfig-2.0.0.dist-info` shadows `[EXTRA]/iniconfig-1.1.1.dist-info`
+    "###
+    );
+}
+
+#[test]
+fn max_nodes() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--max-nodes").arg("3"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    requests v2.31.0
+    ├── charset-normalizer v3.3.2
+    ├── idna v3.6
+    … (truncated at 3 nodes)
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+#[cfg(feature = "git")]
+fn show_source() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@0.0.1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@0dacfd662c64cb4ceb16e6cf65a157a8b715b979)
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--show-source"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig v2.0.0 (registry)
+    uv-public-pypackage v0.1.0 (git)
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+#[cfg(unix)]
+fn show_reused() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--link-mode")
+        .arg("hardlink"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("idna==3.6")
+        .arg("--link-mode")
+        .arg("copy"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + idna==3.6
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--show-reused"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    idna v3.6 (copied)
+    iniconfig v2.0.0 (linked)
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn flat() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--flat"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    certifi v2024.2.2 <- requests
+    charset-normalizer v3.3.2 <- requests
+    idna v3.6 <- requests
+    requests v2.31.0 <- (top-level)
+    urllib3 v2.2.1 <- requests
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn dedupe_per_root() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install().arg("iniconfig==2.0.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // Simulate two roots that share a dependency.
+    for name in ["alpha", "beta"] {
+        ChildPath::new(context.site_packages())
+            .child(format!("{name}-1.0.0.dist-info"))
+            .child("METADATA")
+            .write_str(&format!(
+                "Metadata-Version: 2.1\nName: {name}\nVersion: 1.0.0\nRequires-Dist: iniconfig\n"
+            ))
+            .unwrap();
+    }
+
+    uv_snapshot!(context.filters(), tree_command(&context), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    alpha v1.0.0
+    └── iniconfig v2.0.0
+    beta v1.0.0
+    └── iniconfig v2.0.0 (*)
+    (*) Package tree already displayed
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--dedupe-per-root"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    alpha v1.0.0
+    └── iniconfig v2.0.0
+    beta v1.0.0
+    └── iniconfig v2.0.0
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn toolchain_preference() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("iniconfig==2.0.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // Virtual environments are selected regardless of the toolchain preference.
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--toolchain-preference").arg("only-managed"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig v2.0.0
+
+    ----- stderr -----
+    "###
+    );
+
+    // With `--system`, the system interpreters on the search path are skipped, and no managed
+    // toolchains are installed.
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--system").arg("--toolchain-preference").arg("only-managed"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No system environment found
+    "###
+    );
+}
+
+#[test]
+fn annotate_optional() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("requests[socks]==2.31.0")
+        .unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Prepared 6 packages in [TIME]
+    Installed 6 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + pysocks==1.7.1
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    // `pysocks` is only required via the `socks` extra of `requests`.
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--annotate-optional"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pysocks v1.7.1 (optional)
+    requests v2.31.0
+    ├── charset-normalizer v3.3.2
+    ├── idna v3.6
+    ├── urllib3 v2.2.1
+    └── certifi v2024.2.2
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn root_markers() {
+    let context = TestContext::new("3.12");
+
+    // The marker environment reflects the current interpreter, with any overrides applied.
+    tree_command(&context)
+        .arg("--root-markers")
+        .arg("--filter-by-marker")
+        .arg("sys_platform == 'win32'")
+        .assert()
+        .success()
+        .stderr(predicates::str::contains("Marker environment:"))
+        .stderr(predicates::str::contains("  python_version: 3.12\n"))
+        .stderr(predicates::str::contains("  sys_platform: win32\n"));
+
+    uv_snapshot!(context.filters(), tree_command(&context).arg("--root-markers").arg("--no-markers"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+
+    ----- stderr -----
+    Marker environment: (ignored)
+    "###
+    );
+}
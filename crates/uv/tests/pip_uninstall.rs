@@ -20,7 +20,7 @@ fn no_arguments() -> Result<()> {
         .arg("uninstall")
         .current_dir(&temp_dir), @r###"
     success: false
-    exit_code: 2
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -46,7 +46,7 @@ fn invalid_requirement() -> Result<()> {
         .arg("flask==1.0.x")
         .current_dir(&temp_dir), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -70,7 +70,7 @@ fn missing_requirements_txt() -> Result<()> {
         .arg("requirements.txt")
         .current_dir(&temp_dir), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -94,7 +94,7 @@ fn invalid_requirements_txt_requirement() -> Result<()> {
         .arg("requirements.txt")
         .current_dir(&temp_dir), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -176,7 +176,7 @@ fn missing_record() -> Result<()> {
     uv_snapshot!(context.filters(), context.pip_uninstall()
         .arg("MarkupSafe"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -15,6 +15,7 @@ fn command(context: &TestContext) -> Command {
     command
         .env("UV_LINK_MODE", "clone")
         .env("UV_CONCURRENT_DOWNLOADS", "50")
+        .env("UV_CONCURRENT_REQUESTS", "50")
         .env("UV_CONCURRENT_BUILDS", "16")
         .env("UV_CONCURRENT_INSTALLS", "8");
     command
@@ -54,6 +55,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         color: Auto,
         native_tls: false,
         connectivity: Online,
+        retries: 3,
         isolated: false,
         show_settings: true,
         preview: Disabled,
@@ -70,8 +72,16 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             "requirements.in",
         ],
         constraint: [],
+        build_constraint: [],
+        constraint_from_lock: [],
         override: [],
         overrides_from_workspace: [],
+        verify: false,
+        backup: false,
+        check_input_hash: false,
+        local_version_policy: Keep,
+        strip_trailing_zeros: false,
+        prune: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -119,6 +129,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             no_build_isolation: false,
+            sources: Enabled,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -127,6 +138,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -138,6 +150,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
             python_version: None,
             python_platform: None,
             universal: false,
@@ -147,19 +162,38 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 ),
             ),
             no_emit_package: [],
+            unsafe_package: [
+                PackageName(
+                    "distribute",
+                ),
+                PackageName(
+                    "pip",
+                ),
+                PackageName(
+                    "setuptools",
+                ),
+            ],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            keep_yanked_reason: false,
+            emit_build_isolation_note: false,
+            emit_input_hash: false,
+            emit_python_version: false,
+        emit_timestamp: false,
+        emit_workspace_members: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
             require_hashes: false,
             upgrade: None,
+            upgrade_strategy: OnlyIfNeeded,
             reinstall: None,
             concurrency: Concurrency {
                 downloads: 50,
+                requests: 50,
                 builds: 16,
                 installs: 8,
             },
@@ -184,6 +218,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         color: Auto,
         native_tls: false,
         connectivity: Online,
+        retries: 3,
         isolated: false,
         show_settings: true,
         preview: Disabled,
@@ -200,8 +235,16 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             "requirements.in",
         ],
         constraint: [],
+        build_constraint: [],
+        constraint_from_lock: [],
         override: [],
         overrides_from_workspace: [],
+        verify: false,
+        backup: false,
+        check_input_hash: false,
+        local_version_policy: Keep,
+        strip_trailing_zeros: false,
+        prune: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -249,6 +292,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             no_build_isolation: false,
+            sources: Enabled,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -257,6 +301,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -268,6 +313,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
             python_version: None,
             python_platform: None,
             universal: false,
@@ -277,19 +325,38 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 ),
             ),
             no_emit_package: [],
+            unsafe_package: [
+                PackageName(
+                    "distribute",
+                ),
+                PackageName(
+                    "pip",
+                ),
+                PackageName(
+                    "setuptools",
+                ),
+            ],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            keep_yanked_reason: false,
+            emit_build_isolation_note: false,
+            emit_input_hash: false,
+            emit_python_version: false,
+        emit_timestamp: false,
+        emit_workspace_members: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
             require_hashes: false,
             upgrade: None,
+            upgrade_strategy: OnlyIfNeeded,
             reinstall: None,
             concurrency: Concurrency {
                 downloads: 50,
+                requests: 50,
                 builds: 16,
                 installs: 8,
             },
@@ -315,6 +382,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         color: Auto,
         native_tls: false,
         connectivity: Online,
+        retries: 3,
         isolated: false,
         show_settings: true,
         preview: Disabled,
@@ -331,8 +399,16 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             "requirements.in",
         ],
         constraint: [],
+        build_constraint: [],
+        constraint_from_lock: [],
         override: [],
         overrides_from_workspace: [],
+        verify: false,
+        backup: false,
+        check_input_hash: false,
+        local_version_policy: Keep,
+        strip_trailing_zeros: false,
+        prune: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -380,6 +456,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             no_build_isolation: false,
+            sources: Enabled,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -388,6 +465,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -399,6 +477,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
             python_version: None,
             python_platform: None,
             universal: false,
@@ -408,19 +489,38 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 ),
             ),
             no_emit_package: [],
+            unsafe_package: [
+                PackageName(
+                    "distribute",
+                ),
+                PackageName(
+                    "pip",
+                ),
+                PackageName(
+                    "setuptools",
+                ),
+            ],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            keep_yanked_reason: false,
+            emit_build_isolation_note: false,
+            emit_input_hash: false,
+            emit_python_version: false,
+        emit_timestamp: false,
+        emit_workspace_members: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
             require_hashes: false,
             upgrade: None,
+            upgrade_strategy: OnlyIfNeeded,
             reinstall: None,
             concurrency: Concurrency {
                 downloads: 50,
+                requests: 50,
                 builds: 16,
                 installs: 8,
             },
@@ -478,6 +578,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         color: Auto,
         native_tls: false,
         connectivity: Online,
+        retries: 3,
         isolated: false,
         show_settings: true,
         preview: Disabled,
@@ -494,8 +595,16 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             "requirements.in",
         ],
         constraint: [],
+        build_constraint: [],
+        constraint_from_lock: [],
         override: [],
         overrides_from_workspace: [],
+        verify: false,
+        backup: false,
+        check_input_hash: false,
+        local_version_policy: Keep,
+        strip_trailing_zeros: false,
+        prune: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -543,6 +652,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             no_build_isolation: false,
+            sources: Enabled,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -551,6 +661,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -562,6 +673,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
             python_version: None,
             python_platform: None,
             universal: false,
@@ -571,19 +685,38 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 ),
             ),
             no_emit_package: [],
+            unsafe_package: [
+                PackageName(
+                    "distribute",
+                ),
+                PackageName(
+                    "pip",
+                ),
+                PackageName(
+                    "setuptools",
+                ),
+            ],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            keep_yanked_reason: false,
+            emit_build_isolation_note: false,
+            emit_input_hash: false,
+            emit_python_version: false,
+        emit_timestamp: false,
+        emit_workspace_members: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
             require_hashes: false,
             upgrade: None,
+            upgrade_strategy: OnlyIfNeeded,
             reinstall: None,
             concurrency: Concurrency {
                 downloads: 50,
+                requests: 50,
                 builds: 16,
                 installs: 8,
             },
@@ -610,6 +743,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         color: Auto,
         native_tls: false,
         connectivity: Online,
+        retries: 3,
         isolated: false,
         show_settings: true,
         preview: Disabled,
@@ -626,8 +760,16 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             "requirements.in",
         ],
         constraint: [],
+        build_constraint: [],
+        constraint_from_lock: [],
         override: [],
         overrides_from_workspace: [],
+        verify: false,
+        backup: false,
+        check_input_hash: false,
+        local_version_policy: Keep,
+        strip_trailing_zeros: false,
+        prune: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -652,6 +794,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             no_build_isolation: false,
+            sources: Enabled,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -660,6 +803,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -671,6 +815,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
             python_version: None,
             python_platform: None,
             universal: false,
@@ -680,19 +827,38 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 ),
             ),
             no_emit_package: [],
+            unsafe_package: [
+                PackageName(
+                    "distribute",
+                ),
+                PackageName(
+                    "pip",
+                ),
+                PackageName(
+                    "setuptools",
+                ),
+            ],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            keep_yanked_reason: false,
+            emit_build_isolation_note: false,
+            emit_input_hash: false,
+            emit_python_version: false,
+        emit_timestamp: false,
+        emit_workspace_members: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
             require_hashes: false,
             upgrade: None,
+            upgrade_strategy: OnlyIfNeeded,
             reinstall: None,
             concurrency: Concurrency {
                 downloads: 50,
+                requests: 50,
                 builds: 16,
                 installs: 8,
             },
@@ -728,6 +894,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         color: Auto,
         native_tls: false,
         connectivity: Online,
+        retries: 3,
         isolated: false,
         show_settings: true,
         preview: Disabled,
@@ -744,8 +911,16 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             "requirements.in",
         ],
         constraint: [],
+        build_constraint: [],
+        constraint_from_lock: [],
         override: [],
         overrides_from_workspace: [],
+        verify: false,
+        backup: false,
+        check_input_hash: false,
+        local_version_policy: Keep,
+        strip_trailing_zeros: false,
+        prune: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -793,6 +968,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             no_build_isolation: false,
+            sources: Enabled,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -801,6 +977,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -812,6 +989,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
             python_version: None,
             python_platform: None,
             universal: false,
@@ -821,19 +1001,38 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 ),
             ),
             no_emit_package: [],
+            unsafe_package: [
+                PackageName(
+                    "distribute",
+                ),
+                PackageName(
+                    "pip",
+                ),
+                PackageName(
+                    "setuptools",
+                ),
+            ],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            keep_yanked_reason: false,
+            emit_build_isolation_note: false,
+            emit_input_hash: false,
+            emit_python_version: false,
+        emit_timestamp: false,
+        emit_workspace_members: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
             require_hashes: false,
             upgrade: None,
+            upgrade_strategy: OnlyIfNeeded,
             reinstall: None,
             concurrency: Concurrency {
                 downloads: 50,
+                requests: 50,
                 builds: 16,
                 installs: 8,
             },
@@ -883,6 +1082,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         color: Auto,
         native_tls: false,
         connectivity: Online,
+        retries: 3,
         isolated: false,
         show_settings: true,
         preview: Disabled,
@@ -899,8 +1099,16 @@ fn resolve_index_url() -> anyhow::Result<()> {
             "requirements.in",
         ],
         constraint: [],
+        build_constraint: [],
+        constraint_from_lock: [],
         override: [],
         overrides_from_workspace: [],
+        verify: false,
+        backup: false,
+        check_input_hash: false,
+        local_version_policy: Keep,
+        strip_trailing_zeros: false,
+        prune: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -971,6 +1179,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             no_build_isolation: false,
+            sources: Enabled,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -979,6 +1188,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -990,6 +1200,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
             python_version: None,
             python_platform: None,
             universal: false,
@@ -999,19 +1212,38 @@ fn resolve_index_url() -> anyhow::Result<()> {
                 ),
             ),
             no_emit_package: [],
+            unsafe_package: [
+                PackageName(
+                    "distribute",
+                ),
+                PackageName(
+                    "pip",
+                ),
+                PackageName(
+                    "setuptools",
+                ),
+            ],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            keep_yanked_reason: false,
+            emit_build_isolation_note: false,
+            emit_input_hash: false,
+            emit_python_version: false,
+        emit_timestamp: false,
+        emit_workspace_members: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
             require_hashes: false,
             upgrade: None,
+            upgrade_strategy: OnlyIfNeeded,
             reinstall: None,
             concurrency: Concurrency {
                 downloads: 50,
+                requests: 50,
                 builds: 16,
                 installs: 8,
             },
@@ -1038,6 +1270,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         color: Auto,
         native_tls: false,
         connectivity: Online,
+        retries: 3,
         isolated: false,
         show_settings: true,
         preview: Disabled,
@@ -1054,8 +1287,16 @@ fn resolve_index_url() -> anyhow::Result<()> {
             "requirements.in",
         ],
         constraint: [],
+        build_constraint: [],
+        constraint_from_lock: [],
         override: [],
         overrides_from_workspace: [],
+        verify: false,
+        backup: false,
+        check_input_hash: false,
+        local_version_policy: Keep,
+        strip_trailing_zeros: false,
+        prune: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1148,6 +1389,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             no_build_isolation: false,
+            sources: Enabled,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -1156,6 +1398,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1167,6 +1410,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
             python_version: None,
             python_platform: None,
             universal: false,
@@ -1176,19 +1422,38 @@ fn resolve_index_url() -> anyhow::Result<()> {
                 ),
             ),
             no_emit_package: [],
+            unsafe_package: [
+                PackageName(
+                    "distribute",
+                ),
+                PackageName(
+                    "pip",
+                ),
+                PackageName(
+                    "setuptools",
+                ),
+            ],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            keep_yanked_reason: false,
+            emit_build_isolation_note: false,
+            emit_input_hash: false,
+            emit_python_version: false,
+        emit_timestamp: false,
+        emit_workspace_members: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
             require_hashes: false,
             upgrade: None,
+            upgrade_strategy: OnlyIfNeeded,
             reinstall: None,
             concurrency: Concurrency {
                 downloads: 50,
+                requests: 50,
                 builds: 16,
                 installs: 8,
             },
@@ -1238,6 +1503,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
         color: Auto,
         native_tls: false,
         connectivity: Online,
+        retries: 3,
         isolated: false,
         show_settings: true,
         preview: Disabled,
@@ -1254,8 +1520,16 @@ fn resolve_find_links() -> anyhow::Result<()> {
             "requirements.in",
         ],
         constraint: [],
+        build_constraint: [],
+        constraint_from_lock: [],
         override: [],
         overrides_from_workspace: [],
+        verify: false,
+        backup: false,
+        check_input_hash: false,
+        local_version_policy: Keep,
+        strip_trailing_zeros: false,
+        prune: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1298,6 +1572,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             no_build_isolation: false,
+            sources: Enabled,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -1306,6 +1581,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1317,6 +1593,9 @@ fn resolve_find_links() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
             python_version: None,
             python_platform: None,
             universal: false,
@@ -1326,19 +1605,38 @@ fn resolve_find_links() -> anyhow::Result<()> {
                 ),
             ),
             no_emit_package: [],
+            unsafe_package: [
+                PackageName(
+                    "distribute",
+                ),
+                PackageName(
+                    "pip",
+                ),
+                PackageName(
+                    "setuptools",
+                ),
+            ],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            keep_yanked_reason: false,
+            emit_build_isolation_note: false,
+            emit_input_hash: false,
+            emit_python_version: false,
+        emit_timestamp: false,
+        emit_workspace_members: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
             require_hashes: false,
             upgrade: None,
+            upgrade_strategy: OnlyIfNeeded,
             reinstall: None,
             concurrency: Concurrency {
                 downloads: 50,
+                requests: 50,
                 builds: 16,
                 installs: 8,
             },
@@ -1387,6 +1685,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         color: Auto,
         native_tls: false,
         connectivity: Online,
+        retries: 3,
         isolated: false,
         show_settings: true,
         preview: Disabled,
@@ -1403,8 +1702,16 @@ fn resolve_top_level() -> anyhow::Result<()> {
             "requirements.in",
         ],
         constraint: [],
+        build_constraint: [],
+        constraint_from_lock: [],
         override: [],
         overrides_from_workspace: [],
+        verify: false,
+        backup: false,
+        check_input_hash: false,
+        local_version_policy: Keep,
+        strip_trailing_zeros: false,
+        prune: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1429,6 +1736,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             no_build_isolation: false,
+            sources: Enabled,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -1437,6 +1745,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1448,6 +1757,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
             python_version: None,
             python_platform: None,
             universal: false,
@@ -1457,19 +1769,38 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 ),
             ),
             no_emit_package: [],
+            unsafe_package: [
+                PackageName(
+                    "distribute",
+                ),
+                PackageName(
+                    "pip",
+                ),
+                PackageName(
+                    "setuptools",
+                ),
+            ],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            keep_yanked_reason: false,
+            emit_build_isolation_note: false,
+            emit_input_hash: false,
+            emit_python_version: false,
+        emit_timestamp: false,
+        emit_workspace_members: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
             require_hashes: false,
             upgrade: None,
+            upgrade_strategy: OnlyIfNeeded,
             reinstall: None,
             concurrency: Concurrency {
                 downloads: 50,
+                requests: 50,
                 builds: 16,
                 installs: 8,
             },
@@ -1511,6 +1842,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         color: Auto,
         native_tls: false,
         connectivity: Online,
+        retries: 3,
         isolated: false,
         show_settings: true,
         preview: Disabled,
@@ -1527,8 +1859,16 @@ fn resolve_top_level() -> anyhow::Result<()> {
             "requirements.in",
         ],
         constraint: [],
+        build_constraint: [],
+        constraint_from_lock: [],
         override: [],
         overrides_from_workspace: [],
+        verify: false,
+        backup: false,
+        check_input_hash: false,
+        local_version_policy: Keep,
+        strip_trailing_zeros: false,
+        prune: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1598,6 +1938,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             no_build_isolation: false,
+            sources: Enabled,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -1606,6 +1947,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1617,6 +1959,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
             python_version: None,
             python_platform: None,
             universal: false,
@@ -1626,19 +1971,38 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 ),
             ),
             no_emit_package: [],
+            unsafe_package: [
+                PackageName(
+                    "distribute",
+                ),
+                PackageName(
+                    "pip",
+                ),
+                PackageName(
+                    "setuptools",
+                ),
+            ],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            keep_yanked_reason: false,
+            emit_build_isolation_note: false,
+            emit_input_hash: false,
+            emit_python_version: false,
+        emit_timestamp: false,
+        emit_workspace_members: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
             require_hashes: false,
             upgrade: None,
+            upgrade_strategy: OnlyIfNeeded,
             reinstall: None,
             concurrency: Concurrency {
                 downloads: 50,
+                requests: 50,
                 builds: 16,
                 installs: 8,
             },
@@ -1663,6 +2027,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         color: Auto,
         native_tls: false,
         connectivity: Online,
+        retries: 3,
         isolated: false,
         show_settings: true,
         preview: Disabled,
@@ -1679,8 +2044,16 @@ fn resolve_top_level() -> anyhow::Result<()> {
             "requirements.in",
         ],
         constraint: [],
+        build_constraint: [],
+        constraint_from_lock: [],
         override: [],
         overrides_from_workspace: [],
+        verify: false,
+        backup: false,
+        check_input_hash: false,
+        local_version_policy: Keep,
+        strip_trailing_zeros: false,
+        prune: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1750,6 +2123,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             no_build_isolation: false,
+            sources: Enabled,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -1758,6 +2132,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1769,6 +2144,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
             python_version: None,
             python_platform: None,
             universal: false,
@@ -1778,19 +2156,38 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 ),
             ),
             no_emit_package: [],
+            unsafe_package: [
+                PackageName(
+                    "distribute",
+                ),
+                PackageName(
+                    "pip",
+                ),
+                PackageName(
+                    "setuptools",
+                ),
+            ],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            keep_yanked_reason: false,
+            emit_build_isolation_note: false,
+            emit_input_hash: false,
+            emit_python_version: false,
+        emit_timestamp: false,
+        emit_workspace_members: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
             require_hashes: false,
             upgrade: None,
+            upgrade_strategy: OnlyIfNeeded,
             reinstall: None,
             concurrency: Concurrency {
                 downloads: 50,
+                requests: 50,
                 builds: 16,
                 installs: 8,
             },
@@ -1839,6 +2236,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         color: Auto,
         native_tls: false,
         connectivity: Online,
+        retries: 3,
         isolated: false,
         show_settings: true,
         preview: Disabled,
@@ -1855,8 +2253,16 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             "requirements.in",
         ],
         constraint: [],
+        build_constraint: [],
+        constraint_from_lock: [],
         override: [],
         overrides_from_workspace: [],
+        verify: false,
+        backup: false,
+        check_input_hash: false,
+        local_version_policy: Keep,
+        strip_trailing_zeros: false,
+        prune: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1881,6 +2287,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             no_build_isolation: false,
+            sources: Enabled,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -1889,6 +2296,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1900,6 +2308,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
             python_version: None,
             python_platform: None,
             universal: false,
@@ -1909,19 +2320,38 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 ),
             ),
             no_emit_package: [],
+            unsafe_package: [
+                PackageName(
+                    "distribute",
+                ),
+                PackageName(
+                    "pip",
+                ),
+                PackageName(
+                    "setuptools",
+                ),
+            ],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            keep_yanked_reason: false,
+            emit_build_isolation_note: false,
+            emit_input_hash: false,
+            emit_python_version: false,
+        emit_timestamp: false,
+        emit_workspace_members: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
             require_hashes: false,
             upgrade: None,
+            upgrade_strategy: OnlyIfNeeded,
             reinstall: None,
             concurrency: Concurrency {
                 downloads: 50,
+                requests: 50,
                 builds: 16,
                 installs: 8,
             },
@@ -1953,6 +2383,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         color: Auto,
         native_tls: false,
         connectivity: Online,
+        retries: 3,
         isolated: false,
         show_settings: true,
         preview: Disabled,
@@ -1969,8 +2400,16 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             "requirements.in",
         ],
         constraint: [],
+        build_constraint: [],
+        constraint_from_lock: [],
         override: [],
         overrides_from_workspace: [],
+        verify: false,
+        backup: false,
+        check_input_hash: false,
+        local_version_policy: Keep,
+        strip_trailing_zeros: false,
+        prune: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1995,6 +2434,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             no_build_isolation: false,
+            sources: Enabled,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -2003,6 +2443,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2014,6 +2455,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
             python_version: None,
             python_platform: None,
             universal: false,
@@ -2023,19 +2467,38 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 ),
             ),
             no_emit_package: [],
+            unsafe_package: [
+                PackageName(
+                    "distribute",
+                ),
+                PackageName(
+                    "pip",
+                ),
+                PackageName(
+                    "setuptools",
+                ),
+            ],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            keep_yanked_reason: false,
+            emit_build_isolation_note: false,
+            emit_input_hash: false,
+            emit_python_version: false,
+        emit_timestamp: false,
+        emit_workspace_members: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
             require_hashes: false,
             upgrade: None,
+            upgrade_strategy: OnlyIfNeeded,
             reinstall: None,
             concurrency: Concurrency {
                 downloads: 50,
+                requests: 50,
                 builds: 16,
                 installs: 8,
             },
@@ -2067,6 +2530,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         color: Auto,
         native_tls: false,
         connectivity: Online,
+        retries: 3,
         isolated: false,
         show_settings: true,
         preview: Disabled,
@@ -2083,8 +2547,16 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             "requirements.in",
         ],
         constraint: [],
+        build_constraint: [],
+        constraint_from_lock: [],
         override: [],
         overrides_from_workspace: [],
+        verify: false,
+        backup: false,
+        check_input_hash: false,
+        local_version_policy: Keep,
+        strip_trailing_zeros: false,
+        prune: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2109,6 +2581,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             no_build_isolation: false,
+            sources: Enabled,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -2117,6 +2590,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2128,6 +2602,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
             python_version: None,
             python_platform: None,
             universal: false,
@@ -2137,19 +2614,38 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 ),
             ),
             no_emit_package: [],
+            unsafe_package: [
+                PackageName(
+                    "distribute",
+                ),
+                PackageName(
+                    "pip",
+                ),
+                PackageName(
+                    "setuptools",
+                ),
+            ],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            keep_yanked_reason: false,
+            emit_build_isolation_note: false,
+            emit_input_hash: false,
+            emit_python_version: false,
+        emit_timestamp: false,
+        emit_workspace_members: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
             require_hashes: false,
             upgrade: None,
+            upgrade_strategy: OnlyIfNeeded,
             reinstall: None,
             concurrency: Concurrency {
                 downloads: 50,
+                requests: 50,
                 builds: 16,
                 installs: 8,
             },
@@ -2183,6 +2679,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         color: Auto,
         native_tls: false,
         connectivity: Online,
+        retries: 3,
         isolated: false,
         show_settings: true,
         preview: Disabled,
@@ -2199,8 +2696,16 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             "requirements.in",
         ],
         constraint: [],
+        build_constraint: [],
+        constraint_from_lock: [],
         override: [],
         overrides_from_workspace: [],
+        verify: false,
+        backup: false,
+        check_input_hash: false,
+        local_version_policy: Keep,
+        strip_trailing_zeros: false,
+        prune: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2225,6 +2730,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             no_build_isolation: false,
+            sources: Enabled,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -2233,6 +2739,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2244,6 +2751,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
             python_version: None,
             python_platform: None,
             universal: false,
@@ -2253,19 +2763,38 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 ),
             ),
             no_emit_package: [],
+            unsafe_package: [
+                PackageName(
+                    "distribute",
+                ),
+                PackageName(
+                    "pip",
+                ),
+                PackageName(
+                    "setuptools",
+                ),
+            ],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            keep_yanked_reason: false,
+            emit_build_isolation_note: false,
+            emit_input_hash: false,
+            emit_python_version: false,
+        emit_timestamp: false,
+        emit_workspace_members: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
             require_hashes: false,
             upgrade: None,
+            upgrade_strategy: OnlyIfNeeded,
             reinstall: None,
             concurrency: Concurrency {
                 downloads: 50,
+                requests: 50,
                 builds: 16,
                 installs: 8,
             },
@@ -2324,6 +2853,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         color: Auto,
         native_tls: false,
         connectivity: Online,
+        retries: 3,
         isolated: false,
         show_settings: true,
         preview: Disabled,
@@ -2340,8 +2870,16 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             "requirements.in",
         ],
         constraint: [],
+        build_constraint: [],
+        constraint_from_lock: [],
         override: [],
         overrides_from_workspace: [],
+        verify: false,
+        backup: false,
+        check_input_hash: false,
+        local_version_policy: Keep,
+        strip_trailing_zeros: false,
+        prune: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2366,6 +2904,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             no_build_isolation: false,
+            sources: Enabled,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -2374,6 +2913,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2385,6 +2925,9 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             config_setting: ConfigSettings(
                 {},
             ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
             python_version: None,
             python_platform: None,
             universal: false,
@@ -2394,19 +2937,195 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
                 ),
             ),
             no_emit_package: [],
+            unsafe_package: [
+                PackageName(
+                    "distribute",
+                ),
+                PackageName(
+                    "pip",
+                ),
+                PackageName(
+                    "setuptools",
+                ),
+            ],
             emit_index_url: false,
             emit_find_links: false,
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            keep_yanked_reason: false,
+            emit_build_isolation_note: false,
+            emit_input_hash: false,
+            emit_python_version: false,
+        emit_timestamp: false,
+        emit_workspace_members: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
             require_hashes: false,
             upgrade: None,
+            upgrade_strategy: OnlyIfNeeded,
             reinstall: None,
             concurrency: Concurrency {
                 downloads: 50,
+                requests: 50,
+                builds: 16,
+                installs: 8,
+            },
+        },
+    }
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// Accept concurrency limits on the command line, clamping them to a minimum of one.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn resolve_concurrency() -> anyhow::Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio>3.0.0")?;
+
+    uv_snapshot!(context.filters(), command(&context)
+        .arg("--show-settings")
+        .arg("requirements.in")
+        .arg("--concurrent-downloads")
+        .arg("0")
+        .arg("--concurrent-requests")
+        .arg("4"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    GlobalSettings {
+        quiet: false,
+        verbose: 0,
+        color: Auto,
+        native_tls: false,
+        connectivity: Online,
+        retries: 3,
+        isolated: false,
+        show_settings: true,
+        preview: Disabled,
+        toolchain_preference: OnlySystem,
+    }
+    CacheSettings {
+        no_cache: false,
+        cache_dir: Some(
+            "[CACHE_DIR]/",
+        ),
+    }
+    PipCompileSettings {
+        src_file: [
+            "requirements.in",
+        ],
+        constraint: [],
+        build_constraint: [],
+        constraint_from_lock: [],
+        override: [],
+        overrides_from_workspace: [],
+        verify: false,
+        backup: false,
+        check_input_hash: false,
+        local_version_policy: Keep,
+        strip_trailing_zeros: false,
+        prune: [],
+        refresh: None(
+            Timestamp(
+                SystemTime {
+                    tv_sec: [TIME],
+                    tv_nsec: [TIME],
+                },
+            ),
+        ),
+        settings: PipSettings {
+            index_locations: IndexLocations {
+                index: None,
+                extra_index: [],
+                flat_index: [],
+                no_index: false,
+            },
+            python: None,
+            system: false,
+            extras: None,
+            break_system_packages: false,
+            target: None,
+            prefix: None,
+            index_strategy: FirstIndex,
+            keyring_provider: Disabled,
+            no_build_isolation: false,
+            sources: Enabled,
+            build_options: BuildOptions {
+                no_binary: None,
+                no_build: None,
+            },
+            strict: false,
+            dependency_mode: Transitive,
+            resolution: Highest,
+            prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
+            output_file: None,
+            no_strip_extras: false,
+            no_strip_markers: false,
+            no_annotate: false,
+            no_header: false,
+            custom_compile_command: None,
+            generate_hashes: false,
+            setup_py: Pep517,
+            config_setting: ConfigSettings(
+                {},
+            ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
+            python_version: None,
+            python_platform: None,
+            universal: false,
+            exclude_newer: Some(
+                ExcludeNewer(
+                    2024-03-25T00:00:00Z,
+                ),
+            ),
+            no_emit_package: [],
+            unsafe_package: [
+                PackageName(
+                    "distribute",
+                ),
+                PackageName(
+                    "pip",
+                ),
+                PackageName(
+                    "setuptools",
+                ),
+            ],
+            emit_index_url: false,
+            emit_find_links: false,
+            emit_build_options: false,
+            emit_marker_expression: false,
+            emit_index_annotation: false,
+            keep_yanked_reason: false,
+            emit_build_isolation_note: false,
+            emit_input_hash: false,
+            emit_python_version: false,
+        emit_timestamp: false,
+        emit_workspace_members: false,
+            annotation_style: Split,
+            link_mode: Clone,
+            compile_bytecode: false,
+            require_hashes: false,
+            upgrade: None,
+            upgrade_strategy: OnlyIfNeeded,
+            reinstall: None,
+            concurrency: Concurrency {
+                downloads: 1,
+                requests: 4,
                 builds: 16,
                 installs: 8,
             },
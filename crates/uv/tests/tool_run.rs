@@ -78,7 +78,7 @@ fn tool_run_at_version() {
     // Empty versions are just treated as package and command names
     uv_snapshot!(context.filters(), context.tool_run().arg("pytest@").arg("--version"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -92,7 +92,7 @@ fn tool_run_at_version() {
     // Invalid versions are just treated as package and command names
     uv_snapshot!(context.filters(), context.tool_run().arg("pytest@invalid").arg("--version"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -113,7 +113,7 @@ fn tool_run_at_version() {
     // When `--from` is used, `@` is not treated as a version request
     uv_snapshot!(filters, context.tool_run().arg("--from").arg("pytest").arg("pytest@8.0.0").arg("--version"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
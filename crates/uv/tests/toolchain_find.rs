@@ -13,7 +13,7 @@ fn toolchain_find() {
     if cfg!(windows) {
         uv_snapshot!(context.filters(), context.toolchain_find().env("UV_TEST_PYTHON_PATH", ""), @r###"
         success: false
-        exit_code: 2
+        exit_code: 1
         ----- stdout -----
 
         ----- stderr -----
@@ -22,7 +22,7 @@ fn toolchain_find() {
     } else {
         uv_snapshot!(context.filters(), context.toolchain_find().env("UV_TEST_PYTHON_PATH", ""), @r###"
         success: false
-        exit_code: 2
+        exit_code: 1
         ----- stdout -----
 
         ----- stderr -----
@@ -109,7 +109,7 @@ fn toolchain_find() {
     if cfg!(windows) {
         uv_snapshot!(context.filters(), context.toolchain_find().arg("pypy"), @r###"
         success: false
-        exit_code: 2
+        exit_code: 1
         ----- stdout -----
 
         ----- stderr -----
@@ -118,7 +118,7 @@ fn toolchain_find() {
     } else {
         uv_snapshot!(context.filters(), context.toolchain_find().arg("pypy"), @r###"
         success: false
-        exit_code: 2
+        exit_code: 1
         ----- stdout -----
 
         ----- stderr -----
@@ -642,7 +642,7 @@ fn path_with_trailing_space_gives_proper_error() {
         .arg("venv")
         .env("UV_CACHE_DIR", path_with_trailing_slash), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----